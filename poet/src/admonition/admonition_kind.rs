@@ -0,0 +1,52 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AdmonitionKind {
+    Caution,
+    Important,
+    Note,
+    Tip,
+    Warning,
+}
+
+impl AdmonitionKind {
+    /// Markers are case-insensitive, like on GitHub.
+    pub fn from_marker(marker: &str) -> Option<Self> {
+        match marker.to_ascii_uppercase().as_str() {
+            "CAUTION" => Some(Self::Caution),
+            "IMPORTANT" => Some(Self::Important),
+            "NOTE" => Some(Self::Note),
+            "TIP" => Some(Self::Tip),
+            "WARNING" => Some(Self::Warning),
+            _ => None,
+        }
+    }
+
+    pub fn marker(&self) -> &'static str {
+        match self {
+            Self::Caution => "CAUTION",
+            Self::Important => "IMPORTANT",
+            Self::Note => "NOTE",
+            Self::Tip => "TIP",
+            Self::Warning => "WARNING",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Caution => "caution",
+            Self::Important => "important",
+            Self::Note => "note",
+            Self::Tip => "tip",
+            Self::Warning => "warning",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Caution => "Caution",
+            Self::Important => "Important",
+            Self::Note => "Note",
+            Self::Tip => "Tip",
+            Self::Warning => "Warning",
+        }
+    }
+}
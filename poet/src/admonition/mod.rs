@@ -0,0 +1,12 @@
+pub mod admonition_kind;
+
+use markdown::mdast::Node;
+
+use crate::admonition::admonition_kind::AdmonitionKind;
+
+/// GitHub-style alert, written as a blockquote that starts with `[!KIND]`.
+pub struct Admonition {
+    /// Blockquote contents with the alert marker stripped.
+    pub children: Vec<Node>,
+    pub kind: AdmonitionKind,
+}
@@ -4,7 +4,7 @@ use rhai::TypeBuilder;
 use crate::author_basename::AuthorBasename;
 use crate::author_data::AuthorData;
 
-#[derive(Clone)]
+#[derive(Clone, Hash)]
 pub struct Author {
     pub basename: AuthorBasename,
     pub data: AuthorData,
@@ -7,7 +7,7 @@ use crate::author::Author;
 use crate::author_basename::AuthorBasename;
 use crate::author_resolve_result::AuthorResolveResult;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Hash)]
 pub struct AuthorCollection {
     authors: BTreeMap<AuthorBasename, Author>,
 }
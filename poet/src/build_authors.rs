@@ -1,4 +1,3 @@
-use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -8,6 +7,7 @@ use crate::author::Author;
 use crate::author_basename::AuthorBasename;
 use crate::author_collection::AuthorCollection;
 use crate::author_data::AuthorData;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
 use crate::document_error_collection::DocumentErrorCollection;
 use crate::filesystem::Filesystem;
 use crate::filesystem::storage::Storage;
@@ -21,15 +21,18 @@ pub async fn build_authors(source_filesystem: Arc<Storage>) -> Result<AuthorColl
             let data: AuthorData = match toml::from_str(&file.contents) {
                 Ok(data) => data,
                 Err(err) => {
-                    error_collection.register_error(
+                    error_collection.register_source_error(
                         file.relative_path.display().to_string(),
+                        DiagnosticCode::InvalidAuthor,
+                        &file,
                         anyhow!("Failed to parse author file: {err}"),
                     );
                     continue;
                 }
             };
 
-            let basename_path = file.get_stem_path_relative_to(&PathBuf::from("authors"));
+            let basename_path =
+                file.get_stem_path_relative_to(&source_filesystem.directories.authors);
             let basename: AuthorBasename = basename_path.into();
 
             authors.insert(basename.clone(), Author { basename, data });
@@ -39,12 +42,14 @@ pub async fn build_authors(source_filesystem: Arc<Storage>) -> Result<AuthorColl
     if error_collection.is_empty() {
         Ok(authors)
     } else {
-        Err(anyhow!("{error_collection}"))
+        Err(error_collection.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use tempfile::tempdir;
 
     use super::*;
@@ -54,6 +59,7 @@ mod tests {
         let directory = tempdir()?;
         let storage = Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         storage
@@ -75,6 +81,7 @@ mod tests {
         let directory = tempdir()?;
         let storage = Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         storage
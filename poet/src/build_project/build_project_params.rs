@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use esbuild_metafile::EsbuildMetaFile;
@@ -5,15 +6,32 @@ use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
 
 use crate::asset_path_renderer::AssetPathRenderer;
 use crate::author_collection::AuthorCollection;
+use crate::build_project::build_project_result::BuildProjectResult;
 use crate::filesystem::storage::Storage;
+use crate::project_config::components_config::ComponentsConfig;
+use crate::project_config::site_config::SiteConfig;
+use crate::project_config::taxonomy_config::TaxonomyConfig;
 
 pub struct BuildProjectParams {
     pub asset_path_renderer: AssetPathRenderer,
     pub authors: AuthorCollection,
+    pub components: ComponentsConfig,
     pub esbuild_metafile: Arc<EsbuildMetaFile>,
+    pub feed_full_content: bool,
     pub generated_page_base_path: String,
+    pub generate_feeds: bool,
+    /// Both `llms.txt` and `llms-full.txt`
+    pub generate_llms_txt: bool,
     pub generate_sitemap: bool,
+    /// Drafts are left out entirely otherwise, as if their files did not
+    /// exist.
+    pub include_drafts: bool,
     pub is_watching: bool,
+    /// Pages rendered by the previous build are reused when neither their
+    /// source nor their rendering dependencies changed since then.
+    pub previous_build_project_result: Option<BuildProjectResult>,
     pub rhai_template_renderer: RhaiTemplateRenderer,
+    pub site: SiteConfig,
     pub source_filesystem: Arc<Storage>,
+    pub taxonomies: BTreeMap<String, TaxonomyConfig>,
 }
@@ -1,9 +1,15 @@
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use esbuild_metafile::EsbuildMetaFile;
 
 use crate::build_project::build_project_result_stub::BuildProjectResultStub;
+use crate::build_project::document_fingerprint::DocumentFingerprint;
+use crate::build_project::rendering_dependencies::RenderingDependencies;
 use crate::content_document_basename::ContentDocumentBasename;
 use crate::content_document_linker::ContentDocumentLinker;
 use crate::content_document_source::ContentDocumentSource;
@@ -14,8 +20,32 @@ pub struct BuildProjectResult {
     pub changed_since_last_build: Vec<ContentDocumentSource>,
     pub content_document_linker: ContentDocumentLinker,
     pub content_document_sources: Arc<BTreeMap<ContentDocumentBasename, ContentDocumentSource>>,
+    pub document_fingerprints: Arc<HashMap<ContentDocumentBasename, DocumentFingerprint>>,
     pub esbuild_metafile: Arc<EsbuildMetaFile>,
+    /// Document contents before the layout, when feeds include them
+    pub feed_contents: Arc<HashMap<ContentDocumentBasename, String>>,
+    /// Markdown alternates before the title, when llms.txt is generated
+    pub markdown_contents: Arc<HashMap<ContentDocumentBasename, String>>,
     pub memory_filesystem: Arc<Memory>,
+    pub rendering_dependencies: RenderingDependencies,
+    pub route_aliases: Arc<BTreeMap<String, PathBuf>>,
+}
+
+impl BuildProjectResult {
+    /// Drafts are built for previews, but are not published anywhere else.
+    pub fn published_content_document_sources(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Iterator<Item = &ContentDocumentSource> {
+        self.content_document_sources
+            .values()
+            .filter(move |content_document_source| {
+                !content_document_source
+                    .reference
+                    .front_matter
+                    .is_draft_at(now)
+            })
+    }
 }
 
 impl From<BuildProjectResultStub> for BuildProjectResult {
@@ -23,16 +53,27 @@ impl From<BuildProjectResultStub> for BuildProjectResult {
         BuildProjectResultStub {
             content_document_linker,
             content_document_sources,
+            document_fingerprints,
             esbuild_metafile,
+            feed_contents,
+            markdown_contents,
             memory_filesystem,
+            rendering_dependencies,
+            route_aliases,
+            ..
         }: BuildProjectResultStub,
     ) -> Self {
         Self {
             changed_since_last_build: vec![],
             content_document_linker,
             content_document_sources,
+            document_fingerprints,
             esbuild_metafile,
+            feed_contents,
+            markdown_contents,
             memory_filesystem,
+            rendering_dependencies,
+            route_aliases,
         }
     }
 }
@@ -5,6 +5,7 @@ use std::sync::atomic::AtomicUsize;
 use anyhow::Result;
 use anyhow::anyhow;
 use async_trait::async_trait;
+use chrono::Utc;
 use tokio::sync::Notify;
 use tokio::sync::RwLock;
 
@@ -33,7 +34,9 @@ impl Holder for BuildProjectResultHolder {
     fn on_update(&self, build_project_result: &Option<Self::Item>) {
         self.total.store(
             if let Some(build_project_result) = build_project_result {
-                build_project_result.content_document_sources.len()
+                build_project_result
+                    .published_content_document_sources(Utc::now())
+                    .count()
             } else {
                 0
             },
@@ -1,4 +1,6 @@
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use esbuild_metafile::EsbuildMetaFile;
@@ -6,16 +8,29 @@ use rayon::iter::ParallelBridge as _;
 use rayon::iter::ParallelIterator as _;
 
 use crate::build_project::build_project_result::BuildProjectResult;
+use crate::build_project::document_fingerprint::DocumentFingerprint;
+use crate::build_project::rendering_dependencies::RenderingDependencies;
 use crate::content_document_basename::ContentDocumentBasename;
 use crate::content_document_linker::ContentDocumentLinker;
 use crate::content_document_source::ContentDocumentSource;
+use crate::diagnostic::Diagnostic;
 use crate::filesystem::memory::Memory;
 
 pub struct BuildProjectResultStub {
     pub content_document_linker: ContentDocumentLinker,
     pub content_document_sources: Arc<BTreeMap<ContentDocumentBasename, ContentDocumentSource>>,
+    pub document_fingerprints: Arc<HashMap<ContentDocumentBasename, DocumentFingerprint>>,
     pub esbuild_metafile: Arc<EsbuildMetaFile>,
+    /// Document contents before the layout, when feeds include them
+    pub feed_contents: Arc<HashMap<ContentDocumentBasename, String>>,
+    /// Markdown alternates before the title, when llms.txt is generated
+    pub markdown_contents: Arc<HashMap<ContentDocumentBasename, String>>,
     pub memory_filesystem: Arc<Memory>,
+    pub rendering_dependencies: RenderingDependencies,
+    /// Generated files by the extra routes they are also served under
+    pub route_aliases: Arc<BTreeMap<String, PathBuf>>,
+    /// Problems that did not fail the build.
+    pub warnings: Vec<Diagnostic>,
 }
 
 impl BuildProjectResultStub {
@@ -43,8 +58,13 @@ impl BuildProjectResultStub {
             changed_since_last_build,
             content_document_linker: self.content_document_linker,
             content_document_sources: self.content_document_sources,
+            document_fingerprints: self.document_fingerprints,
             esbuild_metafile: self.esbuild_metafile,
+            feed_contents: self.feed_contents,
+            markdown_contents: self.markdown_contents,
             memory_filesystem: self.memory_filesystem,
+            rendering_dependencies: self.rendering_dependencies,
+            route_aliases: self.route_aliases,
         }
     }
 }
@@ -69,6 +89,7 @@ mod tests {
         let directory = tempdir()?;
         let source_filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         source_filesystem
@@ -94,12 +115,20 @@ mod tests {
                 base_path: "/".to_string(),
             },
             authors,
+            components: Default::default(),
             esbuild_metafile: Default::default(),
+            feed_full_content: false,
             generated_page_base_path: "/".to_string(),
+            generate_feeds: false,
+            generate_llms_txt: false,
             generate_sitemap: false,
+            include_drafts: false,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: Default::default(),
             source_filesystem,
+            taxonomies: Default::default(),
         })
         .await
     }
@@ -12,17 +12,27 @@ use crate::author_collection::AuthorCollection;
 use crate::content_document::ContentDocument;
 use crate::content_document_collection_ranked::ContentDocumentCollectionRanked;
 use crate::content_document_linker::ContentDocumentLinker;
+use crate::link_definitions::LinkDefinitions;
+use crate::paginator::Paginator;
+use crate::project_config::components_config::ComponentsConfig;
+use crate::project_config::site_config::SiteConfig;
+use crate::taxonomy_index::TaxonomyIndex;
 
 pub struct ContentDocumentRenderingContext<'render> {
     pub asset_path_renderer: AssetPathRenderer,
     pub authors: Vec<Author>,
     pub available_collections: Arc<HashSet<String>>,
     pub available_authors: Arc<AuthorCollection>,
+    pub components: Arc<ComponentsConfig>,
     pub content_document: &'render ContentDocument,
     pub content_document_collections_ranked: Arc<HashMap<String, ContentDocumentCollectionRanked>>,
     pub content_document_linker: ContentDocumentLinker,
     pub esbuild_metafile: Arc<EsbuildMetaFile>,
     pub is_watching: bool,
+    pub link_definitions: LinkDefinitions,
+    pub paginator: Option<Paginator>,
     pub rhai_template_renderer: &'render RhaiTemplateRenderer,
+    pub site: Arc<SiteConfig>,
     pub syntax_set: &'render SyntaxSet,
+    pub taxonomy_index: Arc<TaxonomyIndex>,
 }
@@ -0,0 +1,9 @@
+use crate::document_dependencies::DocumentDependencies;
+
+/// What a rendered document read, and the hash of it at the time of the
+/// build.
+#[derive(Clone)]
+pub struct DocumentFingerprint {
+    pub dependencies: DocumentDependencies,
+    pub hash: u64,
+}
@@ -3,6 +3,11 @@ pub mod build_project_result;
 pub mod build_project_result_holder;
 pub mod build_project_result_stub;
 mod content_document_rendering_context;
+pub mod document_fingerprint;
+mod rendered_content_document;
+pub mod rendering_dependencies;
+mod reusable_document;
+pub mod shortcode_source;
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -13,19 +18,26 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use chrono::Utc;
 use dashmap::DashMap;
 use log::debug;
 use log::info;
 use rayon::iter::IntoParallelRefIterator as _;
 use rayon::iter::ParallelIterator as _;
 use rhai::Dynamic;
+use slug::slugify;
 use syntect::parsing::SyntaxSet;
 
 use crate::asset_manager::AssetManager;
 use crate::author_resolve_result::AuthorResolveResult;
 use crate::build_project::build_project_params::BuildProjectParams;
+use crate::build_project::build_project_result::BuildProjectResult;
 use crate::build_project::build_project_result_stub::BuildProjectResultStub;
 use crate::build_project::content_document_rendering_context::ContentDocumentRenderingContext;
+use crate::build_project::document_fingerprint::DocumentFingerprint;
+use crate::build_project::rendered_content_document::RenderedContentDocument;
+use crate::build_project::rendering_dependencies::RenderingDependencies;
+use crate::build_project::reusable_document::ReusableDocument;
 use crate::build_timer::BuildTimer;
 use crate::content_document::ContentDocument;
 use crate::content_document_basename::ContentDocumentBasename;
@@ -35,16 +47,41 @@ use crate::content_document_component_context::ContentDocumentComponentContext;
 use crate::content_document_front_matter::ContentDocumentFrontMatter;
 use crate::content_document_in_collection::ContentDocumentInCollection;
 use crate::content_document_linker::ContentDocumentLinker;
+use crate::content_document_lint::ContentDocumentLint;
+use crate::content_document_lint::lint_content_document_mdast::lint_content_document_mdast;
 use crate::content_document_reference::ContentDocumentReference;
 use crate::content_document_source::ContentDocumentSource;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
 use crate::document_error_collection::DocumentErrorCollection;
 use crate::eval_content_document_mdast::eval_content_document_mdast;
+use crate::eval_markdown_mdast::eval_markdown_mdast;
+use crate::eval_markdown_mdast_params::EvalMarkdownMdastParams;
+use crate::feed_entry::FeedEntry;
 use crate::filesystem::Filesystem as _;
+use crate::filesystem::file_entry::FileEntry;
 use crate::filesystem::memory::Memory;
+use crate::filesystem::read_file_contents_result::ReadFileContentsResult;
+use crate::find_footnotes_in_mdast::find_footnotes_in_mdast;
 use crate::find_front_matter_in_mdast::find_front_matter_in_mdast;
+use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
 use crate::find_table_of_contents_in_mdast::find_table_of_contents_in_mdast;
-use crate::generate_sitemap::create_sitemap;
+use crate::generate_feed::create_feed;
+use crate::generate_llms_txt::create_llms_full_txt;
+use crate::generate_llms_txt::create_llms_txt;
+use crate::generate_robots_txt::create_robots_txt;
+use crate::generate_sitemap::create_sitemaps;
+use crate::heading_ids::HeadingIds;
+use crate::insert_markdown_alternate_link::insert_markdown_alternate_link;
+use crate::link_checker::LinkChecker;
+use crate::link_checker::broken_link::BrokenLink;
+use crate::link_definitions::LinkDefinitions;
+use crate::link_definitions::undefined_reference::UndefinedReference;
+use crate::paginator::Paginator;
+use crate::source_position_error::SourcePositionError;
 use crate::string_to_mdast::string_to_mdast;
+use crate::taxonomy_index::TaxonomyIndex;
+use crate::taxonomy_term_component_context::TaxonomyTermComponentContext;
 
 fn render_document<'render>(
     ContentDocumentRenderingContext {
@@ -52,6 +89,7 @@ fn render_document<'render>(
         authors,
         available_authors,
         available_collections,
+        components,
         content_document:
             ContentDocument {
                 mdast,
@@ -66,31 +104,58 @@ fn render_document<'render>(
         content_document_linker,
         esbuild_metafile,
         is_watching,
+        link_definitions,
+        paginator,
         rhai_template_renderer,
+        site,
         syntax_set,
+        taxonomy_index,
     }: ContentDocumentRenderingContext<'render>,
-) -> Result<String> {
+) -> Result<RenderedContentDocument> {
+    let heading_ids = content_document_linker
+        .heading_ids_by_basename
+        .get(&reference.basename())
+        .cloned()
+        .unwrap_or_default();
     let component_context = ContentDocumentComponentContext {
         asset_manager: AssetManager::from_esbuild_metafile(esbuild_metafile, asset_path_renderer),
         authors: authors.clone(),
         available_authors,
         available_collections,
+        components,
         content_document_collections_ranked,
         content_document_linker,
+        dependency_recorder: Default::default(),
+        footnotes: None,
         front_matter: front_matter.clone(),
+        heading_ids,
         is_watching,
+        link_definitions,
+        paginator,
         reference: reference.clone(),
+        site,
         table_of_contents: None,
+        taxonomy_index,
     };
 
-    let table_of_contents = find_table_of_contents_in_mdast(
+    let footnotes = find_footnotes_in_mdast(
         mdast,
         &component_context,
         rhai_template_renderer,
         syntax_set,
     )?;
 
-    let component_context_with_toc = component_context.with_table_of_contents(table_of_contents);
+    let component_context_with_footnotes = component_context.with_footnotes(footnotes);
+
+    let table_of_contents = find_table_of_contents_in_mdast(
+        mdast,
+        &component_context_with_footnotes,
+        rhai_template_renderer,
+        syntax_set,
+    )?;
+
+    let component_context_with_toc =
+        component_context_with_footnotes.with_table_of_contents(table_of_contents);
 
     let layout_content = eval_content_document_mdast(
         mdast,
@@ -99,24 +164,96 @@ fn render_document<'render>(
         syntax_set,
     )?;
 
-    rhai_template_renderer.render(
+    let page = rhai_template_renderer.render(
         &front_matter.layout,
         component_context_with_toc.clone(),
         Dynamic::from_map(front_matter.props.clone()),
-        layout_content.into(),
-    )
+        layout_content.clone().into(),
+    )?;
+
+    let markdown_content = eval_markdown_mdast(
+        EvalMarkdownMdastParams {
+            mdast,
+            is_directly_in_root: false,
+            is_first_child: false,
+            is_in_top_paragraph: false,
+            rhai_template_renderer,
+        },
+        &mut component_context_with_toc.clone(),
+    )?;
+
+    Ok(RenderedContentDocument {
+        content: layout_content,
+        dependencies: component_context_with_toc
+            .dependency_recorder
+            .dependencies(),
+        markdown: format!("# {}\n\n{markdown_content}\n", front_matter.title),
+        markdown_content,
+        page,
+    })
+}
+
+/// Both the page and its Markdown alternate have to be reusable. Whether a
+/// scheduled document is still a draft depends on the time of the build, so
+/// those are always rendered again.
+fn find_reusable_files(
+    previous_build_project_result: &BuildProjectResult,
+    content_document_source: Option<&ContentDocumentSource>,
+) -> Option<Vec<(PathBuf, String)>> {
+    let content_document_source = content_document_source
+        .filter(|source| source.reference.front_matter.publish_at.is_none())?;
+    let previous_content_document_source = previous_build_project_result
+        .content_document_sources
+        .get(&content_document_source.reference.basename())?;
+
+    if previous_content_document_source.file_entry.contents_hash
+        != content_document_source.file_entry.contents_hash
+    {
+        return None;
+    }
+
+    [
+        content_document_source
+            .reference
+            .target_file_relative_path()
+            .ok()?,
+        content_document_source
+            .reference
+            .markdown_target_file_relative_path()
+            .ok()?,
+    ]
+    .into_iter()
+    .map(|relative_path| {
+        match previous_build_project_result
+            .memory_filesystem
+            .read_file_contents_sync(&relative_path)
+            .ok()?
+        {
+            ReadFileContentsResult::Found { contents } => Some((relative_path, contents)),
+            ReadFileContentsResult::Directory | ReadFileContentsResult::NotFound => None,
+        }
+    })
+    .collect()
 }
 
 pub async fn build_project(
     BuildProjectParams {
         asset_path_renderer,
         authors,
+        components,
         esbuild_metafile,
+        feed_full_content,
         generated_page_base_path,
+        generate_feeds,
+        generate_llms_txt,
         generate_sitemap,
+        include_drafts,
         is_watching,
+        previous_build_project_result,
         rhai_template_renderer,
+        site,
         source_filesystem,
+        taxonomies,
     }: BuildProjectParams,
 ) -> Result<BuildProjectResultStub> {
     info!(
@@ -128,6 +265,7 @@ pub async fn build_project(
     let _build_timer = BuildTimer::default();
     let error_collection: DocumentErrorCollection = Default::default();
     let memory_filesystem = Arc::new(Memory::default());
+    let now = Utc::now();
     let syntax_set = SyntaxSet::load_defaults_newlines();
 
     let mut content_document_basename_by_id: HashMap<String, ContentDocumentBasename> =
@@ -143,17 +281,43 @@ pub async fn build_project(
     let mut content_document_list: Vec<ContentDocument> = Vec::new();
     let mut content_document_sources: BTreeMap<ContentDocumentBasename, ContentDocumentSource> =
         Default::default();
+    let mut heading_ids_by_basename: HashMap<ContentDocumentBasename, HeadingIds> = HashMap::new();
+    let mut shortcode_files: HashMap<String, FileEntry> = HashMap::new();
 
     for file in source_filesystem.read_project_files().await? {
-        if file.kind.is_content() {
-            let mdast = string_to_mdast(&file.contents)?;
-            let front_matter: ContentDocumentFrontMatter = find_front_matter_in_mdast(&mdast)?
-                .ok_or_else(|| {
+        if file.kind.is_shortcode() {
+            shortcode_files.insert(
+                file.get_stem_relative_to(&source_filesystem.directories.shortcodes),
+                file,
+            );
+        } else if file.kind.is_content() {
+            let basename_path =
+                file.get_stem_path_relative_to(&source_filesystem.directories.content);
+            let basename: ContentDocumentBasename = basename_path.clone().into();
+            let previous_mdast = previous_build_project_result
+                .as_ref()
+                .and_then(|previous| previous.content_document_sources.get(&basename))
+                .filter(|previous_source| {
+                    previous_source.file_entry.contents_hash == file.contents_hash
+                })
+                .map(|previous_source| previous_source.mdast.clone());
+            let mdast = match previous_mdast {
+                Some(mdast) => mdast,
+                None => string_to_mdast(&file.contents)?,
+            };
+            let front_matter = ContentDocumentFrontMatter::from_toml_table(
+                find_front_matter_in_mdast(&mdast)?.ok_or_else(|| {
                     anyhow!("No front matter found in file: {:?}", file.relative_path)
-                })?;
+                })?,
+                &taxonomies,
+            )?;
+
+            if !include_drafts && front_matter.is_draft_at(now) {
+                debug!("Skipping draft document: {basename}");
+
+                continue;
+            }
 
-            let basename_path = file.get_stem_path_relative_to(&PathBuf::from("content"));
-            let basename: ContentDocumentBasename = basename_path.clone().into();
             let content_document_reference = ContentDocumentReference {
                 basename_path,
                 front_matter: front_matter.clone(),
@@ -164,6 +328,7 @@ pub async fn build_project(
                 if content_document_basename_by_id.contains_key(id) {
                     error_collection.register_error(
                         content_document_reference.basename().to_string(),
+                        DiagnosticCode::DuplicateDocumentId,
                         anyhow!("Duplicate document id: #{id} in '{basename}'"),
                     );
                 }
@@ -171,6 +336,18 @@ pub async fn build_project(
                 content_document_basename_by_id.insert(id.clone(), basename.clone());
             }
 
+            match find_heading_ids_in_mdast(&mdast) {
+                Ok(heading_ids) => {
+                    heading_ids_by_basename.insert(basename.clone(), heading_ids);
+                }
+                Err(err) => error_collection.register_source_error(
+                    content_document_reference.basename().to_string(),
+                    DiagnosticCode::DuplicateHeadingId,
+                    &file,
+                    err,
+                ),
+            }
+
             content_document_by_basename
                 .insert(basename.clone(), content_document_reference.clone());
             content_document_list.push(ContentDocument {
@@ -207,6 +384,7 @@ pub async fn build_project(
         {
             error_collection.register_error(
                 reference.basename().to_string(),
+                DiagnosticCode::InvalidCollection,
                 anyhow!(
                     "Document does belong to the collection it claims to be it's primary collection"
                 ),
@@ -219,6 +397,7 @@ pub async fn build_project(
             {
                 error_collection.register_error(
                     reference.basename().to_string(),
+                    DiagnosticCode::InvalidCollection,
                     anyhow!("Succeeding document does not exist: '{after}'"),
                 );
             }
@@ -228,6 +407,7 @@ pub async fn build_project(
             {
                 error_collection.register_error(
                     reference.basename().to_string(),
+                    DiagnosticCode::InvalidCollection,
                     anyhow!("Parent document does not exist: '{parent}'"),
                 );
             }
@@ -256,11 +436,142 @@ pub async fn build_project(
         );
     }
 
+    let mut route_aliases: BTreeMap<String, PathBuf> = BTreeMap::new();
+
+    for reference in content_document_by_basename.values() {
+        if !reference.front_matter.render {
+            continue;
+        }
+
+        for alias in &reference.front_matter.aliases {
+            match reference.target_file_relative_path() {
+                Ok(target_file_relative_path) => {
+                    if route_aliases
+                        .insert(
+                            alias.trim_start_matches('/').to_string(),
+                            target_file_relative_path,
+                        )
+                        .is_some()
+                    {
+                        error_collection.register_error(
+                            reference.basename().to_string(),
+                            DiagnosticCode::DuplicateAlias,
+                            anyhow!("Alias is declared by more than one document: '{alias}'"),
+                        );
+                    }
+                }
+                Err(err) => error_collection.register_error(
+                    reference.basename().to_string(),
+                    DiagnosticCode::WriteFailed,
+                    anyhow!(err),
+                ),
+            }
+        }
+    }
+
+    let mut paginators_by_basename: HashMap<ContentDocumentBasename, Vec<Paginator>> =
+        HashMap::new();
+
+    for reference in content_document_by_basename.values() {
+        let Some(paginate) = &reference.front_matter.paginate else {
+            continue;
+        };
+
+        if !reference.front_matter.render {
+            continue;
+        }
+
+        let Some(collection) = content_document_collections.get(&paginate.collection) else {
+            error_collection.register_error(
+                reference.basename().to_string(),
+                DiagnosticCode::InvalidPagination,
+                anyhow!(
+                    "Paginated collection is never used in any document: '{}'",
+                    paginate.collection
+                ),
+            );
+
+            continue;
+        };
+
+        let mut items: Vec<ContentDocumentReference> = collection
+            .documents
+            .iter()
+            .map(|document| document.reference.clone())
+            .filter(|item| item.front_matter.render && item != reference)
+            .collect();
+
+        items.sort_by(|a, b| paginate.sort.compare(a, b));
+
+        match Paginator::paginate(reference, &items, paginate.per_page) {
+            Ok(paginators) => {
+                for paginator in paginators.iter().skip(1) {
+                    match reference.page_basename_path(paginator.current_page) {
+                        Ok(page_basename_path) => {
+                            if content_document_by_basename
+                                .contains_key(&page_basename_path.clone().into())
+                            {
+                                error_collection.register_error(
+                                    reference.basename().to_string(),
+                                    DiagnosticCode::InvalidPagination,
+                                    anyhow!(
+                                        "Listing page would overwrite a document: '{}'",
+                                        page_basename_path.display()
+                                    ),
+                                );
+                            }
+                        }
+                        Err(err) => error_collection.register_error(
+                            reference.basename().to_string(),
+                            DiagnosticCode::InvalidPagination,
+                            anyhow!(err),
+                        ),
+                    }
+                }
+
+                paginators_by_basename.insert(reference.basename(), paginators);
+            }
+            Err(err) => error_collection.register_error(
+                reference.basename().to_string(),
+                DiagnosticCode::InvalidPagination,
+                anyhow!(err),
+            ),
+        }
+    }
+
+    let mut taxonomy_index = TaxonomyIndex::new(&taxonomies);
+
+    for reference in content_document_by_basename.values() {
+        if reference.front_matter.render {
+            taxonomy_index.add_document(reference);
+        }
+    }
+
+    for taxonomy in taxonomy_index.taxonomies.values() {
+        if taxonomy.layout.is_some() {
+            for term in taxonomy.terms.values() {
+                if content_document_by_basename.contains_key(&term.basename().into()) {
+                    error_collection.register_error(
+                        term.basename(),
+                        DiagnosticCode::InvalidTaxonomy,
+                        anyhow!(
+                            "Term listing page would overwrite a document: '{}'",
+                            term.basename()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
     if !error_collection.is_empty() {
-        return Err(anyhow!("{error_collection}"));
+        return Err(error_collection.into());
     }
 
     let authors_arc = Arc::new(authors);
+    let components_arc = Arc::new(components);
+    let site_arc = Arc::new(site);
+    let taxonomy_index_arc = Arc::new(taxonomy_index);
 
     let available_collections_arc: Arc<HashSet<String>> = Arc::new(
         content_document_collections
@@ -268,6 +579,9 @@ pub async fn build_project(
             .map(|key| key.to_string())
             .collect::<HashSet<String>>(),
     );
+    let feed_contents: DashMap<ContentDocumentBasename, String> = Default::default();
+    let collects_feed_contents = generate_feeds && feed_full_content;
+    let markdown_contents: DashMap<ContentDocumentBasename, String> = Default::default();
     let content_document_reference_collection_dashmap: DashMap<String, ContentDocumentReference> =
         Default::default();
     let content_document_basename_by_id_arc = Arc::new(content_document_basename_by_id);
@@ -276,7 +590,97 @@ pub async fn build_project(
     let content_document_linker = ContentDocumentLinker {
         content_document_basename_by_id: content_document_basename_by_id_arc.clone(),
         content_document_by_basename: content_document_by_basename_arc.clone(),
+        heading_ids_by_basename: Arc::new(heading_ids_by_basename),
     };
+    let rendering_dependencies = RenderingDependencies {
+        authors: authors_arc.clone(),
+        components: components_arc.clone(),
+        content_document_collections_ranked: content_document_collections_ranked_arc.clone(),
+        content_document_linker: content_document_linker.clone(),
+        esbuild_metafile: esbuild_metafile.clone(),
+        shortcodes: Arc::new(RenderingDependencies::shortcode_sources(&shortcode_files)),
+        taxonomy_index: taxonomy_index_arc.clone(),
+    };
+    let reusable_build_project_result = previous_build_project_result.filter(|previous| {
+        rendering_dependencies.is_unchanged_since(&previous.rendering_dependencies)
+    });
+    let document_fingerprints: DashMap<ContentDocumentBasename, DocumentFingerprint> =
+        Default::default();
+    let link_checker = LinkChecker {
+        asset_manager: AssetManager::from_esbuild_metafile(
+            esbuild_metafile.clone(),
+            asset_path_renderer.clone(),
+        ),
+        content_document_linker: content_document_linker.clone(),
+    };
+
+    // Check links and references of every rendered document up front, so all
+    // broken ones are reported together instead of the first one failing the
+    // render.
+    // Lints are collected in the same pass, they only produce warnings
+    let mut link_definitions_by_basename: HashMap<ContentDocumentBasename, LinkDefinitions> =
+        HashMap::new();
+
+    for (
+        basename,
+        ContentDocumentSource {
+            file_entry, mdast, ..
+        },
+    ) in &content_document_sources
+    {
+        let link_definitions = find_link_definitions_in_mdast(mdast);
+        let heading_ids = content_document_linker
+            .heading_ids_by_basename
+            .get(basename)
+            .cloned()
+            .unwrap_or_default();
+
+        for BrokenLink {
+            message, position, ..
+        } in link_checker.check(mdast, &heading_ids, &link_definitions)
+        {
+            error_collection.register_source_error(
+                basename.to_string(),
+                DiagnosticCode::BrokenLink,
+                file_entry,
+                SourcePositionError::attach(anyhow!(message), position.as_ref()),
+            );
+        }
+
+        for UndefinedReference { label, position } in
+            link_definitions.undefined_references_in_mdast(mdast, &file_entry.contents)
+        {
+            error_collection.register_source_error(
+                basename.to_string(),
+                DiagnosticCode::UndefinedReference,
+                file_entry,
+                SourcePositionError::attach(
+                    anyhow!("Undefined link reference: [{label}]"),
+                    position.as_ref(),
+                ),
+            );
+        }
+
+        for ContentDocumentLint {
+            code,
+            message,
+            position,
+        } in lint_content_document_mdast(mdast, &syntax_set)
+        {
+            error_collection.register_warning(
+                basename.to_string(),
+                code,
+                file_entry,
+                SourcePositionError::attach(anyhow!(message), position.as_ref()),
+            );
+        }
+
+        link_definitions_by_basename.insert(basename.clone(), link_definitions);
+    }
+
+    if !error_collection.is_empty() {
+        return Err(error_collection.into());
+    }
 
     content_document_list
         .par_iter()
@@ -301,6 +705,7 @@ pub async fn build_project(
             for author_name in &missing_authors {
                 error_collection.register_error(
                     content_document.reference.basename().to_string(),
+                    DiagnosticCode::MissingAuthor,
                     anyhow!("Author does not exist: '{author_name}'"),
                 );
             }
@@ -309,28 +714,188 @@ pub async fn build_project(
                 return;
             }
 
-            match render_document(ContentDocumentRenderingContext {
-                asset_path_renderer: asset_path_renderer.clone(),
-                authors: found_authors,
-                available_authors: authors_arc.clone(),
-                available_collections: available_collections_arc.clone(),
-                esbuild_metafile: esbuild_metafile.clone(),
-                is_watching,
-                content_document,
-                content_document_collections_ranked: content_document_collections_ranked_arc
-                    .clone(),
-                content_document_linker: content_document_linker.clone(),
-                rhai_template_renderer: &rhai_template_renderer,
-                syntax_set: &syntax_set,
-            }) {
-                Ok(processed_file) => {
-                    match content_document.reference.target_file_relative_path() {
-                        Ok(relative_path) => {
+            let link_definitions = link_definitions_by_basename
+                .get(&content_document.reference.basename())
+                .cloned()
+                .unwrap_or_default();
+
+            let paginators = paginators_by_basename.get(&content_document.reference.basename());
+
+            if let Some(ReusableDocument {
+                feed_content,
+                files,
+                fingerprint,
+                markdown_content,
+            }) = reusable_build_project_result
+                .as_ref()
+                .filter(|_| paginators.is_none())
+                .and_then(|previous| {
+                    let previous_fingerprint = previous
+                        .document_fingerprints
+                        .get(&content_document.reference.basename())
+                        .filter(|previous_fingerprint| {
+                            previous_fingerprint.hash
+                                == rendering_dependencies.fingerprint(
+                                    content_document,
+                                    &found_authors,
+                                    &previous_fingerprint.dependencies,
+                                )
+                        })?;
+
+                    Some(ReusableDocument {
+                        feed_content: if collects_feed_contents {
+                            Some(
+                                previous
+                                    .feed_contents
+                                    .get(&content_document.reference.basename())?
+                                    .clone(),
+                            )
+                        } else {
+                            None
+                        },
+                        files: find_reusable_files(
+                            previous,
+                            content_document_sources.get(&content_document.reference.basename()),
+                        )?,
+                        fingerprint: previous_fingerprint.clone(),
+                        markdown_content: if generate_llms_txt {
+                            Some(
+                                previous
+                                    .markdown_contents
+                                    .get(&content_document.reference.basename())?
+                                    .clone(),
+                            )
+                        } else {
+                            None
+                        },
+                    })
+                })
+            {
+                for (relative_path, previous_contents) in files {
+                    if let Err(err) =
+                        memory_filesystem.set_file_contents_sync(&relative_path, &previous_contents)
+                    {
+                        error_collection.register_error(
+                            content_document.reference.basename().to_string(),
+                            DiagnosticCode::WriteFailed,
+                            err,
+                        );
+
+                        return;
+                    }
+                }
+
+                if let Some(feed_content) = feed_content {
+                    feed_contents.insert(content_document.reference.basename(), feed_content);
+                }
+
+                if let Some(markdown_content) = markdown_content {
+                    markdown_contents
+                        .insert(content_document.reference.basename(), markdown_content);
+                }
+
+                document_fingerprints.insert(content_document.reference.basename(), fingerprint);
+
+                debug!(
+                    "Reusing previously rendered document: {}",
+                    content_document.reference.basename()
+                );
+
+                return;
+            }
+
+            // Paginated documents are rendered once for every listing page
+            let pages: Vec<Option<Paginator>> = match paginators {
+                Some(paginators) => paginators.iter().cloned().map(Some).collect(),
+                None => vec![None],
+            };
+
+            for paginator in pages {
+                let target_file_relative_path = match &paginator {
+                    Some(paginator) => content_document
+                        .reference
+                        .page_target_file_relative_path(paginator.current_page)
+                        .and_then(|relative_path| {
+                            Ok((
+                                relative_path,
+                                content_document
+                                    .reference
+                                    .page_markdown_link(paginator.current_page)?,
+                            ))
+                        }),
+                    None => content_document
+                        .reference
+                        .target_file_relative_path()
+                        .and_then(|relative_path| {
+                            Ok((relative_path, content_document.reference.markdown_link()?))
+                        }),
+                };
+
+                match render_document(ContentDocumentRenderingContext {
+                    asset_path_renderer: asset_path_renderer.clone(),
+                    authors: found_authors.clone(),
+                    available_authors: authors_arc.clone(),
+                    available_collections: available_collections_arc.clone(),
+                    components: components_arc.clone(),
+                    esbuild_metafile: esbuild_metafile.clone(),
+                    is_watching,
+                    content_document,
+                    link_definitions: link_definitions.clone(),
+                    content_document_collections_ranked: content_document_collections_ranked_arc
+                        .clone(),
+                    content_document_linker: content_document_linker.clone(),
+                    paginator,
+                    rhai_template_renderer: &rhai_template_renderer,
+                    site: site_arc.clone(),
+                    syntax_set: &syntax_set,
+                    taxonomy_index: taxonomy_index_arc.clone(),
+                }) {
+                    Ok(RenderedContentDocument {
+                        content,
+                        dependencies,
+                        markdown,
+                        markdown_content,
+                        page,
+                    }) => match target_file_relative_path {
+                        Ok((relative_path, markdown_link)) => {
+                            document_fingerprints
+                                .entry(content_document.reference.basename())
+                                .or_insert_with(|| DocumentFingerprint {
+                                    hash: rendering_dependencies.fingerprint(
+                                        content_document,
+                                        &found_authors,
+                                        &dependencies,
+                                    ),
+                                    dependencies,
+                                });
+
+                            if collects_feed_contents {
+                                feed_contents
+                                    .entry(content_document.reference.basename())
+                                    .or_insert(content);
+                            }
+
+                            if generate_llms_txt {
+                                markdown_contents
+                                    .entry(content_document.reference.basename())
+                                    .or_insert(markdown_content);
+                            }
+
                             if let Err(err) = memory_filesystem
-                                .set_file_contents_sync(&relative_path, &processed_file)
+                                .set_file_contents_sync(
+                                    &relative_path,
+                                    &insert_markdown_alternate_link(&page, &markdown_link),
+                                )
+                                .and_then(|_| {
+                                    memory_filesystem.set_file_contents_sync(
+                                        &relative_path.with_extension("md"),
+                                        &markdown,
+                                    )
+                                })
                             {
                                 error_collection.register_error(
                                     content_document.reference.basename().to_string(),
+                                    DiagnosticCode::WriteFailed,
                                     err,
                                 );
                             } else {
@@ -343,33 +908,208 @@ pub async fn build_project(
                         Err(err) => {
                             error_collection.register_error(
                                 content_document.reference.basename().to_string(),
+                                DiagnosticCode::WriteFailed,
                                 anyhow!(err),
                             );
                         }
+                    },
+                    Err(err) => {
+                        match content_document_sources.get(&content_document.reference.basename()) {
+                            Some(ContentDocumentSource { file_entry, .. }) => error_collection
+                                .register_source_error(
+                                    content_document.reference.basename().to_string(),
+                                    DiagnosticCode::RenderFailed,
+                                    file_entry,
+                                    err,
+                                ),
+                            None => error_collection.register_error(
+                                content_document.reference.basename().to_string(),
+                                DiagnosticCode::RenderFailed,
+                                err,
+                            ),
+                        }
                     }
                 }
-                Err(err) => error_collection
-                    .register_error(content_document.reference.basename().to_string(), err),
             }
         });
 
+    for taxonomy in taxonomy_index_arc.taxonomies.values() {
+        if let Some(layout) = &taxonomy.layout {
+            for term in taxonomy.terms.values() {
+                let rendered = rhai_template_renderer.render(
+                    layout,
+                    TaxonomyTermComponentContext {
+                        asset_manager: AssetManager::from_esbuild_metafile(
+                            esbuild_metafile.clone(),
+                            asset_path_renderer.clone(),
+                        ),
+                        content_document_linker: content_document_linker.clone(),
+                        is_watching,
+                        site: site_arc.clone(),
+                        taxonomy_index: taxonomy_index_arc.clone(),
+                        term: term.clone(),
+                    },
+                    Dynamic::from_map(Default::default()),
+                    Dynamic::from(String::new()),
+                );
+
+                match rendered.and_then(|rendered| {
+                    memory_filesystem
+                        .set_file_contents_sync(&term.target_file_relative_path(), &rendered)
+                }) {
+                    Ok(()) => debug!("Rendered term listing page: {}", term.basename()),
+                    Err(err) => error_collection.register_error(
+                        term.basename(),
+                        DiagnosticCode::RenderFailed,
+                        err,
+                    ),
+                }
+            }
+        }
+    }
+
+    if generate_feeds {
+        info!("Building feeds");
+
+        let feed_entries_of = |references: Vec<&ContentDocumentReference>| -> Vec<FeedEntry> {
+            references
+                .into_iter()
+                .filter(|reference| reference.front_matter.render)
+                .map(|reference| FeedEntry {
+                    authors: authors_arc
+                        .resolve(&reference.front_matter.authors)
+                        .found_authors,
+                    content: feed_contents
+                        .get(&reference.basename())
+                        .map(|content| content.value().clone()),
+                    reference: reference.clone(),
+                })
+                .collect()
+        };
+        let mut feeds: Vec<(String, String, Vec<FeedEntry>)> = vec![(
+            "feed.xml".to_string(),
+            site_arc.title.clone().unwrap_or_default(),
+            feed_entries_of(content_document_by_basename_arc.values().collect()),
+        )];
+
+        for collection in content_document_collections.values() {
+            feeds.push((
+                format!("{}/feed.xml", slugify(&collection.name)),
+                match &site_arc.title {
+                    Some(title) => format!("{title}: {}", collection.name),
+                    None => collection.name.clone(),
+                },
+                feed_entries_of(
+                    collection
+                        .documents
+                        .iter()
+                        .map(|document| &document.reference)
+                        .collect(),
+                ),
+            ));
+        }
+
+        for (relative_path, title, entries) in feeds {
+            match create_feed(
+                title,
+                format!("{generated_page_base_path}{relative_path}"),
+                generated_page_base_path.clone(),
+                entries,
+            )
+            .and_then(|feed| {
+                memory_filesystem.set_file_contents_sync(Path::new(&relative_path), &feed)
+            }) {
+                Ok(()) => debug!("Built feed: {relative_path}"),
+                Err(err) => {
+                    error_collection.register_error(relative_path, DiagnosticCode::WriteFailed, err)
+                }
+            }
+        }
+    }
+
+    if generate_llms_txt {
+        info!("Building llms.txt");
+
+        let mut collections: Vec<&ContentDocumentCollectionRanked> =
+            content_document_collections_ranked_arc.values().collect();
+
+        collections.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for (relative_path, generated) in [
+            (
+                "llms.txt",
+                create_llms_txt(
+                    &site_arc,
+                    &collections,
+                    content_document_by_basename_arc.values(),
+                ),
+            ),
+            (
+                "llms-full.txt",
+                create_llms_full_txt(
+                    &collections,
+                    content_document_by_basename_arc.values(),
+                    &markdown_contents,
+                ),
+            ),
+        ] {
+            if let Err(err) = generated.and_then(|contents| {
+                memory_filesystem.set_file_contents_sync(Path::new(relative_path), &contents)
+            }) {
+                error_collection.register_error(
+                    relative_path.to_string(),
+                    DiagnosticCode::WriteFailed,
+                    err,
+                );
+            }
+        }
+    }
+
     if generate_sitemap {
         info!("Building sitemap");
 
-        match create_sitemap(
+        // Every listing page but the first is linked to from its predecessor
+        let listing_page_links = paginators_by_basename
+            .iter()
+            .filter(|(basename, _)| {
+                content_document_by_basename_arc
+                    .get(*basename)
+                    .is_some_and(|reference| reference.front_matter.is_listed_in_sitemap_at(now))
+            })
+            .flat_map(|(_, paginators)| paginators)
+            .filter_map(|paginator| paginator.next_link.clone());
+
+        match create_sitemaps(
+            &generated_page_base_path,
             content_document_by_basename_arc
                 .values()
-                .filter(|content_document| content_document.front_matter.render),
+                .filter(|content_document| {
+                    content_document.front_matter.is_listed_in_sitemap_at(now)
+                }),
+            listing_page_links,
         ) {
-            Ok(sitemap) => {
-                if let Err(err) =
-                    memory_filesystem.set_file_contents_sync(Path::new("sitemap.xml"), &sitemap)
-                {
-                    error_collection.register_error("sitemap.xml".to_string(), err);
+            Ok(sitemaps) => {
+                for (relative_path, contents) in sitemaps.into_iter().chain([(
+                    "robots.txt".to_string(),
+                    create_robots_txt(&format!("{generated_page_base_path}sitemap.xml")),
+                )]) {
+                    if let Err(err) = memory_filesystem
+                        .set_file_contents_sync(Path::new(&relative_path), &contents)
+                    {
+                        error_collection.register_error(
+                            relative_path,
+                            DiagnosticCode::WriteFailed,
+                            err,
+                        );
+                    }
                 }
             }
             Err(err) => {
-                error_collection.register_error("sitemap.xml".to_string(), err);
+                error_collection.register_error(
+                    "sitemap.xml".to_string(),
+                    DiagnosticCode::WriteFailed,
+                    err,
+                );
             }
         }
     }
@@ -379,31 +1119,45 @@ pub async fn build_project(
             esbuild_metafile,
             content_document_linker,
             content_document_sources: Arc::new(content_document_sources),
+            document_fingerprints: Arc::new(document_fingerprints.into_iter().collect()),
+            feed_contents: Arc::new(feed_contents.into_iter().collect()),
+            markdown_contents: Arc::new(markdown_contents.into_iter().collect()),
             memory_filesystem,
+            rendering_dependencies,
+            route_aliases: Arc::new(route_aliases),
+            warnings: error_collection.diagnostics(),
         })
     } else {
-        Err(anyhow!("{error_collection}"))
+        Err(error_collection.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::path::Path;
     use std::sync::Arc;
 
     use anyhow::Result;
     use anyhow::anyhow;
+    use chrono::Utc;
+    use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
     use tempfile::tempdir;
 
     use super::build_project;
     use crate::asset_path_renderer::AssetPathRenderer;
     use crate::build_authors::build_authors;
     use crate::build_project::build_project_params::BuildProjectParams;
+    use crate::build_project::build_project_result::BuildProjectResult;
     use crate::build_project::build_project_result_stub::BuildProjectResultStub;
     use crate::compile_shortcodes::compile_shortcodes;
+    use crate::diagnostic::Diagnostic;
+    use crate::diagnostic::diagnostic_code::DiagnosticCode;
     use crate::filesystem::Filesystem as _;
     use crate::filesystem::read_file_contents_result::ReadFileContentsResult;
     use crate::filesystem::storage::Storage;
+    use crate::filesystem_http_route_index::FilesystemHttpRouteIndex;
+    use crate::project_config::taxonomy_config::TaxonomyConfig;
 
     const LAYOUT_MINIMAL: &str = r#"
 fn template(context, props, content) {
@@ -446,10 +1200,18 @@ fn template(context, props, content) {
     async fn build(
         files: &[(&str, &str)],
         generate_sitemap: bool,
+    ) -> Result<BuildProjectResultStub> {
+        build_with(files, |params| params.generate_sitemap = generate_sitemap).await
+    }
+
+    async fn build_with(
+        files: &[(&str, &str)],
+        configure: impl FnOnce(&mut BuildProjectParams),
     ) -> Result<BuildProjectResultStub> {
         let directory = tempdir()?;
         let source_filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         for (relative_path, contents) in files {
@@ -461,19 +1223,30 @@ fn template(context, props, content) {
         let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
         let authors = build_authors(source_filesystem.clone()).await?;
 
-        build_project(BuildProjectParams {
+        let mut params = BuildProjectParams {
             asset_path_renderer: AssetPathRenderer {
                 base_path: "/".to_string(),
             },
             authors,
+            components: Default::default(),
             esbuild_metafile: Default::default(),
+            feed_full_content: false,
             generated_page_base_path: "/".to_string(),
-            generate_sitemap,
+            generate_feeds: false,
+            generate_llms_txt: false,
+            generate_sitemap: false,
+            include_drafts: false,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: Default::default(),
             source_filesystem,
-        })
-        .await
+            taxonomies: Default::default(),
+        };
+
+        configure(&mut params);
+
+        build_project(params).await
     }
 
     async fn read(result: &BuildProjectResultStub, relative_path: &str) -> Result<String> {
@@ -549,6 +1322,55 @@ fn template(context, props, content) {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn leaves_excluded_documents_out_of_sitemap_and_generates_robots_txt() -> Result<()> {
+        let result = build_with(
+            &[
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html>{content}</html> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"Layout\"\ntitle = \"Home\"\n+++\n\nHome.\n",
+                ),
+                (
+                    "content/guide.md",
+                    "+++\nchangefreq = \"monthly\"\ndescription = \"Guide\"\nlast_updated_at = \"2025-03-01\"\nlayout = \"Layout\"\npriority = 0.9\ntitle = \"Guide\"\n+++\n\nGuide.\n",
+                ),
+                (
+                    "content/thanks.md",
+                    "+++\ndescription = \"Thanks\"\nlayout = \"Layout\"\nsitemap = false\ntitle = \"Thanks\"\n+++\n\nThanks.\n",
+                ),
+                (
+                    "content/internal.md",
+                    "+++\ndescription = \"Internal\"\nlayout = \"Layout\"\ntitle = \"Internal\"\n\n[seo]\nnoindex = true\n+++\n\nInternal.\n",
+                ),
+            ],
+            |params| {
+                params.generate_sitemap = true;
+                params.generated_page_base_path = "https://example.com/".to_string();
+            },
+        )
+        .await?;
+
+        let sitemap = read(&result, "sitemap.xml").await?;
+
+        assert!(sitemap.contains("<loc>https://example.com/guide/</loc>"));
+        assert!(sitemap.contains("<lastmod>2025-03-01T00:00:00+00:00</lastmod>"));
+        assert!(sitemap.contains("<changefreq>monthly</changefreq>"));
+        assert!(sitemap.contains("<priority>0.9</priority>"));
+        assert!(!sitemap.contains("thanks"));
+        assert!(!sitemap.contains("internal"));
+        assert!(read(&result, "thanks/index.html").await.is_ok());
+        assert_eq!(
+            read(&result, "robots.txt").await?,
+            "User-agent: *\nAllow: /\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+
+        Ok(())
+    }
+
     const LAYOUT_RICH: &str = r#"
 fn template(context, props, content) {
   component {
@@ -670,45 +1492,1448 @@ fn template(context, props, content) {
     }
 
     #[tokio::test]
-    async fn errors_on_duplicate_document_id() -> Result<()> {
-        let front_matter = |title: &str| {
-            format!(
-                "+++\ndescription = \"d\"\nid = \"dup\"\nlayout = \"LayoutMinimal\"\ntitle = \"{title}\"\n+++\n\nBody.\n"
-            )
-        };
-
-        let outcome = build(
+    async fn lets_configured_endnotes_shortcode_place_footnotes() -> Result<()> {
+        let result = build_with(
             &[
-                ("content/a.md", &front_matter("A")),
-                ("content/b.md", &front_matter("B")),
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Notes.rhai",
+                    "fn template(context, props, content) { component { <aside>{context.footnotes.notes.len()} notes</aside> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nClaim[^source].\n\n[^source]: Source.\n",
+                ),
             ],
-            false,
+            |params| params.components.endnotes = Some("Notes".to_string()),
         )
-        .await;
+        .await?;
 
-        assert!(
-            outcome.is_err_and(|error| error.to_string().contains("Duplicate document id: #dup"))
-        );
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(r##"<a href="#footnote-source" id="footnote-reference-source" role="doc-noteref">1</a>"##));
+        assert!(home.contains("<aside>1 notes</aside>"));
+        assert!(!home.contains("doc-endnotes"));
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn errors_when_a_referenced_author_does_not_exist() -> Result<()> {
-        let outcome = build(
-            &[(
-                "content/a.md",
-                "+++\ndescription = \"d\"\nlayout = \"LayoutMinimal\"\ntitle = \"A\"\nauthors = [\"ghost\"]\n+++\n\nBody.\n",
-            )],
+    async fn renders_endnotes_when_no_endnotes_shortcode_is_configured() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Endnotes.rhai",
+                    "fn template(context, props, content) { component { <aside>notes</aside> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nClaim[^source].\n\n[^source]: Source.\n",
+                ),
+            ],
             false,
         )
-        .await;
+        .await?;
 
-        assert!(
-            outcome.is_err_and(|error| {
-                error.to_string().contains("Author does not exist: 'ghost'")
-            })
-        );
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains("doc-endnotes"));
+        assert!(!home.contains("<aside>notes</aside>"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wraps_math_through_configured_math_shortcode() -> Result<()> {
+        let result = build_with(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Formula.rhai",
+                    "fn template(context, props, content) { component { <span class=\"math\" data-source={props.source}>{content}</span> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nSquare $x^2$.\n",
+                ),
+            ],
+            |params| params.components.math = Some("Formula".to_string()),
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(r#"<span class="math" data-source="x^2"><math"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn leaves_math_alone_when_no_math_shortcode_is_configured() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Math.rhai",
+                    "fn template(context, props, content) { component { <span class=\"math\">{content}</span> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nSquare $x^2$.\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains("<p>Square <math"));
+        assert!(!home.contains(r#"<span class="math">"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passes_alert_kind_to_configured_admonition_shortcode() -> Result<()> {
+        let result = build_with(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Callout.rhai",
+                    "fn template(context, props, content) { component { <div class={`callout callout--${props.kind}`}>{content}</div> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n> [!WARNING]\n> Mind the gap.\n",
+                ),
+            ],
+            |params| params.components.admonition = Some("Callout".to_string()),
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(
+            home.contains(r#"<div class="callout callout--warning"><p>Mind the gap.</p></div>"#)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn leaves_alerts_alone_when_no_admonition_shortcode_is_configured() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Admonition.rhai",
+                    "fn template(context, props, content) { component { <div class=\"callout\">{content}</div> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n> [!WARNING]\n> Mind the gap.\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(r#"<aside class="admonition admonition--warning" role="note">"#));
+        assert!(!home.contains(r#"<div class="callout">"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passes_parsed_rows_to_configured_table_shortcode() -> Result<()> {
+        let result = build_with(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/DataTable.rhai",
+                    "fn template(context, props, content) { component { <div class=\"table\" data-columns={props.header.len()} data-rows={props.rows.len()} data-align={props.align[0]}>{content}</div> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n| A | B |\n| -: | - |\n| 1 | 2 |\n| 3 | 4 |\n",
+                ),
+            ],
+            |params| params.components.table = Some("DataTable".to_string()),
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(
+            r#"<div class="table" data-columns="2" data-rows="2" data-align="right"><table><thead>"#
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn leaves_tables_alone_when_no_table_shortcode_is_configured() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Table.rhai",
+                    "fn template(context, props, content) { component { <div class=\"table\">{content}</div> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n| A | B |\n| - | - |\n| 1 | 2 |\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains("<table><thead>"));
+        assert!(!home.contains(r#"<div class="table">"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_on_duplicate_document_id() -> Result<()> {
+        let front_matter = |title: &str| {
+            format!(
+                "+++\ndescription = \"d\"\nid = \"dup\"\nlayout = \"LayoutMinimal\"\ntitle = \"{title}\"\n+++\n\nBody.\n"
+            )
+        };
+
+        let outcome = build(
+            &[
+                ("content/a.md", &front_matter("A")),
+                ("content/b.md", &front_matter("B")),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(
+            outcome.is_err_and(|error| error.to_string().contains("Duplicate document id: #dup"))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn leaves_out_drafts_and_scheduled_documents_unless_included() -> Result<()> {
+        let files = [
+            (
+                "shortcodes/DraftLayout.rhai",
+                "fn template(context, props, content) { component { <main data-draft={context.is_draft}>{content}</main> } }",
+            ),
+            (
+                "content/index.md",
+                "+++\ndescription = \"Home\"\nlayout = \"DraftLayout\"\ntitle = \"Home\"\npublish_at = \"2020-01-01\"\n+++\n\nHome.\n",
+            ),
+            (
+                "content/draft.md",
+                "+++\ndescription = \"Draft\"\ndraft = true\nlayout = \"DraftLayout\"\ntitle = \"Draft\"\n+++\n\nDraft.\n",
+            ),
+            (
+                "content/scheduled.md",
+                "+++\ndescription = \"Scheduled\"\nlayout = \"DraftLayout\"\ntitle = \"Scheduled\"\npublish_at = \"2999-01-01\"\n+++\n\nLater.\n",
+            ),
+        ];
+
+        let published = build_with(&files, |params| params.generate_sitemap = true).await?;
+
+        assert!(
+            read(&published, "index.html")
+                .await?
+                .contains("data-draft=\"false\"")
+        );
+        assert!(read(&published, "draft/index.html").await.is_err());
+        assert!(read(&published, "scheduled/index.html").await.is_err());
+        assert!(!read(&published, "sitemap.xml").await?.contains("scheduled"));
+        assert_eq!(published.content_document_sources.len(), 1);
+
+        let preview = build_with(&files, |params| {
+            params.generate_sitemap = true;
+            params.include_drafts = true;
+        })
+        .await?;
+
+        assert!(
+            read(&preview, "draft/index.html")
+                .await?
+                .contains("data-draft=\"true\"")
+        );
+        assert!(
+            read(&preview, "scheduled/index.html")
+                .await?
+                .contains("data-draft=\"true\"")
+        );
+        assert_eq!(preview.content_document_sources.len(), 3);
+
+        let preview_sitemap = read(&preview, "sitemap.xml").await?;
+
+        assert!(!preview_sitemap.contains("draft"));
+        assert!(!preview_sitemap.contains("scheduled"));
+        assert_eq!(
+            BuildProjectResult::from(preview)
+                .published_content_document_sources(Utc::now())
+                .count(),
+            1
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn serves_documents_under_their_aliases() -> Result<()> {
+        let result = build(
+            &[
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html>{content}</html> } }",
+                ),
+                (
+                    "content/guide.md",
+                    "+++\naliases = [\"/old/guide/\"]\ndescription = \"Guide\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\nGuide.\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+        let route_index = FilesystemHttpRouteIndex::from_filesystem(
+            result.memory_filesystem,
+            &result.route_aliases,
+        )
+        .await?;
+
+        assert_eq!(
+            route_index
+                .get_file_entry_for_path("old/guide/")
+                .map(|file| file.relative_path),
+            Some("guide/index.html".into())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_on_alias_declared_by_two_documents() -> Result<()> {
+        let document = "+++\naliases = [\"old/\"]\ndescription = \"D\"\nlayout = \"Layout\"\ntitle = \"D\"\n+++\n\nD.\n";
+        let outcome = build(
+            &[("content/a.md", document), ("content/b.md", document)],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error
+                .to_string()
+                .contains("Alias is declared by more than one document: 'old/'")
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn renders_markdown_alternates_of_documents() -> Result<()> {
+        let result = build(
+            &[
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html><head><title>{context.front_matter.title}</title></head>{content}</html> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"Layout\"\ntitle = \"Home\"\n+++\n\n## Start\n\nRead the [guide](docs/guide) in {\"two\" + \" minutes\"}.\n\n| Step | Time |\n| :--- | ---: |\n| Install | 1 |\n",
+                ),
+                (
+                    "content/docs/guide.md",
+                    "+++\ndescription = \"Guide\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\nGuide.\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(
+            "<title>Home</title><link rel=\"alternate\" type=\"text/markdown\" href=\"/index.md\"></head>"
+        ));
+        assert_eq!(
+            read(&result, "index.md").await?,
+            "# Home\n\n## Start\n\nRead the [guide](/docs/guide/) in two minutes.\n\n| Step | Time |\n| :-- | --: |\n| Install | 1 |\n"
+        );
+        assert!(
+            read(&result, "docs/guide/index.md")
+                .await?
+                .starts_with("# Guide\n\nGuide.")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn renders_seo_metadata_from_front_matter() -> Result<()> {
+        let result = build(
+            &[
+                ("authors/alice.toml", "name = \"Alice\""),
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html><head>{context.seo.render()}</head>{content}</html> } }",
+                ),
+                (
+                    "content/docs/index.md",
+                    "+++\ndescription = \"Docs\"\nlayout = \"Layout\"\ntitle = \"Docs\"\n\n[[collection]]\nname = \"docs\"\n+++\n\nDocs.\n",
+                ),
+                (
+                    "content/docs/setup.md",
+                    "+++\nauthors = [\"alice\"]\ndescription = \"How to set up\"\nlast_updated_at = \"2025-03-01\"\nlayout = \"Layout\"\ntitle = \"Setup\"\n\n[[collection]]\nname = \"docs\"\nparent = \"docs/index\"\n\n[seo]\ntitle = \"Setting up poet\"\ntype = \"TechArticle\"\n+++\n\nSetup.\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let setup = read(&result, "docs/setup/index.html").await?;
+
+        assert!(setup.contains("<meta property=\"og:title\" content=\"Setting up poet\">"));
+        assert!(setup.contains("<meta property=\"og:description\" content=\"How to set up\">"));
+        assert!(setup.contains("\"@type\":\"TechArticle\""));
+        assert!(setup.contains("\"name\":\"Alice\""));
+        assert!(setup.contains("\"dateModified\":\"2025-03-01T00:00:00+00:00\""));
+        assert!(setup.contains("\"item\":\"/docs/\",\"name\":\"Docs\",\"position\":1"));
+        assert!(setup.contains("\"item\":\"/docs/setup/\",\"name\":\"Setup\",\"position\":2"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builds_llms_txt_from_content_tree() -> Result<()> {
+        let result = build_with(
+            &[
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html>{content}</html> } }",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Docs for everyone\"\nlayout = \"Layout\"\ntitle = \"Home\"\n+++\n\nWelcome.\n",
+                ),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/docs/setup.md",
+                    "+++\ndescription = \"How to set up\"\nlayout = \"Layout\"\ntitle = \"Setup\"\n\n[[collection]]\nname = \"Guides\"\n+++\n\nRun *poet* {40 + 2} times.\n\n<PrimaryNavigation>\ninner\n</PrimaryNavigation>\n",
+                ),
+                (
+                    "content/hidden.md",
+                    "+++\ndescription = \"Hidden\"\nlayout = \"Layout\"\nrender = false\ntitle = \"Hidden\"\n+++\n\nNot rendered.\n",
+                ),
+            ],
+            |params| params.generate_llms_txt = true,
+        )
+        .await?;
+
+        let llms_txt = read(&result, "llms.txt").await?;
+
+        assert!(llms_txt.starts_with("# Home\n\n> Docs for everyone\n"));
+        assert!(llms_txt.contains("## Guides\n\n- [Setup](/docs/setup/): How to set up\n"));
+
+        let llms_full_txt = read(&result, "llms-full.txt").await?;
+
+        assert!(
+            llms_full_txt.starts_with("# Setup\n\nSource: /docs/setup/\n\nRun *poet* 42 times.\n")
+        );
+        assert!(!llms_full_txt.contains("+++"));
+        assert!(!llms_full_txt.contains("{40 + 2}"));
+        assert!(llms_full_txt.contains("<nav>\ninner\n</nav>\n\n---\n\n# Home\n"));
+        assert!(!llms_full_txt.contains("Not rendered."));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builds_site_and_collection_feeds() -> Result<()> {
+        let result = build_with(
+            &[
+                ("authors/alice.toml", "name = \"Alice\""),
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <html>{content}</html> } }",
+                ),
+                (
+                    "content/about.md",
+                    "+++\ndescription = \"About us\"\nlayout = \"Layout\"\ntitle = \"About\"\n+++\n\nAbout.\n",
+                ),
+                (
+                    "content/blog/hello.md",
+                    "+++\nauthors = [\"alice\"]\ndescription = \"First post\"\nlast_updated_at = \"2025-03-01\"\nlayout = \"Layout\"\ntitle = \"Hello\"\n\n[[collection]]\nname = \"Blog\"\n+++\n\nHello *world*.\n",
+                ),
+            ],
+            |params| {
+                params.feed_full_content = true;
+                params.generate_feeds = true;
+                params.site.title = Some("Example".to_string());
+            },
+        )
+        .await?;
+
+        let site_feed = read(&result, "feed.xml").await?;
+
+        assert!(site_feed.contains("<title>Example</title>"));
+        assert!(site_feed.contains("<title>About</title>"));
+        assert!(site_feed.contains("<title>Hello</title>"));
+
+        let blog_feed = read(&result, "blog/feed.xml").await?;
+
+        assert!(blog_feed.contains("<title>Example: Blog</title>"));
+        assert!(!blog_feed.contains("<title>About</title>"));
+        assert!(blog_feed.contains("<name>Alice</name>"));
+        assert!(blog_feed.contains("<summary>First post</summary>"));
+        assert!(blog_feed.contains("<updated>2025-03-01T00:00:00+00:00</updated>"));
+        assert!(blog_feed.contains("&lt;em&gt;world&lt;/em&gt;"));
+        assert!(!blog_feed.contains("&lt;html&gt;"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn renders_listing_pages_of_paginated_document() -> Result<()> {
+        let result = build(
+            &[
+                (
+                    "shortcodes/Post.rhai",
+                    "fn template(context, props, content) { component { <article>{content}</article> } }",
+                ),
+                (
+                    "shortcodes/Listing.rhai",
+                    r#"
+fn template(context, props, content) {
+  component {
+    <main data-page={context.paginator.current_page} data-pages={context.paginator.total_pages}>
+      {
+        let ret = [];
+
+        for item in context.paginator.items {
+          ret.push(component {
+            <li>{item.front_matter.title}</li>
+          });
+        }
+
+        ret
+      }
+      {
+        if context.paginator.next_link != () {
+          component { <a rel="next" href={context.paginator.next_link}>next</a> }
+        } else if context.paginator.previous_link != () {
+          component { <a rel="prev" href={context.paginator.previous_link}>previous</a> }
+        } else {
+          ""
+        }
+      }
+    </main>
+  }
+}
+"#,
+                ),
+                (
+                    "content/blog/index.md",
+                    "+++\ndescription = \"Blog\"\nlayout = \"Listing\"\ntitle = \"Blog\"\npaginate = { collection = \"blog\", per_page = 2, sort = \"date_desc\" }\n+++\n",
+                ),
+                (
+                    "content/blog/a.md",
+                    "+++\ndescription = \"a\"\nlayout = \"Post\"\npublish_at = \"2024-01-01\"\ntitle = \"Post a\"\n\n[[collection]]\nname = \"blog\"\n+++\n\nPost.\n",
+                ),
+                (
+                    "content/blog/b.md",
+                    "+++\ndescription = \"b\"\nlayout = \"Post\"\npublish_at = \"2024-01-02\"\ntitle = \"Post b\"\n\n[[collection]]\nname = \"blog\"\n+++\n\nPost.\n",
+                ),
+                (
+                    "content/blog/c.md",
+                    "+++\ndescription = \"c\"\nlayout = \"Post\"\npublish_at = \"2024-01-03\"\ntitle = \"Post c\"\n\n[[collection]]\nname = \"blog\"\n+++\n\nPost.\n",
+                ),
+            ],
+            true,
+        )
+        .await?;
+
+        let first_page = read(&result, "blog/index.html").await?;
+
+        assert!(first_page.contains("data-page=\"1\" data-pages=\"2\""));
+        assert!(first_page.find("Post c") < first_page.find("Post b"));
+        assert!(!first_page.contains("Post a"));
+        assert!(first_page.contains("href=\"/blog/page/2/\""));
+
+        let second_page = read(&result, "blog/page/2/index.html").await?;
+
+        assert!(second_page.contains("<li>Post a</li>"));
+        assert!(second_page.contains("href=\"/blog/\""));
+        assert!(
+            read(&result, "sitemap.xml")
+                .await?
+                .contains("/blog/page/2/")
+        );
+
+        let route_index = FilesystemHttpRouteIndex::from_filesystem(
+            result.memory_filesystem.clone(),
+            &result.route_aliases,
+        )
+        .await?;
+
+        assert!(
+            route_index
+                .get_file_entry_for_path("blog/page/2/")
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_on_pagination_of_unused_collection() -> Result<()> {
+        let outcome = build(
+            &[(
+                "content/index.md",
+                "+++\ndescription = \"Home\"\nlayout = \"Listing\"\ntitle = \"Home\"\npaginate = { collection = \"ghost\", per_page = 2 }\n+++\n",
+            )],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error
+                .to_string()
+                .contains("Paginated collection is never used in any document: 'ghost'")
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn renders_a_listing_page_for_every_taxonomy_term() -> Result<()> {
+        let mut taxonomies = BTreeMap::new();
+
+        taxonomies.insert(
+            "tags".to_string(),
+            TaxonomyConfig {
+                layout: Some("TermLayout".to_string()),
+            },
+        );
+
+        let result = build_with(
+            &[
+                (
+                    "shortcodes/Layout.rhai",
+                    "fn template(context, props, content) { component { <main data-terms={context.taxonomy(\"tags\").terms.len()}>{content}</main> } }",
+                ),
+                (
+                    "shortcodes/TermLayout.rhai",
+                    "fn template(context, props, content) { component { <h1>{context.term.name}</h1><p>{context.term.documents.len()}</p> } }",
+                ),
+                (
+                    "content/a.md",
+                    "+++\ndescription = \"A\"\nlayout = \"Layout\"\ntitle = \"A\"\ntags = [\"Rust Lang\", \"web\"]\n+++\n\nA.\n",
+                ),
+                (
+                    "content/b.md",
+                    "+++\ndescription = \"B\"\nlayout = \"Layout\"\ntitle = \"B\"\ntags = [\"Rust Lang\"]\n+++\n\nB.\n",
+                ),
+            ],
+            |params| params.taxonomies = taxonomies,
+        )
+        .await?;
+
+        assert!(
+            read(&result, "a/index.html")
+                .await?
+                .contains("data-terms=\"2\"")
+        );
+
+        let term_page = read(&result, "tags/rust-lang/index.html").await?;
+
+        assert!(term_page.contains("<h1>Rust Lang</h1>"));
+        assert!(term_page.contains("<p>2</p>"));
+        assert!(read(&result, "tags/web/index.html").await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_on_taxonomy_that_is_not_configured() -> Result<()> {
+        let outcome = build(
+            &[(
+                "content/a.md",
+                "+++\ndescription = \"A\"\nlayout = \"Layout\"\ntitle = \"A\"\ncategories = [\"news\"]\n+++\n\nA.\n",
+            )],
+            false,
+        )
+        .await;
+
+        assert!(
+            outcome
+                .is_err_and(|error| { error.to_string().contains("unknown field `categories`") })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn links_to_headings_of_other_documents() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n[Install](guide#install)\n",
+                ),
+                (
+                    "content/guide.md",
+                    "+++\ndescription = \"Guide\"\nlayout = \"LayoutMinimal\"\ntitle = \"Guide\"\n+++\n\n## Getting the binary {#install}\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+
+        let home = read(&result, "index.html").await?;
+
+        assert!(home.contains(r#"<a href="/guide/#install">Install</a>"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_when_a_linked_heading_does_not_exist() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n[Install](guide#setup)\n",
+                ),
+                (
+                    "content/guide.md",
+                    "+++\ndescription = \"Guide\"\nlayout = \"LayoutMinimal\"\ntitle = \"Guide\"\n+++\n\n## Install\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error
+                .to_string()
+                .contains("Heading does not exist: #setup in 'guide'")
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn links_to_headings_of_the_same_page() -> Result<()> {
+        let files = |link: &str| {
+            [
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+                (
+                    "shortcodes/PrimaryNavigation.rhai",
+                    PRIMARY_NAVIGATION.to_string(),
+                ),
+                (
+                    "content/index.md",
+                    format!(
+                        "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n## Install\n\nSee [install]({link}).\n"
+                    ),
+                ),
+            ]
+        };
+
+        let existing = files("#install");
+        let result = build(
+            &existing
+                .each_ref()
+                .map(|(path, contents)| (*path, contents.as_str())),
+            false,
+        )
+        .await?;
+
+        assert!(
+            read(&result, "index.html")
+                .await?
+                .contains(r##"<a href="#install">install</a>"##)
+        );
+
+        let missing = files("#setup");
+        let outcome = build(
+            &missing
+                .each_ref()
+                .map(|(path, contents)| (*path, contents.as_str())),
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error.to_string().contains(
+                "- content/index.md:9:5\n- Neither a document id nor a heading of this document: #setup\n",
+            )
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_all_broken_links_with_their_source_positions() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n[Gone](gone)\n\n![Missing](missing.png)\n",
+                ),
+                (
+                    "content/guide.md",
+                    "+++\ndescription = \"Guide\"\nlayout = \"LayoutMinimal\"\ntitle = \"Guide\"\n+++\n\n## Install\n\nSee [home](index#nowhere).\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            let message = error.to_string();
+
+            message.contains("- content/index.md:7:1\n- Document does not exist: gone\n")
+                && message
+                    .contains("- content/index.md:9:1\n- Image asset not found: 'missing.png'\n")
+                && message.contains(
+                    "- content/guide.md:9:5\n- Heading does not exist: #nowhere in 'index'\n",
+                )
+                && message.contains("> 9 | See [home](index#nowhere).\n")
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn points_expression_errors_into_the_source_file() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nHello {context.front_matter.nope} there\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error.to_string().contains(
+                "- content/index.md:7:29\n- Expression failed: 'context.front_matter.nope'\n",
+            ) && error.to_string().contains(
+                "> 7 | Hello {context.front_matter.nope} there\n    |                             ^\n",
+            )
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn points_component_errors_into_the_component_file() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "shortcodes/Broken.rhai",
+                    "fn template(context, props, content) {\n  component { <p>{context.nope}</p> }\n}",
+                ),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n<Broken />\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            error.to_string().contains(
+                "- content/index.md:7:1\n- Component 'Broken' failed at shortcodes/Broken.rhai:2:27\n",
+            )
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_lints_as_warnings_without_failing_the_build() -> Result<()> {
+        let result = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n```nope\nwhat\n```\n",
+                ),
+            ],
+            false,
+        )
+        .await?;
+        let warnings: Vec<String> = result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_string())
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "content/index.md:7:1: No syntax found for language: nope [missing-syntax]"
+                    .to_string()
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exposes_document_errors_as_diagnostics() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\n[Gone](gone)\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            let diagnostics = Diagnostic::from_build_error(&error);
+
+            diagnostics.len() == 1
+                && diagnostics[0].code == DiagnosticCode::BrokenLink
+                && diagnostics[0].to_string()
+                    == "content/index.md:7:1: Document does not exist: gone [broken-link]"
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_undefined_link_references_at_their_position() -> Result<()> {
+        let outcome = build(
+            &[
+                ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL),
+                ("shortcodes/PrimaryNavigation.rhai", PRIMARY_NAVIGATION),
+                (
+                    "content/index.md",
+                    "+++\ndescription = \"Home\"\nlayout = \"LayoutMinimal\"\ntitle = \"Home\"\n+++\n\nRead [the docs][docs].\n",
+                ),
+            ],
+            false,
+        )
+        .await;
+
+        assert!(outcome.is_err_and(|error| {
+            let diagnostics = Diagnostic::from_build_error(&error);
+
+            diagnostics.len() == 1
+                && diagnostics[0].code == DiagnosticCode::UndefinedReference
+                && diagnostics[0].to_string()
+                    == "content/index.md:7:6: Undefined link reference: [docs] [undefined-reference]"
+        }));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_when_a_referenced_author_does_not_exist() -> Result<()> {
+        let outcome = build(
+            &[(
+                "content/a.md",
+                "+++\ndescription = \"d\"\nlayout = \"LayoutMinimal\"\ntitle = \"A\"\nauthors = [\"ghost\"]\n+++\n\nBody.\n",
+            )],
+            false,
+        )
+        .await;
+
+        assert!(
+            outcome.is_err_and(|error| {
+                error.to_string().contains("Author does not exist: 'ghost'")
+            })
+        );
+
+        Ok(())
+    }
+
+    async fn rebuild(
+        source_filesystem: Arc<Storage>,
+        rhai_template_renderer: RhaiTemplateRenderer,
+        previous_build_project_result: Option<BuildProjectResult>,
+    ) -> Result<BuildProjectResultStub> {
+        rebuild_with(
+            source_filesystem,
+            rhai_template_renderer,
+            previous_build_project_result,
+            |_| {},
+        )
+        .await
+    }
+
+    async fn rebuild_with(
+        source_filesystem: Arc<Storage>,
+        rhai_template_renderer: RhaiTemplateRenderer,
+        previous_build_project_result: Option<BuildProjectResult>,
+        configure: impl FnOnce(&mut BuildProjectParams),
+    ) -> Result<BuildProjectResultStub> {
+        let esbuild_metafile = match &previous_build_project_result {
+            Some(previous) => previous.esbuild_metafile.clone(),
+            None => Arc::new(Default::default()),
+        };
+        let authors = build_authors(source_filesystem.clone()).await?;
+        let mut params = BuildProjectParams {
+            asset_path_renderer: AssetPathRenderer {
+                base_path: "/".to_string(),
+            },
+            authors,
+            components: Default::default(),
+            esbuild_metafile,
+            feed_full_content: false,
+            generated_page_base_path: "/".to_string(),
+            generate_feeds: false,
+            generate_llms_txt: false,
+            generate_sitemap: false,
+            include_drafts: false,
+            is_watching: true,
+            previous_build_project_result,
+            rhai_template_renderer,
+            site: Default::default(),
+            source_filesystem,
+            taxonomies: Default::default(),
+        };
+
+        configure(&mut params);
+
+        build_project(params).await
+    }
+
+    fn page(title: &str, body: &str) -> String {
+        format!(
+            "+++\ndescription = \"d\"\nlayout = \"LayoutMinimal\"\ntitle = \"{title}\"\n+++\n\n{body}\n"
+        )
+    }
+
+    #[tokio::test]
+    async fn reuses_pages_whose_sources_and_dependencies_did_not_change() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            ("content/a.md", page("A", "First body.")),
+            ("content/b.md", page("B", "Second body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let first: BuildProjectResult = rebuild(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+        )
+        .await?
+        .into();
+
+        first
+            .memory_filesystem
+            .set_file_contents_sync(Path::new("a/index.html"), "previously rendered")?;
+
+        source_filesystem
+            .set_file_contents(Path::new("content/b.md"), &page("B", "Changed body."))
+            .await?;
+
+        let second = rebuild(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            Some(first),
+        )
+        .await?;
+
+        assert_eq!(read(&second, "a/index.html").await?, "previously rendered");
+        assert!(
+            read(&second, "b/index.html")
+                .await?
+                .contains("Changed body.")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reuses_pages_whose_full_contents_go_into_feeds() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            ("content/a.md", page("A", "First body.")),
+            ("content/b.md", page("B", "Second body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let with_full_feeds = |params: &mut BuildProjectParams| {
+            params.feed_full_content = true;
+            params.generate_feeds = true;
+        };
+        let first: BuildProjectResult = rebuild_with(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+            with_full_feeds,
+        )
+        .await?
+        .into();
+
+        first
+            .memory_filesystem
+            .set_file_contents_sync(Path::new("a/index.html"), "previously rendered")?;
+
+        source_filesystem
+            .set_file_contents(Path::new("content/b.md"), &page("B", "Changed body."))
+            .await?;
+
+        let second = rebuild_with(
+            source_filesystem,
+            rhai_template_renderer,
+            Some(first),
+            with_full_feeds,
+        )
+        .await?;
+        let feed = read(&second, "feed.xml").await?;
+
+        assert_eq!(read(&second, "a/index.html").await?, "previously rendered");
+        assert!(feed.contains("First body."));
+        assert!(feed.contains("Changed body."));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reuses_pages_whose_markdown_goes_into_llms_txt() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            ("content/a.md", page("A", "First body.")),
+            ("content/b.md", page("B", "Second body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let with_llms_txt = |params: &mut BuildProjectParams| params.generate_llms_txt = true;
+        let first: BuildProjectResult = rebuild_with(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+            with_llms_txt,
+        )
+        .await?
+        .into();
+
+        first
+            .memory_filesystem
+            .set_file_contents_sync(Path::new("a/index.html"), "previously rendered")?;
+
+        source_filesystem
+            .set_file_contents(Path::new("content/b.md"), &page("B", "Changed body."))
+            .await?;
+
+        let second = rebuild_with(
+            source_filesystem,
+            rhai_template_renderer,
+            Some(first),
+            with_llms_txt,
+        )
+        .await?;
+        let llms_full_txt = read(&second, "llms-full.txt").await?;
+
+        assert_eq!(read(&second, "a/index.html").await?, "previously rendered");
+        assert!(llms_full_txt.contains("First body."));
+        assert!(llms_full_txt.contains("Changed body."));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rerenders_scheduled_pages_even_when_unchanged() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            (
+                "content/a.md",
+                "+++\ndescription = \"d\"\nlayout = \"LayoutMinimal\"\npublish_at = \"2020-01-01\"\ntitle = \"A\"\n+++\n\nBody.\n".to_string(),
+            ),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let first: BuildProjectResult = rebuild(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+        )
+        .await?
+        .into();
+
+        first
+            .memory_filesystem
+            .set_file_contents_sync(Path::new("a/index.html"), "previously rendered")?;
+
+        let second = rebuild(source_filesystem, rhai_template_renderer, Some(first)).await?;
+
+        assert!(read(&second, "a/index.html").await?.contains("Body."));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rerenders_only_pages_linking_to_a_document_whose_front_matter_changed() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            ("content/a.md", page("A", "See [the second page](b).")),
+            ("content/b.md", page("B", "Second body.")),
+            ("content/c.md", page("C", "Third body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let first: BuildProjectResult = rebuild(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+        )
+        .await?
+        .into();
+
+        for relative_path in ["a/index.html", "c/index.html"] {
+            first
+                .memory_filesystem
+                .set_file_contents_sync(Path::new(relative_path), "previously rendered")?;
+        }
+
+        source_filesystem
+            .set_file_contents(
+                Path::new("content/b.md"),
+                &page("Renamed B", "Second body."),
+            )
+            .await?;
+
+        let second = rebuild(source_filesystem, rhai_template_renderer, Some(first)).await?;
+
+        assert!(
+            read(&second, "a/index.html")
+                .await?
+                .contains("the second page")
+        );
+        assert_eq!(read(&second, "c/index.html").await?, "previously rendered");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reuses_pages_linking_to_headings_that_only_moved() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            (
+                "content/a.md",
+                page("A", "See [the introduction](b#intro)."),
+            ),
+            ("content/b.md", page("B", "## Intro\n\nSecond body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let first: BuildProjectResult = rebuild(
+            source_filesystem.clone(),
+            rhai_template_renderer.clone(),
+            None,
+        )
+        .await?
+        .into();
+
+        first
+            .memory_filesystem
+            .set_file_contents_sync(Path::new("a/index.html"), "previously rendered")?;
+
+        source_filesystem
+            .set_file_contents(
+                Path::new("content/b.md"),
+                &page("B", "Added paragraph.\n\n## Intro\n\nSecond body."),
+            )
+            .await?;
+
+        let second = rebuild(source_filesystem, rhai_template_renderer, Some(first)).await?;
+
+        assert_eq!(read(&second, "a/index.html").await?, "previously rendered");
+        assert!(
+            read(&second, "b/index.html")
+                .await?
+                .contains("Added paragraph.")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rerenders_only_pages_using_a_changed_shortcode() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/LayoutMinimal.rhai", LAYOUT_MINIMAL.to_string()),
+            (
+                "shortcodes/Note.rhai",
+                "fn template(context, props, content) { component { <aside>{content}</aside> } }"
+                    .to_string(),
+            ),
+            (
+                "shortcodes/PrimaryNavigation.rhai",
+                PRIMARY_NAVIGATION.to_string(),
+            ),
+            ("content/a.md", page("A", "<Note>\nCareful.\n</Note>")),
+            ("content/b.md", page("B", "Second body.")),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), &contents)
+                .await?;
+        }
+
+        let first: BuildProjectResult = rebuild(
+            source_filesystem.clone(),
+            compile_shortcodes(source_filesystem.clone()).await?,
+            None,
+        )
+        .await?
+        .into();
+
+        for relative_path in ["a/index.html", "b/index.html"] {
+            first
+                .memory_filesystem
+                .set_file_contents_sync(Path::new(relative_path), "previously rendered")?;
+        }
+
+        source_filesystem
+            .set_file_contents(
+                Path::new("shortcodes/Note.rhai"),
+                "fn template(context, props, content) { component { <strong>{content}</strong> } }",
+            )
+            .await?;
+
+        let second = rebuild(
+            source_filesystem.clone(),
+            compile_shortcodes(source_filesystem).await?,
+            Some(first),
+        )
+        .await?;
+
+        assert!(read(&second, "a/index.html").await?.contains("<strong>"));
+        assert_eq!(read(&second, "b/index.html").await?, "previously rendered");
 
         Ok(())
     }
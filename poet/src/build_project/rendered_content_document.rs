@@ -0,0 +1,13 @@
+use crate::document_dependencies::DocumentDependencies;
+
+pub struct RenderedContentDocument {
+    /// Document contents, before they are put into the layout
+    pub content: String,
+    /// What rendering the document read
+    pub dependencies: DocumentDependencies,
+    /// Markdown alternate of the document, with expressions evaluated
+    pub markdown: String,
+    /// Markdown alternate, before the title is put above it
+    pub markdown_content: String,
+    pub page: String,
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash as _;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use esbuild_metafile::EsbuildMetaFile;
+use markdown::mdast::AttributeContent;
+use markdown::mdast::AttributeValue;
+use markdown::mdast::AttributeValueExpression;
+use markdown::mdast::MdxFlowExpression;
+use markdown::mdast::MdxJsxAttribute;
+use markdown::mdast::MdxJsxFlowElement;
+use markdown::mdast::MdxJsxTextElement;
+use markdown::mdast::MdxTextExpression;
+use markdown::mdast::Node;
+
+use crate::author::Author;
+use crate::author_collection::AuthorCollection;
+use crate::build_project::shortcode_source::ShortcodeSource;
+use crate::content_document::ContentDocument;
+use crate::content_document_collection_ranked::ContentDocumentCollectionRanked;
+use crate::content_document_linker::ContentDocumentLinker;
+use crate::content_document_reference::ContentDocumentReference;
+use crate::document_dependencies::DocumentDependencies;
+use crate::filesystem::file_entry::FileEntry;
+use crate::project_config::components_config::ComponentsConfig;
+use crate::taxonomy_index::TaxonomyIndex;
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|character: char| !character.is_alphanumeric() && character != '_')
+        .filter(|word| !word.is_empty())
+}
+
+/// Component tags, and anything expressions could call, are candidates.
+fn find_mentioned_names_in_mdast(mdast: &Node, names: &mut BTreeSet<String>) {
+    match mdast {
+        Node::MdxJsxFlowElement(MdxJsxFlowElement {
+            attributes, name, ..
+        })
+        | Node::MdxJsxTextElement(MdxJsxTextElement {
+            attributes, name, ..
+        }) => {
+            names.extend(name.clone());
+
+            for attribute in attributes {
+                if let AttributeContent::Property(MdxJsxAttribute {
+                    value: Some(AttributeValue::Expression(AttributeValueExpression { value, .. })),
+                    ..
+                }) = attribute
+                {
+                    names.extend(words(value).map(str::to_string));
+                }
+            }
+        }
+        Node::MdxFlowExpression(MdxFlowExpression { value, .. })
+        | Node::MdxTextExpression(MdxTextExpression { value, .. }) => {
+            names.extend(words(value).map(str::to_string));
+        }
+        _ => {}
+    }
+
+    if let Some(children) = mdast.children() {
+        for child in children {
+            find_mentioned_names_in_mdast(child, names);
+        }
+    }
+}
+
+/// Links to a document depend on its front matter, and on its headings
+/// when a fragment is validated, but not on where those headings are.
+fn hash_reference(reference: &ContentDocumentReference, hasher: &mut impl Hasher) {
+    reference.basename_path.hash(hasher);
+    reference.front_matter.hash(hasher);
+    reference.generated_page_base_path.hash(hasher);
+}
+
+/// Everything pages are rendered from, besides their own source files. Each
+/// page is fingerprinted with just the parts it read, so a change renders
+/// again only the pages that depend on it.
+#[derive(Clone)]
+pub struct RenderingDependencies {
+    pub authors: Arc<AuthorCollection>,
+    pub components: Arc<ComponentsConfig>,
+    pub content_document_collections_ranked: Arc<HashMap<String, ContentDocumentCollectionRanked>>,
+    pub content_document_linker: ContentDocumentLinker,
+    pub esbuild_metafile: Arc<EsbuildMetaFile>,
+    pub shortcodes: Arc<HashMap<String, ShortcodeSource>>,
+    pub taxonomy_index: Arc<TaxonomyIndex>,
+}
+
+impl RenderingDependencies {
+    pub fn shortcode_sources(
+        shortcode_files: &HashMap<String, FileEntry>,
+    ) -> HashMap<String, ShortcodeSource> {
+        shortcode_files
+            .iter()
+            .map(|(name, file)| {
+                (
+                    name.clone(),
+                    ShortcodeSource {
+                        contents_hash: file.contents_hash,
+                        mentioned_shortcodes: words(&file.contents)
+                            .filter(|word| word != name && shortcode_files.contains_key(*word))
+                            .map(str::to_string)
+                            .collect(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn fingerprint(
+        &self,
+        content_document: &ContentDocument,
+        found_authors: &[Author],
+        DocumentDependencies {
+            available_authors,
+            collections,
+            links,
+            taxonomies,
+        }: &DocumentDependencies,
+    ) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        found_authors.hash(&mut hasher);
+
+        if *available_authors {
+            self.authors.hash(&mut hasher);
+        }
+
+        for collection_name in collections {
+            let collection = self
+                .content_document_collections_ranked
+                .get(collection_name);
+
+            collection_name.hash(&mut hasher);
+            collection.is_some().hash(&mut hasher);
+
+            for reference in collection
+                .iter()
+                .flat_map(|collection| &collection.hierarchy.flat)
+            {
+                hash_reference(reference, &mut hasher);
+            }
+        }
+
+        for path in links {
+            path.hash(&mut hasher);
+
+            let basename = self.content_document_linker.linked_basename(path);
+
+            basename.hash(&mut hasher);
+
+            if let Some(basename) = basename {
+                if let Some(reference) = self
+                    .content_document_linker
+                    .content_document_by_basename
+                    .get(&basename)
+                {
+                    hash_reference(reference, &mut hasher);
+                }
+
+                if let Some(heading_ids) = self
+                    .content_document_linker
+                    .heading_ids_by_basename
+                    .get(&basename)
+                {
+                    for heading_id in heading_ids.ids_by_offset.values() {
+                        heading_id.hash(&mut hasher);
+                    }
+                }
+            }
+        }
+
+        for taxonomy_name in taxonomies {
+            taxonomy_name.hash(&mut hasher);
+
+            if let Some(taxonomy) = self.taxonomy_index.taxonomies.get(taxonomy_name) {
+                taxonomy.layout.hash(&mut hasher);
+
+                for (slug, term) in &taxonomy.terms {
+                    slug.hash(&mut hasher);
+                    term.name.hash(&mut hasher);
+
+                    for reference in &term.documents {
+                        hash_reference(reference, &mut hasher);
+                    }
+                }
+            }
+        }
+
+        for shortcode_name in self.shortcodes_used_by(content_document) {
+            shortcode_name.hash(&mut hasher);
+            self.shortcodes
+                .get(&shortcode_name)
+                .map(|shortcode| shortcode.contents_hash)
+                .hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Pages rendered with a different asset manifest might point to assets
+    /// that are gone.
+    pub fn is_unchanged_since(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.esbuild_metafile, &other.esbuild_metafile)
+    }
+
+    /// Names that are not shortcodes yet are kept, so adding a shortcode
+    /// with one of their names is noticed.
+    fn shortcodes_used_by(&self, content_document: &ContentDocument) -> BTreeSet<String> {
+        let ComponentsConfig {
+            admonition,
+            endnotes,
+            math,
+            table,
+        } = self.components.as_ref();
+        let mut pending: BTreeSet<String> = [admonition, endnotes, math, table]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain([content_document.reference.front_matter.layout.clone()])
+            .collect();
+
+        find_mentioned_names_in_mdast(&content_document.mdast, &mut pending);
+
+        let mut used: BTreeSet<String> = BTreeSet::new();
+
+        while let Some(name) = pending.pop_first() {
+            if let Some(shortcode) = self.shortcodes.get(&name) {
+                pending.extend(
+                    shortcode
+                        .mentioned_shortcodes
+                        .iter()
+                        .filter(|mentioned| !used.contains(*mentioned))
+                        .cloned(),
+                );
+            }
+
+            used.insert(name);
+        }
+
+        used
+    }
+}
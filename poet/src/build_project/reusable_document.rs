@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use crate::build_project::document_fingerprint::DocumentFingerprint;
+
+/// What the previous build produced for a document that can be reused.
+pub struct ReusableDocument {
+    /// Only kept when feeds include full contents
+    pub feed_content: Option<String>,
+    pub files: Vec<(PathBuf, String)>,
+    pub fingerprint: DocumentFingerprint,
+    /// Only kept when llms.txt is generated
+    pub markdown_content: Option<String>,
+}
@@ -0,0 +1,10 @@
+use std::collections::BTreeSet;
+
+use blake3::Hash;
+
+/// A shortcode file, with the other shortcodes its source mentions.
+#[derive(Clone)]
+pub struct ShortcodeSource {
+    pub contents_hash: Hash,
+    pub mentioned_shortcodes: BTreeSet<String>,
+}
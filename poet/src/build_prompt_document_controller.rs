@@ -25,6 +25,7 @@ pub fn build_prompt_document_controller(
         asset_path_renderer,
         content_document_linker,
         esbuild_metafile,
+        file_entry: file,
         front_matter,
         name,
         mdast,
@@ -0,0 +1,8 @@
+use crate::diagnostic::Diagnostic;
+use crate::mcp::prompt_controller_collection::PromptControllerCollection;
+
+pub struct BuildPromptControllerCollectionResult {
+    pub prompt_controller_collection: PromptControllerCollection,
+    /// Problems that did not fail the build.
+    pub warnings: Vec<Diagnostic>,
+}
@@ -1,6 +1,6 @@
 pub mod build_prompt_document_controller_collection_params;
+pub mod build_prompt_document_controller_collection_result;
 
-use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -12,12 +12,16 @@ use rayon::iter::ParallelIterator as _;
 
 use crate::build_prompt_document_controller::build_prompt_document_controller;
 use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_params::BuildPromptControllerCollectionParams;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_result::BuildPromptControllerCollectionResult;
 use crate::build_prompt_document_controller_params::BuildPromptDocumentControllerParams;
 use crate::build_timer::BuildTimer;
+use crate::content_document_lint::ContentDocumentLint;
+use crate::content_document_lint::lint_content_document_mdast::lint_prompt_document_mdast;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
 use crate::document_error_collection::DocumentErrorCollection;
 use crate::filesystem::Filesystem as _;
 use crate::mcp::prompt_controller::PromptController;
-use crate::mcp::prompt_controller_collection::PromptControllerCollection;
+use crate::source_position_error::SourcePositionError;
 
 pub async fn build_prompt_document_controller_collection(
     BuildPromptControllerCollectionParams {
@@ -27,7 +31,7 @@ pub async fn build_prompt_document_controller_collection(
         rhai_template_renderer,
         source_filesystem,
     }: BuildPromptControllerCollectionParams,
-) -> Result<PromptControllerCollection> {
+) -> Result<BuildPromptControllerCollectionResult> {
     info!("Processing prompt files...");
 
     let _build_timer = BuildTimer::default();
@@ -41,7 +45,7 @@ pub async fn build_prompt_document_controller_collection(
         .filter(|file| file.kind.is_prompt())
         .for_each(|file| {
             let name = file
-                .get_stem_path_relative_to(&PathBuf::from("prompts"))
+                .get_stem_path_relative_to(&source_filesystem.directories.prompts)
                 .display()
                 .to_string();
 
@@ -54,19 +58,36 @@ pub async fn build_prompt_document_controller_collection(
                 rhai_template_renderer: rhai_template_renderer.clone(),
             }) {
                 Ok(prompt_document_controller) => {
+                    for ContentDocumentLint {
+                        code,
+                        message,
+                        position,
+                    } in lint_prompt_document_mdast(&prompt_document_controller.mdast)
+                    {
+                        error_collection.register_warning(
+                            name.clone(),
+                            code,
+                            &prompt_document_controller.file_entry,
+                            SourcePositionError::attach(anyhow!(message), position.as_ref()),
+                        );
+                    }
+
                     prompt_controller_map.insert(name, Arc::new(prompt_document_controller));
                 }
                 Err(err) => {
-                    error_collection.register_error(name, err);
+                    error_collection.register_error(name, DiagnosticCode::InvalidPrompt, err);
                 }
             }
         });
 
     if !error_collection.is_empty() {
-        return Err(anyhow!("{error_collection}"));
+        return Err(error_collection.into());
     }
 
-    Ok(prompt_controller_map.into())
+    Ok(BuildPromptControllerCollectionResult {
+        prompt_controller_collection: prompt_controller_map.into(),
+        warnings: error_collection.diagnostics(),
+    })
 }
 
 #[cfg(test)]
@@ -81,10 +102,11 @@ mod tests {
     use crate::filesystem::storage::Storage;
     use crate::mcp::list_resources_cursor::ListResourcesCursor;
 
-    async fn build(prompt_files: &[(&str, &str)]) -> Result<PromptControllerCollection> {
+    async fn build(prompt_files: &[(&str, &str)]) -> Result<BuildPromptControllerCollectionResult> {
         let directory = tempdir()?;
         let source_filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         for (relative_path, contents) in prompt_files {
@@ -109,19 +131,23 @@ mod tests {
 
     #[tokio::test]
     async fn builds_a_controller_for_each_prompt_file() -> Result<()> {
-        let collection = build(&[(
+        let BuildPromptControllerCollectionResult {
+            prompt_controller_collection,
+            warnings,
+        } = build(&[(
             "prompts/greet.md",
             "+++\narguments = {}\ndescription = \"Greeting\"\ntitle = \"Greet\"\n+++\n\n**user**: hello\n",
         )])
         .await?;
 
-        let prompts = collection.list_mcp_prompts(ListResourcesCursor {
+        let prompts = prompt_controller_collection.list_mcp_prompts(ListResourcesCursor {
             offset: 0,
             per_page: 10,
         });
 
         assert_eq!(prompts.len(), 1);
         assert_eq!(prompts[0].name, "greet");
+        assert!(warnings.is_empty());
 
         Ok(())
     }
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::asset_path_renderer::AssetPathRenderer;
+use crate::build_authors::build_authors;
+use crate::build_project::build_project;
+use crate::build_project::build_project_params::BuildProjectParams;
+use crate::build_project::build_project_result_stub::BuildProjectResultStub;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_params::BuildPromptControllerCollectionParams;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_result::BuildPromptControllerCollectionResult;
+use crate::compile_shortcodes::compile_shortcodes;
+use crate::diagnostic::Diagnostic;
+use crate::filesystem::storage::Storage;
+use crate::project_config::ProjectConfig;
+use crate::read_esbuild_metafile_or_default::read_esbuild_metafile_or_default;
+
+/// Builds everything in memory, and returns the warnings of a successful
+/// build.
+pub async fn check_project(
+    source_filesystem: Arc<Storage>,
+    project_config: &ProjectConfig,
+) -> Result<Vec<Diagnostic>> {
+    let asset_path_renderer = AssetPathRenderer {
+        base_path: "/".to_string(),
+    };
+    let esbuild_metafile = read_esbuild_metafile_or_default(source_filesystem.clone()).await?;
+    let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+    let authors = build_authors(source_filesystem.clone()).await?;
+
+    let BuildProjectResultStub {
+        content_document_linker,
+        mut warnings,
+        ..
+    } = build_project(BuildProjectParams {
+        asset_path_renderer: asset_path_renderer.clone(),
+        authors,
+        components: project_config.components.clone(),
+        esbuild_metafile: esbuild_metafile.clone(),
+        feed_full_content: false,
+        generated_page_base_path: "/".to_string(),
+        generate_feeds: false,
+        generate_llms_txt: false,
+        generate_sitemap: false,
+        include_drafts: true,
+        is_watching: false,
+        previous_build_project_result: None,
+        rhai_template_renderer: rhai_template_renderer.clone(),
+        site: project_config.site.clone(),
+        source_filesystem: source_filesystem.clone(),
+        taxonomies: project_config.taxonomies.clone(),
+    })
+    .await?;
+
+    let BuildPromptControllerCollectionResult {
+        warnings: prompt_warnings,
+        ..
+    } = build_prompt_document_controller_collection(BuildPromptControllerCollectionParams {
+        asset_path_renderer,
+        content_document_linker,
+        esbuild_metafile,
+        rhai_template_renderer,
+        source_filesystem,
+    })
+    .await?;
+
+    warnings.extend(prompt_warnings);
+
+    Ok(warnings)
+}
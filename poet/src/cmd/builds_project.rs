@@ -2,21 +2,20 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::filesystem::storage::Storage;
+use crate::project_config::ProjectConfig;
 
 pub trait BuildsProject {
     fn source_directory(&self) -> PathBuf;
 
-    fn assets_directory(&self) -> PathBuf {
-        let mut static_files_directory: PathBuf = self.source_directory().clone();
-
-        static_files_directory.push("assets");
-
-        static_files_directory
+    fn assets_directory(&self, project_config: &ProjectConfig) -> PathBuf {
+        self.source_directory()
+            .join(&project_config.server.assets_path)
     }
 
-    fn source_filesystem(&self) -> Arc<Storage> {
+    fn source_filesystem(&self, project_config: &ProjectConfig) -> Arc<Storage> {
         Arc::new(Storage {
             base_directory: self.source_directory(),
+            directories: project_config.directories.clone(),
         })
     }
 }
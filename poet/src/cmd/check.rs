@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use log::info;
+
+use crate::check_project::check_project;
+use crate::cmd::builds_project::BuildsProject;
+use crate::cmd::diagnostics_format::DiagnosticsFormat;
+use crate::cmd::handler::Handler;
+use crate::cmd::value_parser::validate_is_directory;
+use crate::project_config::ProjectConfig;
+
+#[derive(Parser)]
+pub struct Check {
+    /// Fail when the project builds, but with warnings
+    #[arg(long, default_value = "false")]
+    deny_warnings: bool,
+
+    #[arg(long, value_enum, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+
+    #[arg(value_parser = validate_is_directory)]
+    source_directory: PathBuf,
+}
+
+impl BuildsProject for Check {
+    fn source_directory(&self) -> PathBuf {
+        self.source_directory.clone()
+    }
+}
+
+#[async_trait(?Send)]
+impl Handler for Check {
+    async fn handle(&self) -> Result<()> {
+        let project_config = ProjectConfig::load(&self.source_directory).await?;
+        let warnings = check_project(self.source_filesystem(&project_config), &project_config)
+            .await
+            .map_err(|err| self.diagnostics_format.report_build_error(err))?;
+
+        self.diagnostics_format.report(&warnings)?;
+
+        if self.deny_warnings && !warnings.is_empty() {
+            return Err(anyhow!(
+                "Check failed, {} warning(s) denied by --deny-warnings",
+                warnings.len()
+            ));
+        }
+
+        info!("Project is valid");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use tempfile::TempDir;
+    use tempfile::tempdir;
+    use tokio::fs;
+
+    use super::*;
+
+    const LAYOUT: &str =
+        "fn template(context, props, content) { component { <html>{content}</html> } }";
+
+    async fn project(body: &str) -> Result<TempDir> {
+        let directory = tempdir()?;
+
+        for (relative_path, contents) in [
+            ("shortcodes/Layout.rhai", LAYOUT.to_string()),
+            (
+                "content/index.md",
+                format!(
+                    "+++\ndescription = \"Home\"\nlayout = \"Layout\"\ntitle = \"Home\"\n+++\n\n{body}\n"
+                ),
+            ),
+        ] {
+            let path = directory.path().join(relative_path);
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+
+            fs::write(path, contents).await?;
+        }
+
+        Ok(directory)
+    }
+
+    fn check(source_directory: &Path, deny_warnings: bool) -> Check {
+        Check {
+            deny_warnings,
+            diagnostics_format: DiagnosticsFormat::Human,
+            source_directory: source_directory.to_path_buf(),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_on_errors() -> Result<()> {
+        let directory = project("[Broken](missing-document)").await?;
+
+        assert!(
+            check(directory.path(), false)
+                .handle()
+                .await
+                .is_err_and(|err| {
+                    format!("{err:#}").contains("Document does not exist: missing-document")
+                })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passes_with_warnings_by_default() -> Result<()> {
+        let directory = project("```not-a-language\ncode\n```").await?;
+
+        check(directory.path(), false).handle().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_on_warnings_when_they_are_denied() -> Result<()> {
+        let directory = project("```not-a-language\ncode\n```").await?;
+
+        assert!(
+            check(directory.path(), true)
+                .handle()
+                .await
+                .is_err_and(|err| {
+                    err.to_string()
+                        .contains("1 warning(s) denied by --deny-warnings")
+                })
+        );
+        check(project("Valid.").await?.path(), true)
+            .handle()
+            .await?;
+
+        Ok(())
+    }
+}
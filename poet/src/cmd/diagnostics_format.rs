@@ -0,0 +1,50 @@
+use anyhow::Result;
+use anyhow::anyhow;
+use clap::ValueEnum;
+use log::warn;
+
+use crate::diagnostic::Diagnostic;
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum DiagnosticsFormat {
+    /// Warnings are logged, errors are reported with code frames
+    #[default]
+    Human,
+    /// One JSON record per diagnostic on stdout
+    Json,
+}
+
+impl DiagnosticsFormat {
+    pub fn report(&self, diagnostics: &[Diagnostic]) -> Result<()> {
+        for diagnostic in diagnostics {
+            match self {
+                Self::Human if diagnostic.is_error() => {}
+                Self::Human => warn!("{diagnostic}"),
+                Self::Json => println!("{}", serde_json::to_string(diagnostic)?),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports everything that went wrong in a failed build, and returns the
+    /// error that should be displayed to the user.
+    pub fn report_build_error(&self, err: anyhow::Error) -> anyhow::Error {
+        let diagnostics = Diagnostic::from_build_error(&err);
+
+        if let Err(report_err) = self.report(&diagnostics) {
+            return report_err.context(err);
+        }
+
+        match self {
+            Self::Human => err,
+            Self::Json => anyhow!(
+                "Build failed with {} error(s)",
+                diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.is_error())
+                    .count()
+            ),
+        }
+    }
+}
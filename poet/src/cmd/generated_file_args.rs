@@ -0,0 +1,34 @@
+use clap::Args;
+
+use crate::cmd::generated_files::GeneratedFiles;
+use crate::project_config::build_config::BuildConfig;
+
+/// Optional files every build command can generate. Flags that are not
+/// given fall back to `[build]` in poet.toml.
+#[derive(Args)]
+pub struct GeneratedFileArgs {
+    /// Put rendered documents into feed entries, not just their descriptions
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    feed_full_content: Option<bool>,
+
+    /// Emit Atom feeds for the whole site and for every collection
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    feeds: Option<bool>,
+
+    /// Emit `llms.txt` and `llms-full.txt` for LLM crawlers
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    llms_txt: Option<bool>,
+}
+
+impl GeneratedFileArgs {
+    pub fn resolve(&self, build_config: &BuildConfig) -> GeneratedFiles {
+        GeneratedFiles {
+            feed_full_content: self
+                .feed_full_content
+                .or(build_config.feed_full_content)
+                .unwrap_or(false),
+            feeds: self.feeds.or(build_config.feeds).unwrap_or(false),
+            llms_txt: self.llms_txt.or(build_config.llms_txt).unwrap_or(false),
+        }
+    }
+}
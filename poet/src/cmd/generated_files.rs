@@ -0,0 +1,5 @@
+pub struct GeneratedFiles {
+    pub feed_full_content: bool,
+    pub feeds: bool,
+    pub llms_txt: bool,
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use tokio::io::BufReader;
+use tokio::io::stdin;
+use tokio::io::stdout;
+
+use crate::cmd::builds_project::BuildsProject;
+use crate::cmd::handler::Handler;
+use crate::cmd::value_parser::validate_is_directory;
+use crate::filesystem::storage::Storage;
+use crate::lsp::language_server::LanguageServer;
+use crate::project_config::ProjectConfig;
+
+#[derive(Parser)]
+pub struct Lsp {
+    #[arg(value_parser = validate_is_directory)]
+    source_directory: PathBuf,
+}
+
+impl BuildsProject for Lsp {
+    fn source_directory(&self) -> PathBuf {
+        self.source_directory.clone()
+    }
+}
+
+#[async_trait(?Send)]
+impl Handler for Lsp {
+    async fn handle(&self) -> Result<()> {
+        // editors identify documents by absolute file URIs
+        let base_directory = self.source_directory().canonicalize()?;
+        let project_config = ProjectConfig::load(&base_directory).await?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory,
+            directories: project_config.directories.clone(),
+        });
+
+        LanguageServer::new(source_filesystem, project_config)
+            .serve(BufReader::new(stdin()), stdout())
+            .await
+    }
+}
@@ -19,6 +19,8 @@ use crate::cmd::value_parser::validate_is_directory_or_create;
 use crate::copy_esbuild_metafile_assets_to::copy_esbuild_metafile_assets_to;
 use crate::filesystem::Filesystem;
 use crate::filesystem::storage::Storage;
+use crate::project_config::PROJECT_CONFIG_FILE_NAME;
+use crate::project_config::ProjectConfig;
 use crate::read_esbuild_metafile_or_default::read_esbuild_metafile_or_default;
 
 const ICON: &str = r#"<svg viewBox="0 0 10 10" fill="none" xmlns="http://www.w3.org/2000/svg">
@@ -68,25 +70,22 @@ impl AppDir {
                             ;;
                         *)
                             echo "Unknown argument: $1"
-                            echo "Usage: $0 --addr ADDRESS --public-path PATH"
+                            echo "Usage: $0 [--addr ADDRESS] [--public-path PATH]"
                             exit 1
                             ;;
                     esac
                 done
 
-                if [ -z "$ADDR" ]; then
-                    echo "Error: --addr is required"
-                    echo "Usage: $0 --addr ADDRESS --public-path PATH"
-                    exit 1
+                # Both can also be set in poet.toml
+                if [ -n "$ADDR" ]; then
+                    set -- "$@" --addr "$ADDR"
                 fi
 
-                if [ -z "$PUBLIC_PATH" ]; then
-                    echo "Error: --public-path is required"
-                    echo "Usage: $0 --addr ADDRESS --public-path PATH"
-                    exit 1
+                if [ -n "$PUBLIC_PATH" ]; then
+                    set -- "$@" --public-path "$PUBLIC_PATH"
                 fi
 
-                exec $APPDIR/poet serve $APPDIR --addr "$ADDR" --app-name "{name}" --public-path "$PUBLIC_PATH"
+                exec $APPDIR/poet serve $APPDIR --app-name "{name}" "$@"
             "#,
             name = self.name,
         })
@@ -113,11 +112,13 @@ impl BuildsProject for AppDir {
 impl Handler for AppDir {
     async fn handle(&self) -> Result<()> {
         let app_dir_path = self.app_dir_path();
-        let source_filesystem = self.source_filesystem();
+        let project_config = ProjectConfig::load(&self.source_directory).await?;
+        let source_filesystem = self.source_filesystem(&project_config);
         let name_lowercase = self.name.to_lowercase();
 
         let app_dir_filesystem = Arc::new(Storage {
             base_directory: app_dir_path.clone(),
+            directories: project_config.directories.clone(),
         });
 
         info!("Copying project files to AppDir...");
@@ -132,6 +133,19 @@ impl Handler for AppDir {
             )
             .await?;
 
+        if self
+            .source_directory
+            .join(PROJECT_CONFIG_FILE_NAME)
+            .exists()
+        {
+            app_dir_filesystem
+                .copy_file_from(
+                    source_filesystem.clone(),
+                    &PathBuf::from(PROJECT_CONFIG_FILE_NAME),
+                )
+                .await?;
+        }
+
         info!("Copying assets to AppDir...");
 
         let esbuild_metafile = read_esbuild_metafile_or_default(source_filesystem.clone()).await?;
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use anyhow::Result;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use clap::Parser;
 use log::info;
@@ -11,6 +12,9 @@ use crate::build_project::build_project;
 use crate::build_project::build_project_params::BuildProjectParams;
 use crate::build_project::build_project_result_stub::BuildProjectResultStub;
 use crate::cmd::builds_project::BuildsProject;
+use crate::cmd::diagnostics_format::DiagnosticsFormat;
+use crate::cmd::generated_file_args::GeneratedFileArgs;
+use crate::cmd::generated_files::GeneratedFiles;
 use crate::cmd::handler::Handler;
 use crate::cmd::value_parser::validate_is_directory;
 use crate::cmd::value_parser::validate_is_directory_or_create;
@@ -18,18 +22,30 @@ use crate::compile_shortcodes::compile_shortcodes;
 use crate::copy_esbuild_metafile_assets_to::copy_esbuild_metafile_assets_to;
 use crate::filesystem::Filesystem;
 use crate::filesystem::storage::Storage;
+use crate::project_config::ProjectConfig;
 use crate::read_esbuild_metafile_or_default::read_esbuild_metafile_or_default;
 
 #[derive(Parser)]
 pub struct StaticPages {
+    #[arg(long, value_enum, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+
     #[arg(long, value_parser = validate_is_directory_or_create)]
     output_directory: PathBuf,
 
-    #[arg(long)]
-    public_path: String,
+    #[command(flatten)]
+    generated_files: GeneratedFileArgs,
 
+    /// Build drafts and documents scheduled for later, for previews
     #[arg(long, default_value = "false")]
-    sitemap: bool,
+    include_drafts: bool,
+
+    /// Defaults to `build.public_path` or `site.base_url` from poet.toml
+    #[arg(long)]
+    public_path: Option<String>,
+
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    sitemap: Option<bool>,
 
     #[arg(value_parser = validate_is_directory)]
     source_directory: PathBuf,
@@ -44,30 +60,63 @@ impl BuildsProject for StaticPages {
 #[async_trait(?Send)]
 impl Handler for StaticPages {
     async fn handle(&self) -> Result<()> {
-        let source_filesystem = self.source_filesystem();
-        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
-        let authors = build_authors(source_filesystem.clone()).await?;
+        let project_config = ProjectConfig::load(&self.source_directory).await?;
+        let GeneratedFiles {
+            feed_full_content,
+            feeds,
+            llms_txt,
+        } = self.generated_files.resolve(&project_config.build);
+        let public_path = self
+            .public_path
+            .clone()
+            .or_else(|| project_config.public_path())
+            .ok_or_else(|| {
+                anyhow!("Public path is not set, use --public-path or set it in poet.toml")
+            })?;
+        let source_filesystem = self.source_filesystem(&project_config);
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone())
+            .await
+            .map_err(|err| self.diagnostics_format.report_build_error(err))?;
+        let authors = build_authors(source_filesystem.clone())
+            .await
+            .map_err(|err| self.diagnostics_format.report_build_error(err))?;
 
         let BuildProjectResultStub {
             esbuild_metafile,
             memory_filesystem,
+            warnings,
             ..
         } = build_project(BuildProjectParams {
             asset_path_renderer: AssetPathRenderer {
-                base_path: self.public_path.clone(),
+                base_path: public_path.clone(),
             },
             authors,
+            components: project_config.components.clone(),
             esbuild_metafile: read_esbuild_metafile_or_default(source_filesystem.clone()).await?,
-            generated_page_base_path: self.public_path.clone(),
-            generate_sitemap: self.sitemap,
+            feed_full_content,
+            generated_page_base_path: public_path,
+            generate_feeds: feeds,
+            generate_llms_txt: llms_txt,
+            generate_sitemap: self
+                .sitemap
+                .or(project_config.build.sitemap)
+                .unwrap_or(false),
+            include_drafts: self.include_drafts,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: project_config.site,
             source_filesystem,
+            taxonomies: project_config.taxonomies,
         })
-        .await?;
+        .await
+        .map_err(|err| self.diagnostics_format.report_build_error(err))?;
+
+        self.diagnostics_format.report(&warnings)?;
 
         let storage = Storage {
             base_directory: self.output_directory.clone(),
+            directories: Default::default(),
         };
 
         info!("Saving generated files in output directory...");
@@ -1,6 +1,12 @@
 mod builds_project;
+pub mod check;
+mod diagnostics_format;
+mod generated_file_args;
+mod generated_files;
 pub mod handler;
+pub mod lsp;
 pub mod make;
+mod prefers_markdown;
 mod respond_with_generated_page;
 mod respond_with_generated_page_holder;
 pub mod serve;
@@ -8,5 +14,3 @@ mod service;
 mod service_manager;
 mod value_parser;
 pub mod watch;
-
-const STATIC_FILES_PUBLIC_PATH: &str = "assets";
@@ -0,0 +1,52 @@
+use actix_web::HttpRequest;
+use actix_web::http::header::Accept;
+use actix_web::http::header::Header as _;
+
+/// Browsers never ask for Markdown explicitly, so wildcards do not count.
+pub fn prefers_markdown(req: &HttpRequest) -> bool {
+    let accept = match Accept::parse(req) {
+        Ok(accept) => accept,
+        Err(_) => return false,
+    };
+    let ranked = accept.ranked();
+    let position_of = |essence: &str| ranked.iter().position(|mime| mime.essence_str() == essence);
+
+    match (position_of("text/markdown"), position_of("text/html")) {
+        (Some(markdown), Some(html)) => markdown < html,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header::ACCEPT;
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn request_accepting(accept: &str) -> HttpRequest {
+        TestRequest::default()
+            .insert_header((ACCEPT, accept))
+            .to_http_request()
+    }
+
+    #[test]
+    fn prefers_markdown_when_requested_explicitly() {
+        assert!(prefers_markdown(&request_accepting("text/markdown")));
+        assert!(prefers_markdown(&request_accepting(
+            "text/markdown, text/html;q=0.9"
+        )));
+    }
+
+    #[test]
+    fn does_not_prefer_markdown_over_html_or_wildcards() {
+        assert!(!prefers_markdown(&request_accepting(
+            "text/html, application/xhtml+xml, */*;q=0.8"
+        )));
+        assert!(!prefers_markdown(&request_accepting(
+            "text/html, text/markdown;q=0.5"
+        )));
+        assert!(!prefers_markdown(&TestRequest::default().to_http_request()));
+    }
+}
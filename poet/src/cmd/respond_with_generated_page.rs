@@ -2,22 +2,39 @@ use std::sync::Arc;
 
 use actix_web::HttpResponse;
 use actix_web::Result;
+use actix_web::http::header::VARY;
 
 use crate::filesystem::file_entry::FileEntry;
 use crate::filesystem_http_route_index::FilesystemHttpRouteIndex;
 
+/// Pages that have a Markdown alternate are negotiated by the `Accept` header.
 pub fn respond_with_generated_page(
     filesystem_http_route_index: Arc<FilesystemHttpRouteIndex>,
     path: String,
+    prefers_markdown: bool,
 ) -> Result<HttpResponse> {
-    match filesystem_http_route_index.get_file_entry_for_path(&path) {
+    let markdown_alternate = filesystem_http_route_index.get_markdown_alternate_for_path(&path);
+    let file_entry = match markdown_alternate {
+        Some(ref markdown_alternate) if prefers_markdown => Some(markdown_alternate.clone()),
+        _ => filesystem_http_route_index.get_file_entry_for_path(&path),
+    };
+
+    match file_entry {
         Some(FileEntry {
             contents,
             relative_path,
             ..
-        }) => Ok(HttpResponse::Ok()
-            .content_type(mime_guess::from_path(relative_path).first_or_octet_stream())
-            .body(contents)),
+        }) => {
+            let mut response = HttpResponse::Ok();
+
+            if markdown_alternate.is_some() {
+                response.insert_header((VARY, "Accept"));
+            }
+
+            Ok(response
+                .content_type(mime_guess::from_path(relative_path).first_or_octet_stream())
+                .body(contents))
+        }
         None => Ok(HttpResponse::NotFound().body("File not found")),
     }
 }
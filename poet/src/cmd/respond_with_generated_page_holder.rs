@@ -8,10 +8,11 @@ use crate::holder::Holder as _;
 pub async fn respond_with_generated_page_holder(
     filesystem_http_route_index_holder: FilesystemHttpRouteIndexHolder,
     path: String,
+    prefers_markdown: bool,
 ) -> Result<HttpResponse> {
     match filesystem_http_route_index_holder.get().await {
         Some(filesystem_http_route_index) => {
-            respond_with_generated_page(filesystem_http_route_index, path)
+            respond_with_generated_page(filesystem_http_route_index, path, prefers_markdown)
         }
         None => Ok(HttpResponse::ServiceUnavailable()
             .body("Server is still starting up, or there are no successful builds yet")),
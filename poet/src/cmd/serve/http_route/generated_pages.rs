@@ -1,3 +1,4 @@
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Result;
 use actix_web::get;
@@ -5,6 +6,7 @@ use actix_web::web;
 use actix_web::web::Data;
 use actix_web::web::Path;
 
+use crate::cmd::prefers_markdown::prefers_markdown;
 use crate::cmd::respond_with_generated_page::respond_with_generated_page;
 use crate::cmd::serve::app_data::AppData;
 
@@ -13,9 +15,14 @@ pub fn register(cfg: &mut web::ServiceConfig) {
 }
 
 #[get("/{path:.*}")]
-async fn respond(app_data: Data<AppData>, path: Path<String>) -> Result<HttpResponse> {
+async fn respond(
+    app_data: Data<AppData>,
+    path: Path<String>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
     respond_with_generated_page(
         app_data.filesystem_http_route_index.clone(),
         path.into_inner(),
+        prefers_markdown(&req),
     )
 }
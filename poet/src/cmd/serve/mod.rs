@@ -10,6 +10,7 @@ use actix_web::App;
 use actix_web::HttpServer;
 use actix_web::web::Data;
 use anyhow::Result;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use clap::Parser;
 use indoc::formatdoc;
@@ -24,9 +25,11 @@ use crate::build_project::build_project_result::BuildProjectResult;
 use crate::build_project::build_project_result_holder::BuildProjectResultHolder;
 use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection;
 use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_params::BuildPromptControllerCollectionParams;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_result::BuildPromptControllerCollectionResult;
 use crate::holder::Holder as _;
-use crate::cmd::STATIC_FILES_PUBLIC_PATH;
 use crate::cmd::builds_project::BuildsProject;
+use crate::cmd::generated_file_args::GeneratedFileArgs;
+use crate::cmd::generated_files::GeneratedFiles;
 use crate::cmd::handler::Handler;
 use crate::cmd::serve::app_data::AppData;
 use crate::cmd::value_parser::parse_socket_addr;
@@ -41,6 +44,7 @@ use crate::mcp::resource_provider::ResourceProvider;
 use crate::mcp::session_manager::SessionManager;
 use crate::mcp::tool_registry::ToolRegistry;
 use crate::mcp_resource_provider_content_documents::McpResourceProviderContentDocuments;
+use crate::project_config::ProjectConfig;
 use crate::prompt_controller_collection_holder::PromptControllerCollectionHolder;
 use crate::read_esbuild_metafile_or_default::read_esbuild_metafile_or_default;
 use crate::search_index::SearchIndex;
@@ -50,8 +54,9 @@ use crate::search_tool::SearchTool;
 
 #[derive(Parser)]
 pub struct Serve {
-    #[arg(long, default_value="127.0.0.1:8070", value_parser = parse_socket_addr)]
-    addr: SocketAddr,
+    /// Defaults to `server.addr` from poet.toml, or 127.0.0.1:8070
+    #[arg(long, value_parser = parse_socket_addr)]
+    addr: Option<SocketAddr>,
 
     #[arg(value_parser = validate_is_directory)]
     app_dir: PathBuf,
@@ -59,11 +64,19 @@ pub struct Serve {
     #[arg(long)]
     app_name: String,
 
-    #[arg(long)]
-    public_path: String,
+    #[command(flatten)]
+    generated_files: GeneratedFileArgs,
 
+    /// Build drafts and documents scheduled for later, for previews
     #[arg(long, default_value = "false")]
-    sitemap: bool,
+    include_drafts: bool,
+
+    /// Defaults to `build.public_path` or `site.base_url` from poet.toml
+    #[arg(long)]
+    public_path: Option<String>,
+
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    sitemap: Option<bool>,
 }
 
 impl BuildsProject for Serve {
@@ -75,10 +88,27 @@ impl BuildsProject for Serve {
 #[async_trait(?Send)]
 impl Handler for Serve {
     async fn handle(&self) -> Result<()> {
+        let project_config = ProjectConfig::load(&self.app_dir).await?;
+        let GeneratedFiles {
+            feed_full_content,
+            feeds,
+            llms_txt,
+        } = self.generated_files.resolve(&project_config.build);
+        let addr = self
+            .addr
+            .or(project_config.server.addr)
+            .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 8070)));
+        let public_path = self
+            .public_path
+            .clone()
+            .or_else(|| project_config.public_path())
+            .ok_or_else(|| {
+                anyhow!("Public path is not set, use --public-path or set it in poet.toml")
+            })?;
         let asset_path_renderer = AssetPathRenderer {
-            base_path: self.public_path.clone(),
+            base_path: public_path.clone(),
         };
-        let source_filesystem = self.source_filesystem();
+        let source_filesystem = self.source_filesystem(&project_config);
         let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
         let app_dir_desktop_entry = AppDirDesktopEntry::parse(
             &source_filesystem
@@ -118,25 +148,38 @@ impl Handler for Serve {
         let build_project_result: BuildProjectResult = build_project(BuildProjectParams {
             asset_path_renderer: asset_path_renderer.clone(),
             authors,
+            components: project_config.components.clone(),
             esbuild_metafile: read_esbuild_metafile_or_default(source_filesystem.clone()).await?,
-            generated_page_base_path: self.public_path.clone(),
-            generate_sitemap: self.sitemap,
+            feed_full_content,
+            generated_page_base_path: public_path,
+            generate_feeds: feeds,
+            generate_llms_txt: llms_txt,
+            generate_sitemap: self
+                .sitemap
+                .or(project_config.build.sitemap)
+                .unwrap_or(false),
+            include_drafts: self.include_drafts,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer: rhai_template_renderer.clone(),
+            site: project_config.site.clone(),
             source_filesystem: source_filesystem.clone(),
+            taxonomies: project_config.taxonomies.clone(),
         })
         .await?
         .into();
 
-        let prompt_controller_collection =
-            build_prompt_document_controller_collection(BuildPromptControllerCollectionParams {
-                asset_path_renderer: asset_path_renderer.clone(),
-                content_document_linker: build_project_result.content_document_linker.clone(),
-                esbuild_metafile: build_project_result.esbuild_metafile.clone(),
-                rhai_template_renderer,
-                source_filesystem: source_filesystem.clone(),
-            })
-            .await?;
+        let BuildPromptControllerCollectionResult {
+            prompt_controller_collection,
+            ..
+        } = build_prompt_document_controller_collection(BuildPromptControllerCollectionParams {
+            asset_path_renderer: asset_path_renderer.clone(),
+            content_document_linker: build_project_result.content_document_linker.clone(),
+            esbuild_metafile: build_project_result.esbuild_metafile.clone(),
+            rhai_template_renderer,
+            source_filesystem: source_filesystem.clone(),
+        })
+        .await?;
 
         let prompt_controller_collection_holder: PromptControllerCollectionHolder =
             Default::default();
@@ -149,12 +192,15 @@ impl Handler for Serve {
             filesystem_http_route_index: Arc::new(
                 FilesystemHttpRouteIndex::from_filesystem(
                     build_project_result.memory_filesystem.clone(),
+                    &build_project_result.route_aliases,
                 )
                 .await?,
             ),
         });
 
-        let assets_directory = self.app_dir.join(STATIC_FILES_PUBLIC_PATH);
+        let assets_directory = self.assets_directory(&project_config);
+        let assets_path = project_config.server.assets_path.clone();
+        let mcp_path = project_config.server.mcp_path.clone();
         let search_index_reader: SearchIndexReader =
             SearchIndex::create_in_memory(build_project_result.content_document_sources.clone())
                 .index()?;
@@ -190,12 +236,9 @@ impl Handler for Serve {
         HttpServer::new(move || {
             App::new()
                 .app_data(app_data.clone())
-                .service(
-                    Files::new(STATIC_FILES_PUBLIC_PATH, assets_directory.clone())
-                        .prefer_utf8(true),
-                )
+                .service(Files::new(&assets_path, assets_directory.clone()).prefer_utf8(true))
                 .service(McpHttpServiceFactory {
-                    mount_path: "/mcp/streamable".to_string(),
+                    mount_path: mcp_path.clone(),
                     prompt_controller_collection_holder: prompt_controller_collection_holder
                         .clone(),
                     resource_list_aggregate: resource_list_aggregate.clone(),
@@ -205,7 +248,7 @@ impl Handler for Serve {
                 })
                 .configure(http_route::generated_pages::register)
         })
-        .bind(self.addr)
+        .bind(addr)
         .expect("Unable to bind server to address")
         .shutdown_timeout(1)
         .run()
@@ -6,8 +6,11 @@ mod watch_project_files;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use anyhow::Result;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use clap::Parser;
 use log::info;
@@ -18,6 +21,9 @@ use self::watch_project_files::watch_project_files;
 use crate::asset_path_renderer::AssetPathRenderer;
 use crate::build_project::build_project_result_holder::BuildProjectResultHolder;
 use crate::cmd::builds_project::BuildsProject;
+use crate::cmd::diagnostics_format::DiagnosticsFormat;
+use crate::cmd::generated_file_args::GeneratedFileArgs;
+use crate::cmd::generated_files::GeneratedFiles;
 use crate::cmd::handler::Handler;
 use crate::cmd::service_manager::ServiceManager;
 use crate::cmd::value_parser::parse_socket_addr;
@@ -35,6 +41,7 @@ use crate::mcp::resource_provider::ResourceProvider;
 use crate::mcp::session_manager::SessionManager;
 use crate::mcp::tool_registry::ToolRegistry;
 use crate::mcp_resource_provider_content_documents::McpResourceProviderContentDocuments;
+use crate::project_config::ProjectConfig;
 use crate::prompt_controller_collection_holder::PromptControllerCollectionHolder;
 use crate::rhai_template_renderer_holder::RhaiTemplateRendererHolder;
 use crate::search_index_reader_holder::SearchIndexReaderHolder;
@@ -42,14 +49,21 @@ use crate::search_tool::SearchTool;
 
 #[derive(Parser)]
 pub struct Watch {
-    #[arg(long, default_value="127.0.0.1:8050", value_parser = parse_socket_addr)]
-    addr: SocketAddr,
+    /// Defaults to `server.addr` from poet.toml, or 127.0.0.1:8050
+    #[arg(long, value_parser = parse_socket_addr)]
+    addr: Option<SocketAddr>,
+
+    #[arg(long, value_enum, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+
+    #[command(flatten)]
+    generated_files: GeneratedFileArgs,
 
     #[arg(value_parser = validate_is_directory)]
     source_directory: PathBuf,
 
-    #[arg(long, default_value = "false")]
-    sitemap: bool,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    sitemap: Option<bool>,
 }
 
 impl BuildsProject for Watch {
@@ -61,6 +75,16 @@ impl BuildsProject for Watch {
 #[async_trait(?Send)]
 impl Handler for Watch {
     async fn handle(&self) -> Result<()> {
+        let project_config = ProjectConfig::load(&self.source_directory).await?;
+        let GeneratedFiles {
+            feed_full_content,
+            feeds,
+            llms_txt,
+        } = self.generated_files.resolve(&project_config.build);
+        let addr = self
+            .addr
+            .or(project_config.server.addr)
+            .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 8050)));
         let ctrlc_notifier = CancellationToken::new();
         let ctrlc_notifier_handler = ctrlc_notifier.clone();
 
@@ -75,22 +99,23 @@ impl Handler for Watch {
             on_esbuild_metafile_changed,
             on_prompt_file_changed,
             on_shortcode_file_changed,
-        } = watch_project_files(self.source_directory.clone())?;
+        } = watch_project_files(self.source_directory.clone(), &project_config.directories)?;
 
-        let generated_page_base_path = format!("http://{}/", self.addr);
+        let generated_page_base_path = format!("http://{addr}/");
 
         let asset_path_renderer = AssetPathRenderer {
             base_path: generated_page_base_path.clone(),
         };
         let build_project_result_holder: BuildProjectResultHolder = Default::default();
         let esbuild_metafile_holder: EsbuildMetaFileHolder = Default::default();
+        let last_build_failed: Arc<AtomicBool> = Default::default();
         let filesystem_http_route_index_holder: FilesystemHttpRouteIndexHolder = Default::default();
         let mcp_resource_provider_content_documents: McpResourceProviderContentDocuments =
             McpResourceProviderContentDocuments(build_project_result_holder.clone());
         let prompt_controller_collection_holder: PromptControllerCollectionHolder =
             Default::default();
         let rhai_template_renderer_holder: RhaiTemplateRendererHolder = Default::default();
-        let source_filesystem = self.source_filesystem();
+        let source_filesystem = self.source_filesystem(&project_config);
         let resource_list_providers: Vec<Arc<dyn ResourceProvider>> =
             vec![Arc::new(mcp_resource_provider_content_documents.clone())];
         let search_index_reader_holder: SearchIndexReaderHolder = Default::default();
@@ -119,12 +144,13 @@ impl Handler for Watch {
         }));
 
         service_manager.register_service(Arc::new(HttpServer {
-            addr: self.addr,
-            assets_directory: self.assets_directory(),
+            addr,
+            assets_directory: self.assets_directory(&project_config),
             ctrlc_notifier: ctrlc_notifier.clone(),
             filesystem_http_route_index_holder,
             prompt_controller_collection_holder: prompt_controller_collection_holder.clone(),
             resource_list_aggregate: Arc::new(resource_list_providers.into()),
+            server_config: project_config.server.clone(),
             session_manager: session_manager.clone(),
             tool_registry: Arc::new(tool_registry),
         }));
@@ -132,21 +158,33 @@ impl Handler for Watch {
         service_manager.register_service(Arc::new(ProjectBuilder {
             asset_path_renderer: asset_path_renderer.clone(),
             build_project_result_holder: build_project_result_holder.clone(),
+            components: project_config.components.clone(),
             ctrlc_notifier: ctrlc_notifier.clone(),
+            diagnostics_format: self.diagnostics_format,
             esbuild_metafile_holder: esbuild_metafile_holder.clone(),
+            feed_full_content,
             generated_page_base_path: generated_page_base_path.clone(),
+            last_build_failed: last_build_failed.clone(),
             on_author_file_changed,
             on_content_file_changed,
             rhai_template_renderer_holder: rhai_template_renderer_holder.clone(),
             session_manager,
-            generate_sitemap: self.sitemap,
+            generate_feeds: feeds,
+            generate_llms_txt: llms_txt,
+            generate_sitemap: self
+                .sitemap
+                .or(project_config.build.sitemap)
+                .unwrap_or(false),
+            site: project_config.site.clone(),
             source_filesystem: source_filesystem.clone(),
+            taxonomies: project_config.taxonomies.clone(),
         }));
 
         service_manager.register_service(Arc::new(PromptControllerCollectionBuilder {
             asset_path_renderer,
             build_project_result_holder: build_project_result_holder.clone(),
             ctrlc_notifier: ctrlc_notifier.clone(),
+            diagnostics_format: self.diagnostics_format,
             esbuild_metafile_holder,
             on_prompt_file_changed,
             prompt_controller_collection_holder,
@@ -171,6 +209,10 @@ impl Handler for Watch {
 
         info!("Poet is shutting down...");
 
+        if last_build_failed.load(Ordering::Relaxed) {
+            return Err(anyhow!("The last build of the project failed"));
+        }
+
         Ok(())
     }
 }
@@ -22,7 +22,9 @@ pub struct FilesystemHttpRouteIndexBuilder {
 impl FilesystemHttpRouteIndexBuilder {
     async fn do_build_filesystem_http_route_index(&self) {
         let BuildProjectResult {
-            memory_filesystem, ..
+            memory_filesystem,
+            route_aliases,
+            ..
         } = match self.build_project_result_holder.get().await {
             Some(build_project_result) => build_project_result,
             None => {
@@ -34,7 +36,9 @@ impl FilesystemHttpRouteIndexBuilder {
 
         self.filesystem_http_route_index_holder
             .set(Some(Arc::new(
-                match FilesystemHttpRouteIndex::from_filesystem(memory_filesystem).await {
+                match FilesystemHttpRouteIndex::from_filesystem(memory_filesystem, &route_aliases)
+                    .await
+                {
                     Ok(filesystem_http_route_index) => filesystem_http_route_index,
                     Err(err) => {
                         error!("Unable to build filesysetm http route index: {err:#?}");
@@ -12,7 +12,6 @@ use log::error;
 use tokio::fs::create_dir_all;
 use tokio_util::sync::CancellationToken;
 
-use crate::cmd::STATIC_FILES_PUBLIC_PATH;
 use crate::cmd::service::Service;
 use crate::cmd::watch::app_data::AppData;
 use crate::cmd::watch::http_route;
@@ -22,6 +21,7 @@ use crate::mcp::mcp_http_service_factory::McpHttpServiceFactory;
 use crate::mcp::resource_list_aggregate::ResourceListAggregate;
 use crate::mcp::session_manager::SessionManager;
 use crate::mcp::tool_registry::ToolRegistry;
+use crate::project_config::server_config::ServerConfig;
 use crate::prompt_controller_collection_holder::PromptControllerCollectionHolder;
 
 pub struct HttpServer {
@@ -31,6 +31,7 @@ pub struct HttpServer {
     pub filesystem_http_route_index_holder: FilesystemHttpRouteIndexHolder,
     pub prompt_controller_collection_holder: PromptControllerCollectionHolder,
     pub resource_list_aggregate: Arc<ResourceListAggregate>,
+    pub server_config: ServerConfig,
     pub session_manager: SessionManager,
     pub tool_registry: Arc<ToolRegistry>,
 }
@@ -56,29 +57,28 @@ impl Service for HttpServer {
                 filesystem_http_route_index_holder: self.filesystem_http_route_index_holder.clone(),
             });
             let assets_directory = self.assets_directory.clone();
+            let assets_path = self.server_config.assets_path.clone();
             let ctrlc_notifier = self.ctrlc_notifier.clone();
             let prompt_controller_collection_holder =
                 self.prompt_controller_collection_holder.clone();
+            let mcp_path = self.server_config.mcp_path.clone();
             let resource_list_aggregate = self.resource_list_aggregate.clone();
             let session_manager = self.session_manager.clone();
             let tool_registry = self.tool_registry.clone();
 
             let server_info = Implementation {
                 description: None,
-                name: "poet".to_string(),
-                title: Some("Poet".to_string()),
+                name: self.server_config.name.clone(),
+                title: Some(self.server_config.title.clone()),
                 version: env!("CARGO_PKG_VERSION").to_string(),
             };
 
             if let Err(err) = ActixHttpServer::new(move || {
                 App::new()
                     .app_data(app_data.clone())
-                    .service(
-                        Files::new(STATIC_FILES_PUBLIC_PATH, assets_directory.clone())
-                            .prefer_utf8(true),
-                    )
+                    .service(Files::new(&assets_path, assets_directory.clone()).prefer_utf8(true))
                     .service(McpHttpServiceFactory {
-                        mount_path: "/mcp/streamable".to_string(),
+                        mount_path: mcp_path.clone(),
                         prompt_controller_collection_holder: prompt_controller_collection_holder
                             .clone(),
                         resource_list_aggregate: resource_list_aggregate.clone(),
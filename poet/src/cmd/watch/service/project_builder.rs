@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -13,6 +16,7 @@ use crate::build_authors::build_authors;
 use crate::build_project::build_project;
 use crate::build_project::build_project_params::BuildProjectParams;
 use crate::build_project::build_project_result_holder::BuildProjectResultHolder;
+use crate::cmd::diagnostics_format::DiagnosticsFormat;
 use crate::cmd::service::Service;
 use crate::esbuild_metafile_holder::EsbuildMetaFileHolder;
 use crate::filesystem::storage::Storage;
@@ -21,20 +25,33 @@ use crate::mcp::jsonrpc::JSONRPC_VERSION;
 use crate::mcp::jsonrpc::notification::resources_list_changed::ResourcesListChanged;
 use crate::mcp::jsonrpc::server_to_client_notification::ServerToClientNotification;
 use crate::mcp::session_manager::SessionManager;
+use crate::project_config::components_config::ComponentsConfig;
+use crate::project_config::site_config::SiteConfig;
+use crate::project_config::taxonomy_config::TaxonomyConfig;
 use crate::rhai_template_renderer_holder::RhaiTemplateRendererHolder;
 
 pub struct ProjectBuilder {
     pub asset_path_renderer: AssetPathRenderer,
     pub build_project_result_holder: BuildProjectResultHolder,
+    pub components: ComponentsConfig,
     pub ctrlc_notifier: CancellationToken,
+    pub diagnostics_format: DiagnosticsFormat,
     pub esbuild_metafile_holder: EsbuildMetaFileHolder,
+    pub feed_full_content: bool,
     pub generated_page_base_path: String,
+    /// Lets the watch command exit with an error when the project was left
+    /// broken.
+    pub last_build_failed: Arc<AtomicBool>,
     pub on_author_file_changed: Arc<Notify>,
     pub on_content_file_changed: Arc<Notify>,
     pub rhai_template_renderer_holder: RhaiTemplateRendererHolder,
     pub session_manager: SessionManager,
+    pub generate_feeds: bool,
+    pub generate_llms_txt: bool,
     pub generate_sitemap: bool,
+    pub site: SiteConfig,
     pub source_filesystem: Arc<Storage>,
+    pub taxonomies: BTreeMap<String, TaxonomyConfig>,
 }
 
 impl ProjectBuilder {
@@ -60,29 +77,51 @@ impl ProjectBuilder {
         let authors = match build_authors(self.source_filesystem.clone()).await {
             Ok(authors) => authors,
             Err(err) => {
-                error!("Failed to build authors: {err:#}");
+                self.last_build_failed.store(true, Ordering::Relaxed);
+
+                error!(
+                    "Failed to build authors: {:#}",
+                    self.diagnostics_format.report_build_error(err)
+                );
                 return;
             }
         };
 
+        let previous_build_project_result = self.build_project_result_holder.get().await;
+
         match build_project(BuildProjectParams {
             asset_path_renderer: self.asset_path_renderer.clone(),
             authors,
+            components: self.components.clone(),
             esbuild_metafile,
+            feed_full_content: self.feed_full_content,
             generated_page_base_path: self.generated_page_base_path.clone(),
+            generate_feeds: self.generate_feeds,
+            generate_llms_txt: self.generate_llms_txt,
             generate_sitemap: self.generate_sitemap,
+            include_drafts: true,
             is_watching: true,
+            previous_build_project_result: previous_build_project_result.clone(),
             rhai_template_renderer,
+            site: self.site.clone(),
             source_filesystem: self.source_filesystem.clone(),
+            taxonomies: self.taxonomies.clone(),
         })
         .await
         {
             Ok(build_project_result_stub) => {
+                self.last_build_failed.store(false, Ordering::Relaxed);
+
+                if let Err(err) = self
+                    .diagnostics_format
+                    .report(&build_project_result_stub.warnings)
+                {
+                    error!("Failed to report diagnostics: {err:#}");
+                }
+
                 self.build_project_result_holder
                     .set(Some(
-                        if let Some(old_build_project_result) =
-                            self.build_project_result_holder.get().await
-                        {
+                        if let Some(old_build_project_result) = previous_build_project_result {
                             build_project_result_stub.changed_compared_to(old_build_project_result)
                         } else {
                             build_project_result_stub.into()
@@ -104,7 +143,14 @@ impl ProjectBuilder {
 
                 info!("Build successful");
             }
-            Err(err) => error!("Failed to build project: {err:#}"),
+            Err(err) => {
+                self.last_build_failed.store(true, Ordering::Relaxed);
+
+                error!(
+                    "Failed to build project: {:#}",
+                    self.diagnostics_format.report_build_error(err)
+                );
+            }
         }
     }
 }
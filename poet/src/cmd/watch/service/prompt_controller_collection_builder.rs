@@ -12,6 +12,8 @@ use crate::build_project::build_project_result::BuildProjectResult;
 use crate::build_project::build_project_result_holder::BuildProjectResultHolder;
 use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection;
 use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_params::BuildPromptControllerCollectionParams;
+use crate::build_prompt_document_controller_collection::build_prompt_document_controller_collection_result::BuildPromptControllerCollectionResult;
+use crate::cmd::diagnostics_format::DiagnosticsFormat;
 use crate::cmd::service::Service;
 use crate::esbuild_metafile_holder::EsbuildMetaFileHolder;
 use crate::filesystem::storage::Storage;
@@ -23,6 +25,7 @@ pub struct PromptControllerCollectionBuilder {
     pub asset_path_renderer: AssetPathRenderer,
     pub build_project_result_holder: BuildProjectResultHolder,
     pub ctrlc_notifier: CancellationToken,
+    pub diagnostics_format: DiagnosticsFormat,
     pub esbuild_metafile_holder: EsbuildMetaFileHolder,
     pub on_prompt_file_changed: Arc<Notify>,
     pub prompt_controller_collection_holder: PromptControllerCollectionHolder,
@@ -77,7 +80,14 @@ impl PromptControllerCollectionBuilder {
         })
         .await
         {
-            Ok(prompt_controller_collection) => {
+            Ok(BuildPromptControllerCollectionResult {
+                prompt_controller_collection,
+                warnings,
+            }) => {
+                if let Err(err) = self.diagnostics_format.report(&warnings) {
+                    error!("Failed to report diagnostics: {err:#}");
+                }
+
                 self.prompt_controller_collection_holder
                     .set(Some(Arc::new(prompt_controller_collection)))
                     .await;
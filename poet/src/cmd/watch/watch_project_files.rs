@@ -16,6 +16,8 @@ use notify_debouncer_full::new_debouncer;
 use notify_debouncer_full::notify::RecursiveMode;
 use tokio::sync::Notify;
 
+use crate::project_config::directories_config::DirectoriesConfig;
+
 pub struct WatchProjectHandle {
     pub debouncer: Debouncer<RecommendedWatcher, RecommendedCache>,
     pub on_author_file_changed: Arc<Notify>,
@@ -43,12 +45,15 @@ fn is_temp_file(path: &Path) -> bool {
     path_string.ends_with("~") || path_string.ends_with(".swp") || path_string.ends_with(".tmp")
 }
 
-pub fn watch_project_files(source_directory: PathBuf) -> Result<WatchProjectHandle> {
-    let authors_directory = source_directory.join("authors");
-    let content_directory = source_directory.join("content");
+pub fn watch_project_files(
+    source_directory: PathBuf,
+    directories: &DirectoriesConfig,
+) -> Result<WatchProjectHandle> {
+    let authors_directory = source_directory.join(&directories.authors);
+    let content_directory = source_directory.join(&directories.content);
     let esbuild_metafile_path = source_directory.join("esbuild-meta.json");
-    let prompts_directory = source_directory.join("prompts");
-    let shortcodes_directory = source_directory.join("shortcodes");
+    let prompts_directory = source_directory.join(&directories.prompts);
+    let shortcodes_directory = source_directory.join(&directories.shortcodes);
 
     let on_author_file_changed = Arc::new(Notify::new());
     let on_content_file_changed = Arc::new(Notify::new());
@@ -0,0 +1,127 @@
+use tantivy::tokenizer::LowerCaser;
+use tantivy::tokenizer::PreTokenizedStream;
+use tantivy::tokenizer::PreTokenizedString;
+use tantivy::tokenizer::RemoveLongFilter;
+use tantivy::tokenizer::TextAnalyzer;
+use tantivy::tokenizer::Token;
+use tantivy::tokenizer::Tokenizer;
+
+/// Name of the tokenizer in the search index
+pub const CODE_TOKENIZER_NAME: &str = "code";
+
+fn is_camel_case_boundary(chars: &[(usize, char)], index: usize) -> bool {
+    let previous = chars[index - 1].1;
+    let current = chars[index].1;
+
+    if !current.is_uppercase() {
+        return false;
+    }
+
+    // `parseQuery` and `HTTPServer` both split before the last uppercase letter
+    previous.is_lowercase()
+        || previous.is_numeric()
+        || (previous.is_uppercase()
+            && chars
+                .get(index + 1)
+                .is_some_and(|(_, next)| next.is_lowercase()))
+}
+
+fn split_words(text: &str) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut word_start: Option<usize> = None;
+
+    let push_word = |tokens: &mut Vec<Token>, start: usize, end: usize| {
+        let offset_from = chars[start].0;
+        let offset_to = chars.get(end).map_or(text.len(), |(offset, _)| *offset);
+
+        tokens.push(Token {
+            offset_from,
+            offset_to,
+            position: tokens.len(),
+            text: text[offset_from..offset_to].to_string(),
+            position_length: 1,
+        });
+    };
+
+    for index in 0..=chars.len() {
+        match chars.get(index) {
+            Some((_, char)) if char.is_alphanumeric() => match word_start {
+                Some(start) if is_camel_case_boundary(&chars, index) => {
+                    push_word(&mut tokens, start, index);
+                    word_start = Some(index);
+                }
+                Some(_) => {}
+                None => word_start = Some(index),
+            },
+            _ => {
+                if let Some(start) = word_start.take() {
+                    push_word(&mut tokens, start, index);
+                }
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Splits identifiers into words, so `parse_query`, `Parser::parse` and
+/// `parseQuery` are all found when searching for "parse".
+#[derive(Clone, Default)]
+pub struct CodeTokenizer;
+
+impl CodeTokenizer {
+    pub fn text_analyzer() -> TextAnalyzer {
+        TextAnalyzer::builder(Self)
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .build()
+    }
+}
+
+impl Tokenizer for CodeTokenizer {
+    type TokenStream<'text> = PreTokenizedStream;
+
+    fn token_stream<'text>(&'text mut self, text: &'text str) -> Self::TokenStream<'text> {
+        PreTokenizedString {
+            text: text.to_string(),
+            tokens: split_words(text),
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::TokenStream as _;
+
+    use super::*;
+
+    fn words(text: &str) -> Vec<String> {
+        let mut text_analyzer = CodeTokenizer::text_analyzer();
+        let mut token_stream = text_analyzer.token_stream(text);
+        let mut words = Vec::new();
+
+        while token_stream.advance() {
+            words.push(token_stream.token().text.clone());
+        }
+
+        words
+    }
+
+    #[test]
+    fn splits_identifiers_on_separators() {
+        assert_eq!(
+            words("search_index::SearchIndex.create_in_memory()"),
+            [
+                "search", "index", "search", "index", "create", "in", "memory"
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_identifiers_on_camel_case() {
+        assert_eq!(words("parseQuery"), ["parse", "query"]);
+        assert_eq!(words("HTTPServer2Config"), ["http", "server2", "config"]);
+    }
+}
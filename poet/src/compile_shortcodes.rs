@@ -1,4 +1,3 @@
-use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -16,7 +15,7 @@ pub async fn compile_shortcodes(source_filesystem: Arc<Storage>) -> Result<RhaiT
     let _build_timer = BuildTimer::default();
     let rhai_template_factory = RhaiTemplateRendererFactory::new(
         source_filesystem.base_directory.clone(),
-        PathBuf::from("shortcodes"),
+        source_filesystem.directories.shortcodes.clone(),
     );
 
     for file in &source_filesystem.read_project_files().await? {
@@ -52,6 +51,7 @@ mod tests {
         let directory = tempdir()?;
         let filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         for (relative_path, contents) in files {
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::Utc;
 use rhai::CustomType;
+use rhai::Dynamic;
 use rhai::EvalAltResult;
 use rhai::TypeBuilder;
 
@@ -13,7 +15,19 @@ use crate::content_document_collection_ranked::ContentDocumentCollectionRanked;
 use crate::content_document_front_matter::ContentDocumentFrontMatter;
 use crate::content_document_linker::ContentDocumentLinker;
 use crate::content_document_reference::ContentDocumentReference;
+use crate::document_dependency_recorder::DocumentDependencyRecorder;
+use crate::footnotes::Footnotes;
+use crate::heading_ids::HeadingIds;
+use crate::is_external_link::is_external_link;
+use crate::link_definitions::LinkDefinitions;
+use crate::markdown_component_context::MarkdownComponentContext;
+use crate::paginator::Paginator;
+use crate::project_config::components_config::ComponentsConfig;
+use crate::project_config::site_config::SiteConfig;
+use crate::seo::Seo;
 use crate::table_of_contents::TableOfContents;
+use crate::taxonomy::Taxonomy;
+use crate::taxonomy_index::TaxonomyIndex;
 
 #[derive(Clone)]
 pub struct ContentDocumentComponentContext {
@@ -21,12 +35,20 @@ pub struct ContentDocumentComponentContext {
     pub authors: Vec<Author>,
     pub available_authors: Arc<AuthorCollection>,
     pub available_collections: Arc<HashSet<String>>,
+    pub components: Arc<ComponentsConfig>,
     pub content_document_collections_ranked: Arc<HashMap<String, ContentDocumentCollectionRanked>>,
     pub content_document_linker: ContentDocumentLinker,
+    pub dependency_recorder: DocumentDependencyRecorder,
+    pub footnotes: Option<Footnotes>,
     pub front_matter: ContentDocumentFrontMatter,
+    pub heading_ids: HeadingIds,
     pub is_watching: bool,
+    pub link_definitions: LinkDefinitions,
+    pub paginator: Option<Paginator>,
     pub reference: ContentDocumentReference,
+    pub site: Arc<SiteConfig>,
     pub table_of_contents: Option<TableOfContents>,
+    pub taxonomy_index: Arc<TaxonomyIndex>,
 }
 
 impl ContentDocumentComponentContext {
@@ -42,16 +64,47 @@ impl ContentDocumentComponentContext {
             authors: Vec::new(),
             available_authors: Arc::new(AuthorCollection::default()),
             available_collections: Arc::new(HashSet::new()),
+            components: Default::default(),
             content_document_collections_ranked: Arc::new(HashMap::new()),
             content_document_linker: ContentDocumentLinker::default(),
+            dependency_recorder: Default::default(),
+            footnotes: None,
             front_matter: ContentDocumentFrontMatter::mock("doc"),
+            heading_ids: Default::default(),
             is_watching: false,
+            link_definitions: Default::default(),
+            paginator: None,
             reference: ContentDocumentReference {
                 basename_path: "doc".into(),
                 front_matter: ContentDocumentFrontMatter::mock("doc"),
                 generated_page_base_path: "/".to_string(),
             },
+            site: Default::default(),
             table_of_contents: None,
+            taxonomy_index: Default::default(),
+        }
+    }
+
+    pub fn with_footnotes(self, footnotes: Footnotes) -> Self {
+        Self {
+            asset_manager: self.asset_manager,
+            authors: self.authors,
+            available_authors: self.available_authors,
+            available_collections: self.available_collections,
+            components: self.components,
+            content_document_collections_ranked: self.content_document_collections_ranked,
+            content_document_linker: self.content_document_linker,
+            dependency_recorder: self.dependency_recorder,
+            footnotes: Some(footnotes),
+            front_matter: self.front_matter,
+            heading_ids: self.heading_ids,
+            is_watching: self.is_watching,
+            link_definitions: self.link_definitions,
+            paginator: self.paginator,
+            reference: self.reference,
+            site: self.site,
+            table_of_contents: self.table_of_contents,
+            taxonomy_index: self.taxonomy_index,
         }
     }
 
@@ -61,12 +114,20 @@ impl ContentDocumentComponentContext {
             authors: self.authors,
             available_authors: self.available_authors,
             available_collections: self.available_collections,
+            components: self.components,
             content_document_collections_ranked: self.content_document_collections_ranked,
             content_document_linker: self.content_document_linker,
+            dependency_recorder: self.dependency_recorder,
+            footnotes: self.footnotes,
             front_matter: self.front_matter,
+            heading_ids: self.heading_ids,
             is_watching: self.is_watching,
+            link_definitions: self.link_definitions,
+            paginator: self.paginator,
             reference: self.reference,
+            site: self.site,
             table_of_contents: Some(table_of_contents),
+            taxonomy_index: self.taxonomy_index,
         }
     }
 
@@ -78,6 +139,8 @@ impl ContentDocumentComponentContext {
     }
 
     fn rhai_available_authors(&mut self) -> rhai::Array {
+        self.dependency_recorder.record_available_authors();
+
         self.available_authors
             .values()
             .map(|author| rhai::Dynamic::from(author.clone()))
@@ -101,6 +164,8 @@ impl ContentDocumentComponentContext {
         &mut self,
         collection_name: &str,
     ) -> Result<ContentDocumentCollectionRanked, Box<EvalAltResult>> {
+        self.dependency_recorder.record_collection(collection_name);
+
         if let Some(collection) = self
             .content_document_collections_ranked
             .get(collection_name)
@@ -111,6 +176,10 @@ impl ContentDocumentComponentContext {
         }
     }
 
+    fn rhai_footnotes(&mut self) -> Footnotes {
+        self.footnotes.clone().unwrap_or_default()
+    }
+
     fn rhai_front_matter(&mut self) -> ContentDocumentFrontMatter {
         self.front_matter.clone()
     }
@@ -120,19 +189,34 @@ impl ContentDocumentComponentContext {
     }
 
     fn rhai_is_current_page(&mut self, other: String) -> Result<bool, Box<EvalAltResult>> {
+        self.dependency_recorder.record_link(&other);
+
         let basename = self.content_document_linker.resolve_id(&other)?;
 
         Ok(self.reference.basename() == basename)
     }
 
+    fn rhai_is_draft(&mut self) -> bool {
+        self.front_matter.is_draft_at(Utc::now())
+    }
+
     fn rhai_is_watching(&mut self) -> bool {
         self.is_watching
     }
 
     fn rhai_link_to(&mut self, path: &str) -> Result<String, Box<EvalAltResult>> {
+        self.dependency_recorder.record_link(path);
+
         Ok(self.content_document_linker.link_to(path)?)
     }
 
+    fn rhai_paginator(&mut self) -> Dynamic {
+        match &self.paginator {
+            Some(paginator) => Dynamic::from(paginator.clone()),
+            None => Dynamic::UNIT,
+        }
+    }
+
     fn rhai_primary_collection(
         &mut self,
     ) -> Result<ContentDocumentCollectionRanked, Box<EvalAltResult>> {
@@ -167,6 +251,53 @@ impl ContentDocumentComponentContext {
         self.reference.clone()
     }
 
+    /// Breadcrumbs are left out when the primary collection is ambiguous.
+    fn rhai_seo(&mut self) -> Result<Seo, Box<EvalAltResult>> {
+        let overrides = &self.front_matter.seo;
+        let placements = &self.front_matter.collections.placements;
+        let primary_collection_name = match placements.len() {
+            1 => placements.first().map(|placement| &placement.name),
+            _ => self.front_matter.primary_collection.as_ref(),
+        };
+        let image = match &overrides.image {
+            Some(image) if is_external_link(image) => Some(image.clone()),
+            Some(image) => Some(self.asset_manager.image(image)?),
+            None => None,
+        };
+
+        if let Some(primary_collection_name) = primary_collection_name {
+            self.dependency_recorder
+                .record_collection(primary_collection_name);
+        }
+
+        Ok(Seo {
+            authors: self.authors.clone(),
+            breadcrumbs: primary_collection_name
+                .and_then(|name| self.content_document_collections_ranked.get(name))
+                .and_then(|collection| collection.hierarchy.path_to(&self.reference.basename()))
+                .unwrap_or_default(),
+            canonical_link: self.reference.canonical_link()?,
+            description: overrides
+                .description
+                .clone()
+                .unwrap_or_else(|| self.front_matter.description.clone()),
+            image,
+            last_updated_at: self.front_matter.last_updated_at,
+            noindex: overrides.noindex,
+            publish_at: self.front_matter.publish_at,
+            schema_type: overrides.schema_type.clone(),
+            site_title: self.site.title.clone(),
+            title: overrides
+                .title
+                .clone()
+                .unwrap_or_else(|| self.front_matter.title.clone()),
+        })
+    }
+
+    fn rhai_site(&mut self) -> SiteConfig {
+        (*self.site).clone()
+    }
+
     fn rhai_table_of_contents(&mut self) -> Result<TableOfContents, Box<EvalAltResult>> {
         if let Some(table_of_contents) = &self.table_of_contents {
             Ok(table_of_contents.clone())
@@ -174,6 +305,12 @@ impl ContentDocumentComponentContext {
             Err("Table of contents is not available. Do not use table of contents variable in document headers.".into())
         }
     }
+
+    fn rhai_taxonomy(&mut self, taxonomy_name: &str) -> Result<Taxonomy, Box<EvalAltResult>> {
+        self.dependency_recorder.record_taxonomy(taxonomy_name);
+
+        Ok(self.taxonomy_index.get(taxonomy_name)?)
+    }
 }
 
 impl CustomType for ContentDocumentComponentContext {
@@ -183,15 +320,42 @@ impl CustomType for ContentDocumentComponentContext {
             .with_get("assets", Self::rhai_get_assets)
             .with_get("authors", Self::rhai_authors)
             .with_get("available_authors", Self::rhai_available_authors)
+            .with_get("footnotes", Self::rhai_footnotes)
             .with_get("front_matter", Self::rhai_front_matter)
+            .with_get("is_draft", Self::rhai_is_draft)
             .with_get("is_watching", Self::rhai_is_watching)
+            .with_get("paginator", Self::rhai_paginator)
             .with_get("primary_collection", Self::rhai_primary_collection)
             .with_get("reference", Self::rhai_reference)
+            .with_get("seo", Self::rhai_seo)
+            .with_get("site", Self::rhai_site)
             .with_get("table_of_contents", Self::rhai_table_of_contents)
             .with_fn("belongs_to", Self::rhai_belongs_to)
             .with_fn("collection", Self::rhai_collection)
             .with_fn("is_current_page", Self::rhai_is_current_page)
-            .with_fn("link_to", Self::rhai_link_to);
+            .with_fn("link_to", Self::rhai_link_to)
+            .with_fn("taxonomy", Self::rhai_taxonomy);
+    }
+}
+
+impl MarkdownComponentContext for ContentDocumentComponentContext {
+    fn asset_manager(&self) -> &AssetManager {
+        &self.asset_manager
+    }
+
+    fn content_document_linker(&self) -> &ContentDocumentLinker {
+        &self.content_document_linker
+    }
+
+    fn link_definitions(&self) -> &LinkDefinitions {
+        &self.link_definitions
+    }
+
+    /// Same-page fragments are checked against this document's headings.
+    fn link_to(&self, path: &str) -> Result<String, String> {
+        self.dependency_recorder.record_link(path);
+        self.content_document_linker
+            .link_from(&self.heading_ids, path)
     }
 }
 
@@ -274,16 +438,24 @@ mod tests {
             authors: Vec::new(),
             available_authors: Arc::new(AuthorCollection::default()),
             available_collections: Arc::new(HashSet::new()),
+            components: Default::default(),
             content_document_collections_ranked: Arc::new(ranked),
             content_document_linker: ContentDocumentLinker::default(),
+            dependency_recorder: Default::default(),
+            footnotes: None,
             front_matter,
+            heading_ids: Default::default(),
             is_watching: false,
+            link_definitions: Default::default(),
+            paginator: None,
             reference: ContentDocumentReference {
                 basename_path: "doc".into(),
                 front_matter: ContentDocumentFrontMatter::mock("doc"),
                 generated_page_base_path: "/".to_string(),
             },
+            site: Default::default(),
             table_of_contents: None,
+            taxonomy_index: Default::default(),
         }
     }
 
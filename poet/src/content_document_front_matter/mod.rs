@@ -1,6 +1,15 @@
 pub mod collection_placement;
 pub mod collection_placement_list;
+pub mod paginate;
+pub mod paginate_sort;
+pub mod seo_overrides;
+pub mod seo_schema_type;
+pub mod sitemap_change_frequency;
+pub mod sitemap_priority;
 
+use std::collections::BTreeMap;
+
+use anyhow::Result;
 use chrono::DateTime;
 use chrono::Utc;
 use rhai::CustomType;
@@ -8,13 +17,24 @@ use rhai::Map;
 use rhai::TypeBuilder;
 use serde::Deserialize;
 use serde::Serialize;
+use toml::Table;
+use toml::Value;
 
 use crate::content_document_front_matter::collection_placement_list::CollectionPlacementList;
+use crate::content_document_front_matter::paginate::Paginate;
+use crate::content_document_front_matter::seo_overrides::SeoOverrides;
+use crate::content_document_front_matter::sitemap_change_frequency::SitemapChangeFrequency;
+use crate::content_document_front_matter::sitemap_priority::SitemapPriority;
+use crate::project_config::taxonomy_config::TaxonomyConfig;
 
 fn default_render() -> bool {
     true
 }
 
+fn default_sitemap() -> bool {
+    true
+}
+
 // #[derive(Debug, Deserialize, Serialize)]
 // pub struct Excerpt {
 //     #[serde(rename = "type")]
@@ -25,10 +45,19 @@ fn default_render() -> bool {
 #[derive(Clone, Debug, Deserialize, Hash, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ContentDocumentFrontMatter {
+    /// Extra paths, like `/old/path/`, that `watch` and `serve` answer with
+    /// this document
+    #[serde(default)]
+    pub aliases: Vec<String>,
     #[serde(default)]
     pub authors: Vec<String>,
+    /// How often the document changes, as hinted in the sitemap
+    #[serde(default)]
+    pub changefreq: Option<SitemapChangeFrequency>,
     pub description: String,
     #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
     pub id: Option<String>,
     pub layout: String,
     // pub references: Vec<String>,
@@ -38,11 +67,29 @@ pub struct ContentDocumentFrontMatter {
     // pub excerpts: Vec<Excerpt>,
     #[serde(default, with = "crate::flexible_datetime")]
     pub last_updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub paginate: Option<Paginate>,
     pub primary_collection: Option<String>,
+    /// Sitemap priority, `0.8` for the home page and `0.5` for the rest by
+    /// default
+    #[serde(default)]
+    pub priority: Option<SitemapPriority>,
     #[serde(default)]
     pub props: Map,
+    #[serde(default, with = "crate::flexible_datetime")]
+    pub publish_at: Option<DateTime<Utc>>,
     #[serde(default = "default_render")]
     pub render: bool,
+    /// Overrides of the metadata rendered by `context.seo`, under `[seo]`
+    #[serde(default)]
+    pub seo: SeoOverrides,
+    /// Set to `false` to leave the document out of the sitemap
+    #[serde(default = "default_sitemap")]
+    pub sitemap: bool,
+    /// Terms by taxonomy name, taken from top-level keys like
+    /// `tags = ["rust"]` by `from_toml_table`
+    #[serde(skip_deserializing)]
+    pub taxonomies: BTreeMap<String, Vec<String>>,
     pub title: String,
 }
 
@@ -50,21 +97,63 @@ impl ContentDocumentFrontMatter {
     #[cfg(test)]
     pub fn mock(name: &str) -> Self {
         Self {
+            aliases: vec![],
             authors: vec![],
+            changefreq: None,
             description: "".to_string(),
+            draft: false,
             id: None,
             last_updated_at: None,
             layout: "SomeLayout".to_string(),
             collections: Default::default(),
+            paginate: None,
             primary_collection: None,
+            priority: None,
             props: Default::default(),
+            publish_at: None,
             render: true,
+            seo: Default::default(),
+            sitemap: true,
+            taxonomies: Default::default(),
             title: name.to_string(),
         }
     }
 }
 
 impl ContentDocumentFrontMatter {
+    /// Taxonomies configured in poet.toml are top-level keys, like
+    /// `tags = ["rust"]`. Any other unknown key is still an error.
+    pub fn from_toml_table(
+        mut table: Table,
+        taxonomies: &BTreeMap<String, TaxonomyConfig>,
+    ) -> Result<Self> {
+        let mut terms_by_taxonomy: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for taxonomy_name in taxonomies.keys() {
+            if let Some(terms) = table.remove(taxonomy_name) {
+                terms_by_taxonomy.insert(taxonomy_name.clone(), terms.try_into()?);
+            }
+        }
+
+        let mut front_matter: Self = Value::Table(table).try_into()?;
+
+        front_matter.taxonomies = terms_by_taxonomy;
+
+        Ok(front_matter)
+    }
+
+    /// Drafts, and documents scheduled for later, are only built for
+    /// previews.
+    pub fn is_draft_at(&self, now: DateTime<Utc>) -> bool {
+        self.draft || self.publish_at.is_some_and(|publish_at| publish_at > now)
+    }
+
+    /// Documents that search engines should not index are left out too, and
+    /// so are drafts, even when they are built for a preview.
+    pub fn is_listed_in_sitemap_at(&self, now: DateTime<Utc>) -> bool {
+        self.render && self.sitemap && !self.seo.noindex && !self.is_draft_at(now)
+    }
+
     fn rhai_description(&mut self) -> String {
         self.description.clone()
     }
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::content_document_front_matter::paginate_sort::PaginateSort;
+
+/// Splits the documents of a collection into listing pages rendered from
+/// the document that declares it.
+#[derive(Clone, Debug, Deserialize, Hash, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Paginate {
+    pub collection: String,
+    pub per_page: usize,
+    #[serde(default)]
+    pub sort: PaginateSort,
+}
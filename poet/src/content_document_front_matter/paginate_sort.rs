@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::content_document_reference::ContentDocumentReference;
+
+#[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginateSort {
+    DateAsc,
+    #[default]
+    DateDesc,
+    TitleAsc,
+    TitleDesc,
+}
+
+impl PaginateSort {
+    /// Dates come from `publish_at`, or `last_updated_at` when the document
+    /// is not scheduled. Undated documents always go last.
+    pub fn compare(&self, a: &ContentDocumentReference, b: &ContentDocumentReference) -> Ordering {
+        let date_of = |reference: &ContentDocumentReference| {
+            reference
+                .front_matter
+                .publish_at
+                .or(reference.front_matter.last_updated_at)
+        };
+
+        let ordering = match self {
+            Self::DateAsc | Self::DateDesc => match (date_of(a), date_of(b)) {
+                (Some(a_date), Some(b_date)) if matches!(self, Self::DateAsc) => {
+                    a_date.cmp(&b_date)
+                }
+                (Some(a_date), Some(b_date)) => b_date.cmp(&a_date),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            Self::TitleAsc => a.front_matter.title.cmp(&b.front_matter.title),
+            Self::TitleDesc => b.front_matter.title.cmp(&a.front_matter.title),
+        };
+
+        ordering.then_with(|| a.cmp(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use chrono::Utc;
+
+    use super::*;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+
+    fn reference(basename: &str, day: Option<u32>) -> ContentDocumentReference {
+        let mut front_matter = ContentDocumentFrontMatter::mock(basename);
+
+        front_matter.publish_at =
+            day.and_then(|day| Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).single());
+
+        ContentDocumentReference {
+            basename_path: basename.into(),
+            front_matter,
+            generated_page_base_path: "/".to_string(),
+        }
+    }
+
+    #[test]
+    fn sorts_newest_first_with_undated_documents_last() {
+        let mut references = [
+            reference("undated", None),
+            reference("old", Some(1)),
+            reference("new", Some(2)),
+        ];
+
+        references.sort_by(|a, b| PaginateSort::DateDesc.compare(a, b));
+
+        let basenames: Vec<String> = references
+            .iter()
+            .map(|reference| reference.basename().to_string())
+            .collect();
+
+        assert_eq!(basenames, ["new", "old", "undated"]);
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::content_document_front_matter::seo_schema_type::SeoSchemaType;
+
+/// Replaces what `context.seo` takes from the rest of the front matter.
+#[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SeoOverrides {
+    pub description: Option<String>,
+    /// Image asset, or an external URL
+    pub image: Option<String>,
+    /// Asks search engines not to index the document, and leaves it out of
+    /// the sitemap
+    pub noindex: bool,
+    #[serde(rename = "type")]
+    pub schema_type: SeoSchemaType,
+    pub title: Option<String>,
+}
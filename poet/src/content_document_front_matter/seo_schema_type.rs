@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Schema.org type of the document in its structured data
+#[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
+pub enum SeoSchemaType {
+    #[default]
+    Article,
+    TechArticle,
+}
+
+impl SeoSchemaType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Article => "Article",
+            Self::TechArticle => "TechArticle",
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use serde::Serialize;
+use sitemap_rs::url::ChangeFrequency;
+
+#[derive(Clone, Copy, Debug, Deserialize, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SitemapChangeFrequency {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+
+impl From<SitemapChangeFrequency> for ChangeFrequency {
+    fn from(change_frequency: SitemapChangeFrequency) -> Self {
+        match change_frequency {
+            SitemapChangeFrequency::Always => Self::Always,
+            SitemapChangeFrequency::Hourly => Self::Hourly,
+            SitemapChangeFrequency::Daily => Self::Daily,
+            SitemapChangeFrequency::Weekly => Self::Weekly,
+            SitemapChangeFrequency::Monthly => Self::Monthly,
+            SitemapChangeFrequency::Yearly => Self::Yearly,
+            SitemapChangeFrequency::Never => Self::Never,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Number between `0.0` and `1.0`
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(into = "f32", try_from = "f32")]
+pub struct SitemapPriority(pub f32);
+
+impl From<SitemapPriority> for f32 {
+    fn from(priority: SitemapPriority) -> Self {
+        priority.0
+    }
+}
+
+impl Hash for SitemapPriority {
+    fn hash<THasher: Hasher>(&self, state: &mut THasher) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl TryFrom<f32> for SitemapPriority {
+    type Error = String;
+
+    fn try_from(priority: f32) -> Result<Self, Self::Error> {
+        if (0.0..=1.0).contains(&priority) {
+            Ok(Self(priority))
+        } else {
+            Err(format!(
+                "Sitemap priority has to be between 0.0 and 1.0, got: {priority}"
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_priority_out_of_range() {
+        assert!(SitemapPriority::try_from(0.4).is_ok());
+        assert!(SitemapPriority::try_from(1.5).is_err());
+        assert!(SitemapPriority::try_from(-0.1).is_err());
+    }
+}
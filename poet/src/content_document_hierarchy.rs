@@ -15,6 +15,13 @@ pub struct ContentDocumentHierarchy {
 }
 
 impl ContentDocumentHierarchy {
+    pub fn path_to(
+        &self,
+        basename: &ContentDocumentBasename,
+    ) -> Option<Vec<ContentDocumentReference>> {
+        self.roots.iter().find_map(|root| root.path_to(basename))
+    }
+
     fn rhai_after(&mut self, basename_string: String) -> Result<Dynamic, Box<EvalAltResult>> {
         let basename: ContentDocumentBasename = basename_string.into();
         let mut flat_peekable = self
@@ -3,16 +3,47 @@ use std::sync::Arc;
 
 use crate::content_document_basename::ContentDocumentBasename;
 use crate::content_document_reference::ContentDocumentReference;
+use crate::heading_ids::HeadingIds;
+
+/// Document ids start with `#` too, so only a later `#` starts a fragment.
+fn split_fragment(path: &str) -> (&str, Option<&str>) {
+    match path.get(1..).and_then(|rest| rest.find('#')) {
+        Some(index) => (&path[..index + 1], Some(&path[index + 2..])),
+        None => (path, None),
+    }
+}
 
 #[derive(Clone, Default)]
 pub struct ContentDocumentLinker {
     pub content_document_basename_by_id: Arc<HashMap<String, ContentDocumentBasename>>,
     pub content_document_by_basename:
         Arc<HashMap<ContentDocumentBasename, ContentDocumentReference>>,
+    pub heading_ids_by_basename: Arc<HashMap<ContentDocumentBasename, HeadingIds>>,
 }
 
 impl ContentDocumentLinker {
+    /// Like `link_to`, but a `#name` that is not a document id points to a
+    /// heading of the document the link is in.
+    pub fn link_from(&self, heading_ids: &HeadingIds, path: &str) -> Result<String, String> {
+        match path.strip_prefix('#') {
+            Some(fragment)
+                if !fragment.contains('#')
+                    && !self.content_document_basename_by_id.contains_key(fragment) =>
+            {
+                if heading_ids.contains(fragment) {
+                    Ok(path.to_string())
+                } else {
+                    Err(format!(
+                        "Neither a document id nor a heading of this document: {path}"
+                    ))
+                }
+            }
+            _ => self.link_to(path),
+        }
+    }
+
     pub fn link_to(&self, path: &str) -> Result<String, String> {
+        let (path, fragment) = split_fragment(path);
         let basename = self.resolve_id(path)?;
 
         if let Some(reference) = self.content_document_by_basename.get(&basename) {
@@ -22,17 +53,41 @@ impl ContentDocumentLinker {
                 ));
             }
 
-            match reference.canonical_link() {
-                Ok(canonical_link) => Ok(canonical_link),
-                Err(err) => Err(format!(
-                    "Unable to generate canonical link for {basename}: {err}"
-                )),
+            let canonical_link = match reference.canonical_link() {
+                Ok(canonical_link) => canonical_link,
+                Err(err) => {
+                    return Err(format!(
+                        "Unable to generate canonical link for {basename}: {err}"
+                    ));
+                }
+            };
+
+            match fragment {
+                Some(fragment) => {
+                    if self
+                        .heading_ids_by_basename
+                        .get(&basename)
+                        .is_some_and(|heading_ids| heading_ids.contains(fragment))
+                    {
+                        Ok(format!("{canonical_link}#{fragment}"))
+                    } else {
+                        Err(format!(
+                            "Heading does not exist: #{fragment} in '{basename}'"
+                        ))
+                    }
+                }
+                None => Ok(canonical_link),
             }
         } else {
             Err(format!("Document does not exist: {path}"))
         }
     }
 
+    /// Document a link points to, with the heading fragment left out.
+    pub fn linked_basename(&self, path: &str) -> Option<ContentDocumentBasename> {
+        self.resolve_id(split_fragment(path).0).ok()
+    }
+
     pub fn resolve_id(&self, path: &str) -> Result<ContentDocumentBasename, String> {
         if path.starts_with("#") {
             if let Some(basename) =
@@ -83,9 +138,20 @@ mod tests {
 
         content_document_basename_by_id.insert("guide-id".to_string(), "guide".to_string().into());
 
+        let mut heading_ids_by_basename: HashMap<ContentDocumentBasename, HeadingIds> =
+            HashMap::new();
+
+        heading_ids_by_basename.insert(
+            "guide".to_string().into(),
+            HeadingIds {
+                ids_by_offset: Arc::new([(0, "install".to_string())].into()),
+            },
+        );
+
         ContentDocumentLinker {
             content_document_basename_by_id: Arc::new(content_document_basename_by_id),
             content_document_by_basename: Arc::new(content_document_by_basename),
+            heading_ids_by_basename: Arc::new(heading_ids_by_basename),
         }
     }
 
@@ -112,6 +178,40 @@ mod tests {
         assert_eq!(linker().link_to("guide"), Ok("/guide/".to_string()));
     }
 
+    #[test]
+    fn link_to_keeps_fragment_of_existing_heading() {
+        assert_eq!(
+            linker().link_to("guide#install"),
+            Ok("/guide/#install".to_string())
+        );
+        assert_eq!(
+            linker().link_to("#guide-id#install"),
+            Ok("/guide/#install".to_string())
+        );
+    }
+
+    #[test]
+    fn link_to_fails_for_missing_heading() {
+        assert!(linker().link_to("guide#missing").is_err());
+    }
+
+    #[test]
+    fn link_from_resolves_fragment_against_own_headings() {
+        let heading_ids = HeadingIds {
+            ids_by_offset: Arc::new([(0, "usage".to_string())].into()),
+        };
+
+        assert_eq!(
+            linker().link_from(&heading_ids, "#usage"),
+            Ok("#usage".to_string())
+        );
+        assert_eq!(
+            linker().link_from(&heading_ids, "#guide-id"),
+            Ok("/guide/".to_string())
+        );
+        assert!(linker().link_from(&heading_ids, "#missing").is_err());
+    }
+
     #[test]
     fn link_to_fails_for_non_renderable_document() {
         assert!(linker().link_to("draft").is_err());
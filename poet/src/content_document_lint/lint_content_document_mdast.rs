@@ -0,0 +1,109 @@
+use markdown::mdast::Code;
+use markdown::mdast::MdxjsEsm;
+use markdown::mdast::Node;
+use markdown::mdast::Yaml;
+use syntect::parsing::SyntaxSet;
+
+use crate::content_document_lint::ContentDocumentLint;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+
+/// Code blocks are only checked against a syntax set when they are going to
+/// be highlighted.
+fn lint_node(mdast: &Node, syntax_set: Option<&SyntaxSet>, lints: &mut Vec<ContentDocumentLint>) {
+    match mdast {
+        Node::Code(Code {
+            lang: Some(lang),
+            position,
+            ..
+        }) if syntax_set
+            .is_some_and(|syntax_set| syntax_set.find_syntax_by_token(lang).is_none()) =>
+        {
+            lints.push(ContentDocumentLint {
+                code: DiagnosticCode::MissingSyntax,
+                message: format!("No syntax found for language: {lang}"),
+                position: position.clone(),
+            });
+        }
+        Node::MdxjsEsm(MdxjsEsm { position, .. }) => {
+            lints.push(ContentDocumentLint {
+                code: DiagnosticCode::UnsupportedNode,
+                message: "MDX ESM expressions are not supported".to_string(),
+                position: position.clone(),
+            });
+        }
+        Node::Yaml(Yaml { position, .. }) => {
+            lints.push(ContentDocumentLint {
+                code: DiagnosticCode::UnsupportedNode,
+                message: "YAML front-matter is not supported, use TOML instead".to_string(),
+                position: position.clone(),
+            });
+        }
+        _ => {}
+    }
+
+    if let Some(children) = mdast.children() {
+        for child in children {
+            lint_node(child, syntax_set, lints);
+        }
+    }
+}
+
+pub fn lint_content_document_mdast(
+    mdast: &Node,
+    syntax_set: &SyntaxSet,
+) -> Vec<ContentDocumentLint> {
+    let mut lints = Vec::new();
+
+    lint_node(mdast, Some(syntax_set), &mut lints);
+
+    lints
+}
+
+/// Prompts are evaluated back into Markdown, so their code blocks are left
+/// as they are.
+pub fn lint_prompt_document_mdast(mdast: &Node) -> Vec<ContentDocumentLint> {
+    let mut lints = Vec::new();
+
+    lint_node(mdast, None, &mut lints);
+
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    #[test]
+    fn reports_code_blocks_without_a_known_syntax() -> Result<()> {
+        let mdast =
+            string_to_mdast("# Code\n\n```rust\nfn main() {}\n```\n\n```nope\nwhat\n```\n")?;
+        let lints = lint_content_document_mdast(&mdast, &SyntaxSet::load_defaults_newlines());
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].code, DiagnosticCode::MissingSyntax);
+        assert_eq!(lints[0].message, "No syntax found for language: nope");
+        assert_eq!(
+            lints[0]
+                .position
+                .as_ref()
+                .map(|position| position.start.line),
+            Some(7)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_unsupported_nodes_but_not_code_blocks_in_prompts() -> Result<()> {
+        let mdast = string_to_mdast("---\ntitle: Prompt\n---\n\n```nope\nwhat\n```\n")?;
+        let lints = lint_prompt_document_mdast(&mdast);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].code, DiagnosticCode::UnsupportedNode);
+
+        Ok(())
+    }
+}
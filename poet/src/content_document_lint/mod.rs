@@ -0,0 +1,14 @@
+pub mod lint_content_document_mdast;
+
+use markdown::unist::Position;
+
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+
+/// Something that does not fail the build, but most likely renders
+/// differently than intended.
+#[derive(Clone, Debug)]
+pub struct ContentDocumentLint {
+    pub code: DiagnosticCode,
+    pub message: String,
+    pub position: Option<Position>,
+}
@@ -37,6 +37,47 @@ impl ContentDocumentReference {
         Ok(format!("{}index.html", self.basename_link_stem()?).into())
     }
 
+    /// Markdown alternate of the page, served next to its HTML version
+    pub fn markdown_link(&self) -> Result<String, String> {
+        Ok(format!("{}index.md", self.canonical_link()?))
+    }
+
+    /// Starts without leading slash
+    pub fn markdown_target_file_relative_path(&self) -> Result<PathBuf, String> {
+        Ok(format!("{}index.md", self.basename_link_stem()?).into())
+    }
+
+    /// Listing pages after the first one live under `page/<number>/`
+    /// next to the document that paginates them.
+    pub fn page_basename_path(&self, page: usize) -> Result<PathBuf, String> {
+        if page > 1 {
+            Ok(format!("{}page/{page}", self.basename_link_stem()?).into())
+        } else {
+            Ok(self.basename_path.clone())
+        }
+    }
+
+    pub fn page_markdown_link(&self, page: usize) -> Result<String, String> {
+        Ok(format!("{}index.md", self.page_canonical_link(page)?))
+    }
+
+    pub fn page_canonical_link(&self, page: usize) -> Result<String, String> {
+        if page > 1 {
+            Ok(format!("{}page/{page}/", self.canonical_link()?))
+        } else {
+            self.canonical_link()
+        }
+    }
+
+    /// Starts without leading slash
+    pub fn page_target_file_relative_path(&self, page: usize) -> Result<PathBuf, String> {
+        if page > 1 {
+            Ok(format!("{}page/{page}/index.html", self.basename_link_stem()?).into())
+        } else {
+            self.target_file_relative_path()
+        }
+    }
+
     fn basename_link_stem(&self) -> Result<String, String> {
         if self.basename_path.ends_with("index") {
             if let Some(parent) = self.basename_path.parent() {
@@ -101,6 +142,10 @@ impl ContentDocumentReference {
     fn rhai_front_matter(&mut self) -> ContentDocumentFrontMatter {
         self.front_matter.clone()
     }
+
+    fn rhai_markdown_link(&mut self) -> Result<String, Box<EvalAltResult>> {
+        Ok(self.markdown_link()?)
+    }
 }
 
 impl CustomType for ContentDocumentReference {
@@ -110,7 +155,8 @@ impl CustomType for ContentDocumentReference {
             .with_get("basename", Self::rhai_basename)
             .with_get("basename_last_stem", Self::rhai_basename_last_stem)
             .with_get("canonical_link", Self::rhai_canonical_link)
-            .with_get("front_matter", Self::rhai_front_matter);
+            .with_get("front_matter", Self::rhai_front_matter)
+            .with_get("markdown_link", Self::rhai_markdown_link);
     }
 }
 
@@ -236,6 +282,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn markdown_alternate_is_placed_next_to_page() -> Result<()> {
+        let reference = ContentDocumentReference {
+            basename_path: "foo/bar".into(),
+            front_matter: ContentDocumentFrontMatter::mock("foo"),
+            generated_page_base_path: "/".to_string(),
+        };
+
+        assert_eq!(reference.markdown_link().unwrap(), "/foo/bar/index.md");
+        assert_eq!(
+            reference.markdown_target_file_relative_path().unwrap(),
+            PathBuf::from("foo/bar/index.md")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn page_paths_are_nested_under_paginating_document() -> Result<()> {
+        let reference = ContentDocumentReference {
+            basename_path: "blog/index".into(),
+            front_matter: ContentDocumentFrontMatter::mock("foo"),
+            generated_page_base_path: "/".to_string(),
+        };
+
+        assert_eq!(reference.page_canonical_link(1).unwrap(), "/blog/");
+        assert_eq!(reference.page_canonical_link(2).unwrap(), "/blog/page/2/");
+        assert_eq!(
+            reference.page_basename_path(3).unwrap(),
+            PathBuf::from("blog/page/3")
+        );
+        assert_eq!(
+            reference.page_target_file_relative_path(2).unwrap(),
+            PathBuf::from("blog/page/2/index.html")
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn canonical_link_does_not_add_trailing_slash_to_base_path() -> Result<()> {
         let reference = ContentDocumentReference {
@@ -5,6 +5,7 @@ use rhai::CustomType;
 use rhai::Dynamic;
 use rhai::TypeBuilder;
 
+use crate::content_document_basename::ContentDocumentBasename;
 use crate::content_document_reference::ContentDocumentReference;
 
 #[derive(Clone)]
@@ -27,6 +28,27 @@ impl ContentDocumentTreeNode {
         flat
     }
 
+    /// Ancestors of the document, starting with this node and ending with
+    /// the document itself
+    pub fn path_to(
+        &self,
+        basename: &ContentDocumentBasename,
+    ) -> Option<Vec<ContentDocumentReference>> {
+        if self.reference.basename() == *basename {
+            return Some(vec![self.reference.clone()]);
+        }
+
+        for node in &self.children {
+            if let Some(mut path) = node.path_to(basename) {
+                path.insert(0, self.reference.clone());
+
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
     fn rhai_children(&mut self) -> Array {
         self.children
             .iter()
@@ -90,4 +112,32 @@ mod tests {
 
         assert_eq!(basenames, vec!["root", "child-a", "grandchild", "child-b"]);
     }
+
+    #[test]
+    fn path_to_lists_ancestors_of_document() {
+        let mut grandchildren = LinkedList::new();
+        grandchildren.push_back(node("grandchild", LinkedList::new()));
+
+        let mut children = LinkedList::new();
+        children.push_back(node("child-a", LinkedList::new()));
+        children.push_back(node("child-b", grandchildren));
+
+        let root = node("root", children);
+        let basenames: Option<Vec<String>> =
+            root.path_to(&"grandchild".to_string().into()).map(|path| {
+                path.iter()
+                    .map(|reference| reference.basename().to_string())
+                    .collect()
+            });
+
+        assert_eq!(
+            basenames,
+            Some(vec![
+                "root".to_string(),
+                "child-b".to_string(),
+                "grandchild".to_string()
+            ])
+        );
+        assert!(root.path_to(&"missing".to_string().into()).is_none());
+    }
 }
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Stable identifiers of problems, so tools can tell them apart without
+/// parsing messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticCode {
+    BrokenLink,
+    BuildFailed,
+    DuplicateAlias,
+    DuplicateDocumentId,
+    DuplicateHeadingId,
+    InvalidAuthor,
+    InvalidCollection,
+    InvalidPagination,
+    InvalidPrompt,
+    InvalidTaxonomy,
+    MissingAuthor,
+    MissingSyntax,
+    RenderFailed,
+    UndefinedReference,
+    UnsupportedNode,
+    WriteFailed,
+}
+
+/// Prints the same kebab-case name that serialization produces.
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(code)) => formatter.write_str(&code),
+            _ => Err(fmt::Error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn displays_the_serialized_name() -> Result<()> {
+        for code in [
+            DiagnosticCode::BrokenLink,
+            DiagnosticCode::DuplicateDocumentId,
+            DiagnosticCode::InvalidPrompt,
+            DiagnosticCode::UnsupportedNode,
+        ] {
+            assert_eq!(
+                serde_json::to_value(code)?,
+                serde_json::Value::String(code.to_string())
+            );
+        }
+
+        assert_eq!(
+            DiagnosticCode::DuplicateDocumentId.to_string(),
+            "duplicate-document-id"
+        );
+
+        Ok(())
+    }
+}
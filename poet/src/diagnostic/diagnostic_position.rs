@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+/// One-based, like mdast positions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct DiagnosticPosition {
+    pub column: usize,
+    pub line: usize,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+use crate::diagnostic::diagnostic_position::DiagnosticPosition;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct DiagnosticRange {
+    pub end: DiagnosticPosition,
+    pub start: DiagnosticPosition,
+}
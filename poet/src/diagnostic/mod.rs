@@ -0,0 +1,102 @@
+pub mod diagnostic_code;
+pub mod diagnostic_position;
+pub mod diagnostic_range;
+pub mod diagnostic_severity;
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+use crate::diagnostic::diagnostic_range::DiagnosticRange;
+use crate::diagnostic::diagnostic_severity::DiagnosticSeverity;
+use crate::document_error_collection::DocumentErrorCollection;
+
+/// A single build problem, in a shape that CI and editors can consume.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    pub basename: Option<String>,
+    pub code: DiagnosticCode,
+    pub message: String,
+    pub range: Option<DiagnosticRange>,
+    pub severity: DiagnosticSeverity,
+    pub source_path: Option<String>,
+}
+
+impl Diagnostic {
+    /// Document errors keep their individual records, anything else failed
+    /// the build as a whole.
+    pub fn from_build_error(err: &anyhow::Error) -> Vec<Self> {
+        match err.downcast_ref::<DocumentErrorCollection>() {
+            Some(error_collection) => error_collection.diagnostics(),
+            None => vec![Self {
+                basename: None,
+                code: DiagnosticCode::BuildFailed,
+                message: format!("{err:#}"),
+                range: None,
+                severity: DiagnosticSeverity::Error,
+                source_path: None,
+            }],
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.source_path, &self.range, &self.basename) {
+            (Some(source_path), Some(range), _) => write!(
+                formatter,
+                "{source_path}:{}:{}: ",
+                range.start.line, range.start.column
+            )?,
+            (Some(source_path), None, _) => write!(formatter, "{source_path}: ")?,
+            (None, _, Some(basename)) => write!(formatter, "{basename}: ")?,
+            (None, _, None) => {}
+        }
+
+        write!(formatter, "{} [{}]", self.message, self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn finds_document_errors_behind_context() -> Result<()> {
+        let error_collection = DocumentErrorCollection::default();
+
+        error_collection.register_error(
+            "guide".to_string(),
+            DiagnosticCode::MissingAuthor,
+            anyhow!("Author does not exist: 'bob'"),
+        );
+
+        let err = anyhow::Error::from(error_collection).context("Failed to build project");
+        let diagnostics = Diagnostic::from_build_error(&err);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "guide: Author does not exist: 'bob' [missing-author]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_other_errors_as_build_failures() {
+        let diagnostics = Diagnostic::from_build_error(&anyhow!("No such directory"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_error());
+        assert_eq!(diagnostics[0].code, DiagnosticCode::BuildFailed);
+    }
+}
@@ -0,0 +1,12 @@
+use std::collections::BTreeSet;
+
+/// What rendering a document read, besides its own source file and the
+/// shortcodes it mentions.
+#[derive(Clone, Debug, Default)]
+pub struct DocumentDependencies {
+    pub available_authors: bool,
+    pub collections: BTreeSet<String>,
+    /// Paths as they were linked, so a document taking over an id is noticed
+    pub links: BTreeSet<String>,
+    pub taxonomies: BTreeSet<String>,
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use crate::document_dependencies::DocumentDependencies;
+
+/// Shared by every clone of a component context, so reads made from nested
+/// components are recorded for the document being rendered.
+#[derive(Clone, Default)]
+pub struct DocumentDependencyRecorder {
+    dependencies: Arc<Mutex<DocumentDependencies>>,
+}
+
+impl DocumentDependencyRecorder {
+    pub fn dependencies(&self) -> DocumentDependencies {
+        self.dependencies
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn record_available_authors(&self) {
+        self.update(|dependencies| dependencies.available_authors = true);
+    }
+
+    pub fn record_collection(&self, collection_name: &str) {
+        self.update(|dependencies| {
+            dependencies.collections.insert(collection_name.to_string());
+        });
+    }
+
+    pub fn record_link(&self, path: &str) {
+        self.update(|dependencies| {
+            dependencies.links.insert(path.to_string());
+        });
+    }
+
+    pub fn record_taxonomy(&self, taxonomy_name: &str) {
+        self.update(|dependencies| {
+            dependencies.taxonomies.insert(taxonomy_name.to_string());
+        });
+    }
+
+    fn update(&self, update: impl FnOnce(&mut DocumentDependencies)) {
+        update(
+            &mut self
+                .dependencies
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+    }
+}
@@ -1,23 +1,102 @@
 use std::cmp::Ordering;
 use std::fmt;
 
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+use crate::diagnostic::diagnostic_position::DiagnosticPosition;
+use crate::diagnostic::diagnostic_range::DiagnosticRange;
+use crate::diagnostic::diagnostic_severity::DiagnosticSeverity;
+use crate::filesystem::file_entry::FileEntry;
+use crate::render_code_frame::render_code_frame;
+use crate::source_position_error::SourcePositionError;
+
+#[derive(Debug)]
 pub struct DocumentError {
     pub basename: String,
+    pub code: DiagnosticCode,
     pub err: anyhow::Error,
+    /// When known, errors with a source position point into this file.
+    pub source_file: Option<FileEntry>,
+}
+
+impl DocumentError {
+    pub fn to_diagnostic(&self, severity: DiagnosticSeverity) -> Diagnostic {
+        let source_position = SourcePositionError::find_in(&self.err);
+
+        Diagnostic {
+            basename: Some(self.basename.clone()),
+            code: self.code,
+            message: self
+                .err
+                .chain()
+                .filter(|cause| !cause.is::<SourcePositionError>())
+                .map(|cause| cause.to_string())
+                .collect::<Vec<_>>()
+                .join(": "),
+            range: source_position.map(|source_position| DiagnosticRange {
+                end: DiagnosticPosition {
+                    column: source_position.end_column,
+                    line: source_position.end_line,
+                },
+                start: DiagnosticPosition {
+                    column: source_position.column,
+                    line: source_position.line,
+                },
+            }),
+            severity,
+            source_path: self
+                .source_file
+                .as_ref()
+                .map(|source_file| source_file.relative_path.display().to_string()),
+        }
+    }
 }
 
 impl fmt::Display for DocumentError {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(formatter, "{}:", self.basename)?;
 
+        let source_location = self
+            .source_file
+            .as_ref()
+            .zip(SourcePositionError::find_in(&self.err));
+
+        if let Some((source_file, source_position)) = source_location {
+            writeln!(
+                formatter,
+                "- {}:{}:{}",
+                source_file.relative_path.display(),
+                source_position.line,
+                source_position.column
+            )?;
+        }
+
         for cause in self.err.chain() {
+            if source_location.is_some() && cause.is::<SourcePositionError>() {
+                continue;
+            }
+
             writeln!(formatter, "- {cause}")?;
         }
 
+        if let Some((source_file, source_position)) = source_location {
+            write!(
+                formatter,
+                "{}",
+                render_code_frame(
+                    &source_file.contents,
+                    source_position.line,
+                    source_position.column
+                )
+            )?;
+        }
+
         Ok(())
     }
 }
 
+impl std::error::Error for DocumentError {}
+
 impl Eq for DocumentError {}
 
 impl Ord for DocumentError {
@@ -40,15 +119,19 @@ impl PartialOrd for DocumentError {
 
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
     use anyhow::anyhow;
 
     use super::*;
+    use crate::filesystem::file_entry_stub::FileEntryStub;
 
     #[test]
     fn display_lists_basename_then_full_error_chain() {
         let document_error = DocumentError {
             basename: "guide".to_string(),
+            code: DiagnosticCode::RenderFailed,
             err: anyhow!("root cause").context("outer context"),
+            source_file: None,
         };
 
         assert_eq!(
@@ -57,15 +140,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_points_into_the_source_file() -> Result<()> {
+        let document_error = DocumentError {
+            basename: "guide".to_string(),
+            code: DiagnosticCode::RenderFailed,
+            err: SourcePositionError {
+                column: 3,
+                end_column: 9,
+                end_line: 2,
+                line: 2,
+                source: anyhow!("Expression failed: 'nope'"),
+            }
+            .into(),
+            source_file: Some(
+                FileEntryStub {
+                    contents: "# Guide\nA {nope}\n".to_string(),
+                    relative_path: "content/guide.md".into(),
+                }
+                .try_into()?,
+            ),
+        };
+
+        assert_eq!(
+            format!("{document_error}"),
+            "guide:\n- content/guide.md:2:3\n- Expression failed: 'nope'\n  1 | # Guide\n> 2 | A {nope}\n    |   ^\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_to_diagnostic_with_range_and_source_path() -> Result<()> {
+        let document_error = DocumentError {
+            basename: "guide".to_string(),
+            code: DiagnosticCode::BrokenLink,
+            err: SourcePositionError {
+                column: 1,
+                end_column: 12,
+                end_line: 3,
+                line: 3,
+                source: anyhow!("Document does not exist: gone"),
+            }
+            .into(),
+            source_file: Some(
+                FileEntryStub {
+                    contents: "# Guide\n\n[Gone](gone)\n".to_string(),
+                    relative_path: "content/guide.md".into(),
+                }
+                .try_into()?,
+            ),
+        };
+
+        let diagnostic = document_error.to_diagnostic(DiagnosticSeverity::Error);
+
+        assert_eq!(
+            serde_json::to_string(&diagnostic)?,
+            r#"{"basename":"guide","code":"broken-link","message":"Document does not exist: gone","range":{"end":{"column":12,"line":3},"start":{"column":1,"line":3}},"severity":"error","source_path":"content/guide.md"}"#
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn orders_by_basename() {
         let alpha = DocumentError {
             basename: "alpha".to_string(),
+            code: DiagnosticCode::RenderFailed,
             err: anyhow!("first"),
+            source_file: None,
         };
         let beta = DocumentError {
             basename: "beta".to_string(),
+            code: DiagnosticCode::RenderFailed,
             err: anyhow!("second"),
+            source_file: None,
         };
 
         assert!(alpha < beta);
@@ -75,11 +224,15 @@ mod tests {
     fn equality_is_decided_by_basename() {
         let one = DocumentError {
             basename: "guide".to_string(),
+            code: DiagnosticCode::RenderFailed,
             err: anyhow!("first"),
+            source_file: None,
         };
         let another = DocumentError {
             basename: "guide".to_string(),
+            code: DiagnosticCode::RenderFailed,
             err: anyhow!("second"),
+            source_file: None,
         };
 
         assert!(one == another);
@@ -3,23 +3,102 @@ use std::fmt;
 use dashmap::DashMap;
 use itertools::Itertools as _;
 
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+use crate::diagnostic::diagnostic_severity::DiagnosticSeverity;
 use crate::document_error::DocumentError;
+use crate::filesystem::file_entry::FileEntry;
 
 #[derive(Default)]
 pub struct DocumentErrorCollection {
     errors: DashMap<String, Vec<DocumentError>>,
+    warnings: DashMap<String, Vec<DocumentError>>,
 }
 
 impl DocumentErrorCollection {
+    /// Warnings do not count, they never fail a build on their own.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
 
-    pub fn register_error(&self, basename: String, err: anyhow::Error) {
-        self.errors
+    /// Errors first, then warnings, each sorted by basename.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Self::sorted_diagnostics(&self.errors, DiagnosticSeverity::Error);
+
+        diagnostics.extend(Self::sorted_diagnostics(
+            &self.warnings,
+            DiagnosticSeverity::Warning,
+        ));
+
+        diagnostics
+    }
+
+    pub fn register_error(&self, basename: String, code: DiagnosticCode, err: anyhow::Error) {
+        Self::register(&self.errors, basename, code, None, err);
+    }
+
+    /// Errors with a source position are displayed with a code frame of the
+    /// source file.
+    pub fn register_source_error(
+        &self,
+        basename: String,
+        code: DiagnosticCode,
+        source_file: &FileEntry,
+        err: anyhow::Error,
+    ) {
+        Self::register(&self.errors, basename, code, Some(source_file), err);
+    }
+
+    pub fn register_warning(
+        &self,
+        basename: String,
+        code: DiagnosticCode,
+        source_file: &FileEntry,
+        err: anyhow::Error,
+    ) {
+        Self::register(&self.warnings, basename, code, Some(source_file), err);
+    }
+
+    fn register(
+        documents: &DashMap<String, Vec<DocumentError>>,
+        basename: String,
+        code: DiagnosticCode,
+        source_file: Option<&FileEntry>,
+        err: anyhow::Error,
+    ) {
+        documents
             .entry(basename.clone())
             .or_default()
-            .push(DocumentError { basename, err });
+            .push(DocumentError {
+                basename,
+                code,
+                err,
+                source_file: source_file.cloned(),
+            });
+    }
+
+    fn sorted_diagnostics(
+        documents: &DashMap<String, Vec<DocumentError>>,
+        severity: DiagnosticSeverity,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for errors in documents
+            .iter()
+            .sorted_by(|a, b| Ord::cmp(&a.key(), &b.key()))
+        {
+            for error in errors.value() {
+                diagnostics.push(error.to_diagnostic(severity));
+            }
+        }
+
+        diagnostics
+    }
+}
+
+impl fmt::Debug for DocumentErrorCollection {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
     }
 }
 
@@ -45,33 +124,103 @@ impl fmt::Display for DocumentErrorCollection {
     }
 }
 
+impl std::error::Error for DocumentErrorCollection {}
+
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
     use anyhow::anyhow;
 
     use super::*;
+    use crate::filesystem::file_entry_stub::FileEntryStub;
 
     #[test]
-    fn is_empty_until_an_error_is_registered() {
+    fn is_empty_until_an_error_is_registered() -> Result<()> {
         let collection = DocumentErrorCollection::default();
+        let source_file: FileEntry = FileEntryStub {
+            contents: "# Guide\n".to_string(),
+            relative_path: "content/guide.md".into(),
+        }
+        .try_into()?;
 
         assert!(collection.is_empty());
 
-        collection.register_error("guide".to_string(), anyhow!("boom"));
+        collection.register_warning(
+            "guide".to_string(),
+            DiagnosticCode::MissingSyntax,
+            &source_file,
+            anyhow!("careful"),
+        );
+
+        assert!(collection.is_empty());
+
+        collection.register_error(
+            "guide".to_string(),
+            DiagnosticCode::RenderFailed,
+            anyhow!("boom"),
+        );
 
         assert!(!collection.is_empty());
+
+        Ok(())
     }
 
     #[test]
     fn display_reports_count_and_sorts_errors_by_basename() {
         let collection = DocumentErrorCollection::default();
 
-        collection.register_error("beta".to_string(), anyhow!("second"));
-        collection.register_error("alpha".to_string(), anyhow!("first"));
+        collection.register_error(
+            "beta".to_string(),
+            DiagnosticCode::RenderFailed,
+            anyhow!("second"),
+        );
+        collection.register_error(
+            "alpha".to_string(),
+            DiagnosticCode::RenderFailed,
+            anyhow!("first"),
+        );
 
         assert_eq!(
             format!("{collection}"),
             "Multiple errors occurred (2 total):\nalpha:\n- first\n\nbeta:\n- second\n\n"
         );
     }
+
+    #[test]
+    fn lists_errors_before_warnings_in_diagnostics() -> Result<()> {
+        let collection = DocumentErrorCollection::default();
+        let source_file: FileEntry = FileEntryStub {
+            contents: "# Guide\n".to_string(),
+            relative_path: "content/guide.md".into(),
+        }
+        .try_into()?;
+
+        collection.register_warning(
+            "alpha".to_string(),
+            DiagnosticCode::MissingSyntax,
+            &source_file,
+            anyhow!("careful"),
+        );
+        collection.register_error(
+            "beta".to_string(),
+            DiagnosticCode::RenderFailed,
+            anyhow!("boom"),
+        );
+
+        let diagnostics: Vec<String> = collection
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                "beta: boom [render-failed]".to_string(),
+                "content/guide.md: careful [missing-syntax]".to_string(),
+            ]
+        );
+
+        Ok(())
+    }
 }
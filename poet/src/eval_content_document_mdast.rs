@@ -1,6 +1,8 @@
 use anyhow::Result;
 use anyhow::anyhow;
-use log::warn;
+use latex2mathml::DisplayStyle;
+use latex2mathml::latex_to_mathml;
+use markdown::mdast::AlignKind;
 use markdown::mdast::Blockquote;
 use markdown::mdast::Code;
 use markdown::mdast::Delete;
@@ -9,10 +11,14 @@ use markdown::mdast::FootnoteReference;
 use markdown::mdast::Heading;
 use markdown::mdast::Html;
 use markdown::mdast::Image;
+use markdown::mdast::ImageReference;
 use markdown::mdast::InlineCode;
+use markdown::mdast::InlineMath;
 use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
 use markdown::mdast::List;
 use markdown::mdast::ListItem;
+use markdown::mdast::Math;
 use markdown::mdast::MdxFlowExpression;
 use markdown::mdast::MdxJsxFlowElement;
 use markdown::mdast::MdxJsxTextElement;
@@ -26,6 +32,9 @@ use markdown::mdast::TableCell;
 use markdown::mdast::TableRow;
 use markdown::mdast::Text;
 use markdown::mdast::ThematicBreak;
+use rhai::Array;
+use rhai::Dynamic;
+use rhai::Map;
 use rhai_components::escape_html::escape_html;
 use rhai_components::escape_html_attribute::escape_html_attribute;
 use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
@@ -34,12 +43,280 @@ use syntect::html::ClassedHTMLGenerator;
 use syntect::parsing::SyntaxSet;
 use syntect::util::LinesWithEndings;
 
+use crate::admonition::Admonition;
 use crate::content_document_component_context::ContentDocumentComponentContext;
 use crate::eval_mdx_element::eval_mdx_element;
+use crate::find_admonition_in_blockquote::find_admonition_in_blockquote;
 use crate::is_external_link::is_external_link;
-use crate::mdast_children_to_heading_id::mdast_children_to_heading_id;
+use crate::link_definitions::link_definition::LinkDefinition;
+use crate::markdown_component_context::MarkdownComponentContext as _;
 use crate::parse_markdown_metadata_line::metadata_line_item::MetadataLineItem;
 use crate::parse_markdown_metadata_line::parse_markdown_metadata_line;
+use crate::source_position_error::SourcePositionError;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
+
+fn find_link_definition<'context>(
+    component_context: &'context ContentDocumentComponentContext,
+    identifier: &str,
+) -> Result<&'context LinkDefinition> {
+    component_context
+        .link_definitions
+        .get(identifier)
+        .ok_or_else(|| anyhow!("Undefined link reference: [{identifier}]"))
+}
+
+/// Renders GitHub-style alerts. The shortcode configured as
+/// `components.admonition`, if any, receives the alert kind and its title as
+/// props, and the evaluated contents.
+fn eval_admonition(
+    Admonition { children, kind }: Admonition,
+    component_context: &ContentDocumentComponentContext,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    syntax_set: &SyntaxSet,
+) -> Result<String> {
+    let content = eval_content_document_children(
+        &children,
+        component_context,
+        rhai_template_renderer,
+        syntax_set,
+    )?;
+
+    if let Some(admonition_component) = &component_context.components.admonition {
+        let mut props = Map::new();
+
+        props.insert("kind".into(), kind.name().into());
+        props.insert("title".into(), kind.title().into());
+
+        rhai_template_renderer.render(
+            admonition_component,
+            component_context.clone(),
+            Dynamic::from_map(props),
+            content.into(),
+        )
+    } else {
+        Ok(format!(
+            r#"<aside class="admonition admonition--{}" role="note"><p class="admonition__title">{}</p>{content}</aside>"#,
+            kind.name(),
+            kind.title(),
+        ))
+    }
+}
+
+fn eval_image(
+    alt: &str,
+    component_context: &ContentDocumentComponentContext,
+    title: &Option<String>,
+    url: &str,
+) -> Result<String> {
+    let mut result = format!("<img alt=\"{}\" ", escape_html_attribute(alt));
+
+    let src = if is_external_link(url) {
+        url
+    } else {
+        &match component_context.asset_manager.image(url) {
+            Ok(src) => src,
+            Err(err) => return Err(anyhow!(err)),
+        }
+    };
+
+    result.push_str(&format!("src=\"{}\"", escape_html_attribute(src)));
+
+    if let Some(title) = title {
+        result.push_str(&format!(" title=\"{}\"", escape_html_attribute(title)));
+    }
+
+    result.push('>');
+
+    Ok(result)
+}
+
+fn eval_link(
+    children: &Vec<Node>,
+    component_context: &ContentDocumentComponentContext,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    syntax_set: &SyntaxSet,
+    title: &Option<String>,
+    url: &str,
+) -> Result<String> {
+    let link = if is_external_link(url) {
+        url.to_string()
+    } else {
+        match component_context.link_to(url) {
+            Ok(link) => link,
+            Err(err) => return Err(anyhow!(err)),
+        }
+    };
+
+    let mut result = format!("<a href=\"{link}\"");
+
+    if let Some(title) = title {
+        result.push_str(&format!(" title=\"{}\"", title));
+    }
+
+    result.push('>');
+    result.push_str(&eval_content_document_children(
+        children,
+        component_context,
+        rhai_template_renderer,
+        syntax_set,
+    )?);
+    result.push_str("</a>");
+
+    Ok(result)
+}
+
+/// Renders LaTeX into MathML. The shortcode configured as `components.math`,
+/// if any, receives the MathML as its content, and the display style with the
+/// LaTeX source as props.
+fn eval_math(
+    component_context: &ContentDocumentComponentContext,
+    display: DisplayStyle,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    value: &str,
+) -> Result<String> {
+    let mathml = latex_to_mathml(value, display)
+        .map_err(|err| anyhow!("Unable to render math expression '{value}': {err}"))?;
+
+    if let Some(math_component) = &component_context.components.math {
+        let mut props = Map::new();
+
+        props.insert("display".into(), display.to_string().into());
+        props.insert("source".into(), value.into());
+
+        rhai_template_renderer.render(
+            math_component,
+            component_context.clone(),
+            Dynamic::from_map(props),
+            mathml.into(),
+        )
+    } else {
+        Ok(mathml)
+    }
+}
+
+fn table_cell_attributes(align: Option<&AlignKind>) -> &'static str {
+    match align {
+        Some(AlignKind::Center) => r#" style="text-align: center""#,
+        Some(AlignKind::Left) => r#" style="text-align: left""#,
+        Some(AlignKind::Right) => r#" style="text-align: right""#,
+        Some(AlignKind::None) | None => "",
+    }
+}
+
+/// Renders the first row as the table header. The shortcode configured as
+/// `components.table`, if any, receives the default markup as its content,
+/// and the evaluated cells with their alignment as props.
+fn eval_table(
+    align: &[AlignKind],
+    children: &[Node],
+    component_context: &ContentDocumentComponentContext,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    syntax_set: &SyntaxSet,
+) -> Result<String> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    for row in children {
+        let mut cells: Vec<String> = Vec::new();
+
+        for cell in row.children().into_iter().flatten() {
+            cells.push(match cell {
+                Node::TableCell(TableCell { children, .. }) => eval_content_document_children(
+                    children,
+                    component_context,
+                    rhai_template_renderer,
+                    syntax_set,
+                )?,
+                _ => eval_content_document_mdast(
+                    cell,
+                    component_context,
+                    rhai_template_renderer,
+                    syntax_set,
+                )?,
+            });
+        }
+
+        rows.push(cells);
+    }
+
+    let mut result = String::from("<table>");
+
+    if let Some((header, body)) = rows.split_first() {
+        result.push_str("<thead><tr>");
+
+        for (index, cell) in header.iter().enumerate() {
+            result.push_str(&format!(
+                r#"<th scope="col"{}>{cell}</th>"#,
+                table_cell_attributes(align.get(index))
+            ));
+        }
+
+        result.push_str("</tr></thead>");
+
+        if !body.is_empty() {
+            result.push_str("<tbody>");
+
+            for row in body {
+                result.push_str("<tr>");
+
+                for (index, cell) in row.iter().enumerate() {
+                    result.push_str(&format!(
+                        "<td{}>{cell}</td>",
+                        table_cell_attributes(align.get(index))
+                    ));
+                }
+
+                result.push_str("</tr>");
+            }
+
+            result.push_str("</tbody>");
+        }
+    }
+
+    result.push_str("</table>");
+
+    if let Some(table_component) = &component_context.components.table {
+        let to_array =
+            |cells: &Vec<String>| -> Array { cells.iter().cloned().map(Dynamic::from).collect() };
+        let mut props = Map::new();
+
+        props.insert(
+            "align".into(),
+            align
+                .iter()
+                .map(|align_kind| {
+                    Dynamic::from(match align_kind {
+                        AlignKind::Center => "center",
+                        AlignKind::Left => "left",
+                        AlignKind::Right => "right",
+                        AlignKind::None => "none",
+                    })
+                })
+                .collect::<Array>()
+                .into(),
+        );
+        props.insert(
+            "header".into(),
+            rows.first().map(to_array).unwrap_or_default().into(),
+        );
+        props.insert(
+            "rows".into(),
+            rows.iter()
+                .skip(1)
+                .map(|row| Dynamic::from_array(to_array(row)))
+                .collect::<Array>()
+                .into(),
+        );
+
+        rhai_template_renderer.render(
+            table_component,
+            component_context.clone(),
+            Dynamic::from_map(props),
+            result.into(),
+        )
+    } else {
+        Ok(result)
+    }
+}
 
 pub fn eval_content_document_children(
     children: &Vec<Node>,
@@ -61,24 +338,44 @@ pub fn eval_content_document_children(
     Ok(content)
 }
 
+/// Errors carry the source position of the innermost node that failed.
 pub fn eval_content_document_mdast(
     mdast: &Node,
     component_context: &ContentDocumentComponentContext,
     rhai_template_renderer: &RhaiTemplateRenderer,
     syntax_set: &SyntaxSet,
+) -> Result<String> {
+    eval_content_document_node(mdast, component_context, rhai_template_renderer, syntax_set)
+        .map_err(|err| SourcePositionError::attach(err, mdast.position()))
+}
+
+fn eval_content_document_node(
+    mdast: &Node,
+    component_context: &ContentDocumentComponentContext,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    syntax_set: &SyntaxSet,
 ) -> Result<String> {
     let mut result = String::new();
 
     match mdast {
         Node::Blockquote(Blockquote { children, .. }) => {
-            result.push_str("<blockquote>");
-            result.push_str(&eval_content_document_children(
-                children,
-                component_context,
-                rhai_template_renderer,
-                syntax_set,
-            )?);
-            result.push_str("</blockquote>");
+            if let Some(admonition) = find_admonition_in_blockquote(children) {
+                result.push_str(&eval_admonition(
+                    admonition,
+                    component_context,
+                    rhai_template_renderer,
+                    syntax_set,
+                )?);
+            } else {
+                result.push_str("<blockquote>");
+                result.push_str(&eval_content_document_children(
+                    children,
+                    component_context,
+                    rhai_template_renderer,
+                    syntax_set,
+                )?);
+                result.push_str("</blockquote>");
+            }
         }
         Node::Break(_) => {
             result.push_str("<br>");
@@ -136,9 +433,8 @@ pub fn eval_content_document_mdast(
 
                         result.push_str(&html_rs);
                     }
+                    // reported by the lint pass
                     None => {
-                        warn!("No syntax found for language: {}", lang);
-
                         result.push_str(&escape_html(value));
                     }
                 }
@@ -148,8 +444,8 @@ pub fn eval_content_document_mdast(
 
             result.push_str("</code></pre>");
         }
-        Node::Definition(node) => {
-            warn!("Definitions are not supported: {node:?}");
+        Node::Definition(_) => {
+            // definitions are resolved by the references using them
         }
         Node::Delete(Delete { children, .. }) => {
             result.push_str("<del>");
@@ -171,34 +467,55 @@ pub fn eval_content_document_mdast(
             )?);
             result.push_str("</em>");
         }
-        Node::FootnoteDefinition(node) => {
-            warn!("Footnote definitions are not supported: {node:?}");
+        Node::FootnoteDefinition(_) => {
+            // footnote definitions are rendered in the endnotes section
         }
         Node::FootnoteReference(FootnoteReference {
-            identifier, label, ..
-        }) => {
-            result.push_str(&format!(
-                "<a href=\"#footnote-{}\" role=\"doc-noteref\">{}</a>",
-                identifier,
-                if let Some(label) = label {
-                    label
-                } else {
-                    identifier
-                },
-            ));
-        }
+            identifier,
+            label,
+            position,
+        }) => match component_context
+            .footnotes
+            .as_ref()
+            .and_then(|footnotes| footnotes.get(identifier))
+        {
+            Some(footnote) => {
+                result.push_str(&format!(
+                    "<sup><a href=\"#{}\" id=\"{}\" role=\"doc-noteref\">{}</a></sup>",
+                    escape_html_attribute(&footnote.id()),
+                    escape_html_attribute(
+                        &footnote.reference_id(footnote.reference_index(position))
+                    ),
+                    footnote.number,
+                ));
+            }
+            None => {
+                result.push_str(&format!(
+                    "<a href=\"#footnote-{}\" role=\"doc-noteref\">{}</a>",
+                    escape_html_attribute(identifier),
+                    if let Some(label) = label {
+                        label
+                    } else {
+                        identifier
+                    },
+                ));
+            }
+        },
         Node::Heading(Heading {
-            children, depth, ..
+            children,
+            depth,
+            position,
         }) => {
             let tag = format!("h{}", depth);
+            let (children, _) = strip_explicit_heading_id(children);
 
             result.push_str(&format!(
                 "<{} id=\"{}\">",
                 tag,
-                escape_html_attribute(&mdast_children_to_heading_id(children)?)
+                escape_html_attribute(&component_context.heading_ids.id_for(&children, position)?)
             ));
             result.push_str(&eval_content_document_children(
-                children,
+                &children,
                 component_context,
                 rhai_template_renderer,
                 syntax_set,
@@ -211,33 +528,26 @@ pub fn eval_content_document_mdast(
         Node::Image(Image {
             alt, url, title, ..
         }) => {
-            result.push_str(&format!("<img alt=\"{}\" ", escape_html_attribute(alt)));
-
-            let src = if is_external_link(url) {
-                url
-            } else {
-                &match component_context.asset_manager.image(url) {
-                    Ok(src) => src,
-                    Err(err) => return Err(anyhow!(err)),
-                }
-            };
-
-            result.push_str(&format!("src=\"{}\"", escape_html_attribute(src)));
-
-            if let Some(title) = title {
-                result.push_str(&format!(" title=\"{}\"", escape_html_attribute(title)));
-            }
-
-            result.push('>');
+            result.push_str(&eval_image(alt, component_context, title, url)?);
         }
-        Node::ImageReference(node) => {
-            warn!("Image references are not supported: {node:?}");
+        Node::ImageReference(ImageReference {
+            alt, identifier, ..
+        }) => {
+            let LinkDefinition { title, url } =
+                find_link_definition(component_context, identifier)?;
+
+            result.push_str(&eval_image(alt, component_context, title, url)?);
         }
         Node::InlineCode(InlineCode { value, .. }) => {
             result.push_str(&format!("<code>{}</code>", escape_html_attribute(value)));
         }
-        Node::InlineMath(node) => {
-            warn!("Inline math expressions are not supported: {node:?}");
+        Node::InlineMath(InlineMath { value, .. }) => {
+            result.push_str(&eval_math(
+                component_context,
+                DisplayStyle::Inline,
+                rhai_template_renderer,
+                value,
+            )?);
         }
         Node::Link(Link {
             children,
@@ -245,40 +555,61 @@ pub fn eval_content_document_mdast(
             url,
             ..
         }) => {
-            let link = if is_external_link(url) {
-                url.clone()
-            } else {
-                match component_context.content_document_linker.link_to(url) {
-                    Ok(link) => link,
-                    Err(err) => return Err(anyhow!(err)),
-                }
-            };
-
-            result.push_str(&format!("<a href=\"{link}\""));
-
-            if let Some(title) = title {
-                result.push_str(&format!(" title=\"{}\"", title));
-            }
-
-            result.push('>');
-            result.push_str(&eval_content_document_children(
+            result.push_str(&eval_link(
                 children,
                 component_context,
                 rhai_template_renderer,
                 syntax_set,
+                title,
+                url,
             )?);
-            result.push_str("</a>");
         }
-        Node::LinkReference(node) => {
-            warn!("Link references are not supported: {node:?}");
+        Node::LinkReference(LinkReference {
+            children,
+            identifier,
+            ..
+        }) => {
+            let LinkDefinition { title, url } =
+                find_link_definition(component_context, identifier)?;
+
+            result.push_str(&eval_link(
+                children,
+                component_context,
+                rhai_template_renderer,
+                syntax_set,
+                title,
+                url,
+            )?);
         }
         Node::List(List {
-            children, ordered, ..
+            children,
+            ordered,
+            start,
+            ..
         }) => {
+            let class = if children.iter().any(|child| {
+                matches!(
+                    child,
+                    Node::ListItem(ListItem {
+                        checked: Some(_),
+                        ..
+                    })
+                )
+            }) {
+                r#" class="contains-task-list""#
+            } else {
+                ""
+            };
+
             if *ordered {
-                result.push_str("<ol>");
+                match start {
+                    Some(start) if *start != 1 => {
+                        result.push_str(&format!(r#"<ol{class} start="{start}">"#));
+                    }
+                    _ => result.push_str(&format!("<ol{class}>")),
+                }
             } else {
-                result.push_str("<ul>");
+                result.push_str(&format!("<ul{class}>"));
             }
 
             result.push_str(&eval_content_document_children(
@@ -294,27 +625,69 @@ pub fn eval_content_document_mdast(
                 result.push_str("</ul>");
             }
         }
-        Node::ListItem(ListItem { children, .. }) => {
-            result.push_str("<li>");
-            result.push_str(&eval_content_document_children(
+        Node::ListItem(ListItem {
+            checked, children, ..
+        }) => {
+            let content = eval_content_document_children(
                 children,
                 component_context,
                 rhai_template_renderer,
                 syntax_set,
-            )?);
+            )?;
+
+            match checked {
+                Some(checked) => {
+                    let checkbox = format!(
+                        r#"<input type="checkbox" disabled{}> "#,
+                        if *checked { " checked" } else { "" }
+                    );
+
+                    result.push_str(r#"<li class="task-list-item">"#);
+
+                    // Keep the checkbox on the same line as the item text
+                    match content.strip_prefix("<p>") {
+                        Some(paragraph) => {
+                            result.push_str("<p>");
+                            result.push_str(&checkbox);
+                            result.push_str(paragraph);
+                        }
+                        None => {
+                            result.push_str(&checkbox);
+                            result.push_str(&content);
+                        }
+                    }
+                }
+                None => {
+                    result.push_str("<li>");
+                    result.push_str(&content);
+                }
+            }
+
             result.push_str("</li>");
         }
-        Node::Math(node) => {
-            warn!("Math expressions are not supported: {node:?}");
+        Node::Math(Math { value, .. }) => {
+            result.push_str(&eval_math(
+                component_context,
+                DisplayStyle::Block,
+                rhai_template_renderer,
+                value,
+            )?);
         }
-        Node::MdxjsEsm(node) => {
-            warn!("MDX ESM expressions are not supported: {node:?}");
+        Node::MdxjsEsm(_) => {
+            // reported by the lint pass
         }
-        Node::MdxFlowExpression(MdxFlowExpression { value, .. })
-        | Node::MdxTextExpression(MdxTextExpression { value, .. }) => {
+        Node::MdxFlowExpression(MdxFlowExpression {
+            position, value, ..
+        })
+        | Node::MdxTextExpression(MdxTextExpression {
+            position, value, ..
+        }) => {
             result.push_str(
                 &rhai_template_renderer
-                    .render_expression(component_context.clone(), value)?
+                    .render_expression(component_context.clone(), value)
+                    .map_err(|err| {
+                        SourcePositionError::attach_to_expression(err, position.as_ref())
+                    })?
                     .to_string(),
             );
         }
@@ -361,6 +734,23 @@ pub fn eval_content_document_mdast(
                 rhai_template_renderer,
                 syntax_set,
             )?);
+
+            if let Some(footnotes) = &component_context.footnotes
+                && !footnotes.is_empty()
+            {
+                let endnotes = footnotes.render_endnotes();
+
+                if let Some(endnotes_component) = &component_context.components.endnotes {
+                    result.push_str(&rhai_template_renderer.render(
+                        endnotes_component,
+                        component_context.clone(),
+                        Dynamic::from_map(Map::new()),
+                        endnotes.into(),
+                    )?);
+                } else {
+                    result.push_str(&endnotes);
+                }
+            }
         }
         Node::Strong(Strong { children, .. }) => {
             result.push_str("<strong>");
@@ -372,15 +762,16 @@ pub fn eval_content_document_mdast(
             )?);
             result.push_str("</strong>");
         }
-        Node::Table(Table { children, .. }) => {
-            result.push_str("<table>");
-            result.push_str(&eval_content_document_children(
+        Node::Table(Table {
+            align, children, ..
+        }) => {
+            result.push_str(&eval_table(
+                align,
                 children,
                 component_context,
                 rhai_template_renderer,
                 syntax_set,
             )?);
-            result.push_str("</table>");
         }
         Node::TableCell(TableCell { children, .. }) => {
             result.push_str("<td>");
@@ -411,8 +802,8 @@ pub fn eval_content_document_mdast(
         Node::Toml(_) => {
             // ignore frontmatter during this pass
         }
-        Node::Yaml(node) => {
-            warn!("YAML front-matter is not supported, use TOML instead: {node:?}");
+        Node::Yaml(_) => {
+            // reported by the lint pass
         }
     }
 
@@ -441,6 +832,9 @@ mod tests {
     use crate::content_document_front_matter::ContentDocumentFrontMatter;
     use crate::content_document_linker::ContentDocumentLinker;
     use crate::content_document_reference::ContentDocumentReference;
+    use crate::find_footnotes_in_mdast::find_footnotes_in_mdast;
+    use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+    use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
     use crate::string_to_mdast::string_to_mdast;
 
     const ASSET_METAFILE: &str = indoc! {r#"
@@ -487,6 +881,7 @@ mod tests {
         ContentDocumentLinker {
             content_document_basename_by_id: Arc::new(HashMap::new()),
             content_document_by_basename: Arc::new(content_document_by_basename),
+            heading_ids_by_basename: Arc::new(HashMap::new()),
         }
     }
 
@@ -496,16 +891,24 @@ mod tests {
             authors: Vec::new(),
             available_authors: Arc::new(AuthorCollection::default()),
             available_collections: Arc::new(HashSet::new()),
+            components: Default::default(),
             content_document_collections_ranked: Arc::new(HashMap::new()),
             content_document_linker: linker(),
+            dependency_recorder: Default::default(),
+            footnotes: None,
             front_matter: ContentDocumentFrontMatter::mock("doc"),
+            heading_ids: Default::default(),
             is_watching: false,
+            link_definitions: Default::default(),
+            paginator: None,
             reference: ContentDocumentReference {
                 basename_path: "doc".into(),
                 front_matter: ContentDocumentFrontMatter::mock("doc"),
                 generated_page_base_path: "/".to_string(),
             },
+            site: Default::default(),
             table_of_contents: None,
+            taxonomy_index: Default::default(),
         })
     }
 
@@ -540,12 +943,13 @@ mod tests {
     }
 
     fn render_with_syntax_set(markdown: &str, syntax_set: &SyntaxSet) -> Result<String> {
-        eval_content_document_mdast(
-            &string_to_mdast(markdown)?,
-            &context()?,
-            &renderer()?,
-            syntax_set,
-        )
+        let mdast = string_to_mdast(markdown)?;
+        let mut context = context()?;
+
+        context.heading_ids = find_heading_ids_in_mdast(&mdast)?;
+        context.link_definitions = find_link_definitions_in_mdast(&mdast);
+
+        eval_content_document_mdast(&mdast, &context, &renderer()?, syntax_set)
     }
 
     fn render(markdown: &str) -> Result<String> {
@@ -586,6 +990,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn renders_unique_and_explicit_heading_ids() -> Result<()> {
+        assert_eq!(
+            render("## Examples\n\n## Examples\n\n## Usage {#how-to}")?,
+            "<h2 id=\"examples\">Examples</h2><h2 id=\"examples-1\">Examples</h2><h2 id=\"how-to\">Usage</h2>"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn renders_blockquote() -> Result<()> {
         assert_eq!(
@@ -596,6 +1010,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn renders_alert_blockquote_as_admonition() -> Result<()> {
+        assert_eq!(
+            render("> [!NOTE]\n> Read *this* first")?,
+            "<aside class=\"admonition admonition--note\" role=\"note\"><p class=\"admonition__title\">Note</p><p>Read <em>this</em> first</p></aside>"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn renders_unordered_list() -> Result<()> {
         let rendered = render("- first\n- second")?;
@@ -618,6 +1042,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn renders_ordered_list_start_number() -> Result<()> {
+        assert!(render("5. fifth\n6. sixth")?.starts_with(r#"<ol start="5">"#));
+        assert!(render("1. first")?.starts_with("<ol>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_task_list_items_as_disabled_checkboxes() -> Result<()> {
+        assert_eq!(
+            render("- [x] done\n- [ ] todo")?,
+            r#"<ul class="contains-task-list"><li class="task-list-item"><p><input type="checkbox" disabled checked> done</p></li><li class="task-list-item"><p><input type="checkbox" disabled> todo</p></li></ul>"#
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn renders_thematic_break() -> Result<()> {
         assert_eq!(render("***")?, "<hr>");
@@ -629,10 +1071,32 @@ mod tests {
     fn renders_table() -> Result<()> {
         let rendered = render("| H1 | H2 |\n| -- | -- |\n| a | b |")?;
 
-        assert!(rendered.starts_with("<table>"));
-        assert!(rendered.ends_with("</table>"));
-        assert!(rendered.contains("<tr><td>H1</td><td>H2</td></tr>"));
-        assert!(rendered.contains("<tr><td>a</td><td>b</td></tr>"));
+        assert_eq!(
+            rendered,
+            r#"<table><thead><tr><th scope="col">H1</th><th scope="col">H2</th></tr></thead><tbody><tr><td>a</td><td>b</td></tr></tbody></table>"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_table_column_alignment() -> Result<()> {
+        let rendered = render("| L | C | R | N |\n| :- | :-: | -: | - |\n| 1 | 2 | 3 | 4 |")?;
+
+        assert!(rendered.contains(r#"<th scope="col" style="text-align: left">L</th>"#));
+        assert!(rendered.contains(r#"<th scope="col" style="text-align: center">C</th>"#));
+        assert!(rendered.contains(r#"<td style="text-align: right">3</td>"#));
+        assert!(rendered.contains("<td>4</td>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_header_only_table_without_body() -> Result<()> {
+        assert_eq!(
+            render("| H |\n| - |")?,
+            r#"<table><thead><tr><th scope="col">H</th></tr></thead></table>"#
+        );
 
         Ok(())
     }
@@ -724,8 +1188,27 @@ mod tests {
     }
 
     #[test]
-    fn skips_unsupported_math_node() -> Result<()> {
-        assert_eq!(render("$$\nx = 1\n$$")?, "");
+    fn renders_block_math_to_mathml() -> Result<()> {
+        let rendered = render("$$\nx = 1\n$$")?;
+
+        assert!(
+            rendered.starts_with(
+                r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="block">"#
+            )
+        );
+        assert!(rendered.contains("<mi>x</mi>"));
+        assert!(rendered.ends_with("</math>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_inline_math_to_mathml() -> Result<()> {
+        let rendered = render("Euler $e^{i\\pi}$ identity")?;
+
+        assert!(rendered.starts_with("<p>Euler <math"));
+        assert!(rendered.contains(r#"display="inline""#));
+        assert!(rendered.contains("<msup>"));
 
         Ok(())
     }
@@ -772,4 +1255,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn renders_numbered_footnotes_with_endnotes_section() -> Result<()> {
+        let mdast = string_to_mdast("one[^b] two[^a] three[^b]\n\n[^a]: Alpha\n\n[^b]: Beta")?;
+        let rhai_template_renderer = renderer()?;
+        let syntax_set = SyntaxSet::new();
+        let context = context()?;
+        let footnotes =
+            find_footnotes_in_mdast(&mdast, &context, &rhai_template_renderer, &syntax_set)?;
+        let rendered = eval_content_document_mdast(
+            &mdast,
+            &context.with_footnotes(footnotes),
+            &rhai_template_renderer,
+            &syntax_set,
+        )?;
+
+        assert!(rendered.contains(
+            r##"<sup><a href="#footnote-b" id="footnote-reference-b" role="doc-noteref">1</a></sup>"##
+        ));
+        assert!(rendered.contains(
+            r##"<sup><a href="#footnote-a" id="footnote-reference-a" role="doc-noteref">2</a></sup>"##
+        ));
+        assert!(rendered.contains(r#"id="footnote-reference-b-2""#));
+        assert!(rendered.contains(
+            r#"<section class="footnotes" role="doc-endnotes"><ol><li id="footnote-b"><p>Beta"#
+        ));
+        assert!(rendered.contains(r##"<a href="#footnote-reference-b-2" role="doc-backlink">"##));
+        assert!(rendered.ends_with("</ol></section>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_reference_links_and_images_through_definitions() -> Result<()> {
+        let rendered = render(indoc! {r#"
+            Read [the guide][docs] or [docs], see ![Logo][brand].
+
+            [docs]: guide "Guide"
+            [brand]: logo.png
+        "#})?;
+
+        assert_eq!(
+            rendered,
+            r#"<p>Read <a href="/guide/" title="Guide">the guide</a> or <a href="/guide/" title="Guide">docs</a>, see <img alt="Logo" src="/static/logo_ABCDEF12.png">.</p>"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_reference_link_to_missing_document() {
+        assert!(render("[x][ghost]\n\n[ghost]: ghost").is_err());
+    }
 }
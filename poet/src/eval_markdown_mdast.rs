@@ -1,17 +1,23 @@
 use anyhow::Result;
 use anyhow::anyhow;
-use log::warn;
+use markdown::mdast::AlignKind;
 use markdown::mdast::Blockquote;
 use markdown::mdast::Code;
 use markdown::mdast::Delete;
 use markdown::mdast::Emphasis;
+use markdown::mdast::FootnoteDefinition;
+use markdown::mdast::FootnoteReference;
 use markdown::mdast::Heading;
 use markdown::mdast::Html;
 use markdown::mdast::Image;
+use markdown::mdast::ImageReference;
 use markdown::mdast::InlineCode;
+use markdown::mdast::InlineMath;
 use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
 use markdown::mdast::List;
 use markdown::mdast::ListItem;
+use markdown::mdast::Math;
 use markdown::mdast::MdxFlowExpression;
 use markdown::mdast::MdxJsxFlowElement;
 use markdown::mdast::MdxJsxTextElement;
@@ -25,13 +31,17 @@ use markdown::mdast::TableCell;
 use markdown::mdast::TableRow;
 use markdown::mdast::Text;
 use markdown::mdast::ThematicBreak;
-use rhai_components::escape_html::escape_html;
 use rhai_components::escape_html_attribute::escape_html_attribute;
 
+use crate::admonition::Admonition;
+use crate::eval_markdown_mdast_params::EvalMarkdownMdastParams;
 use crate::eval_mdx_element::eval_mdx_element;
-use crate::eval_prompt_document_mdast_params::EvalPromptDocumentMdastParams;
+use crate::find_admonition_in_blockquote::find_admonition_in_blockquote;
 use crate::is_external_link::is_external_link;
-use crate::prompt_document_component_context::PromptDocumentComponentContext;
+use crate::link_definitions::link_definition::LinkDefinition;
+use crate::markdown_component_context::MarkdownComponentContext;
+use crate::source_position_error::SourcePositionError;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
 
 fn into_blockquote(input: String) -> String {
     input
@@ -41,31 +51,89 @@ fn into_blockquote(input: String) -> String {
         .join("\n")
 }
 
-fn trim_chunk(chunk: String) -> Result<String> {
-    if chunk.is_empty() {
-        return Ok(chunk);
+fn find_link_definition<TComponentContext: MarkdownComponentContext>(
+    component_context: &TComponentContext,
+    identifier: &str,
+) -> Result<LinkDefinition> {
+    component_context
+        .link_definitions()
+        .get(identifier)
+        .cloned()
+        .ok_or_else(|| anyhow!("Undefined link reference: [{identifier}]"))
+}
+
+fn eval_image<TComponentContext: MarkdownComponentContext>(
+    alt: &str,
+    component_context: &TComponentContext,
+    title: &Option<String>,
+    url: &str,
+) -> Result<String> {
+    let mut result = format!("![{}](", escape_html_attribute(alt));
+
+    let src = if is_external_link(url) {
+        url
+    } else {
+        &match component_context.asset_manager().image(url) {
+            Ok(src) => src,
+            Err(err) => return Err(anyhow!(err)),
+        }
+    };
+
+    result.push_str(&escape_html_attribute(src));
+
+    if let Some(title) = title {
+        result.push_str(&format!(" \"{}\"", escape_html_attribute(title)));
     }
 
-    Ok(chunk
-        .trim()
-        .strip_prefix(':')
-        .ok_or_else(|| anyhow!("Unable to strip chunk prefix from '{chunk}'"))?
-        .trim_start()
-        .to_string())
+    result.push(')');
+
+    Ok(result)
 }
 
-pub fn eval_prompt_document_children(
+fn eval_link<TComponentContext: MarkdownComponentContext>(
     children: &Vec<Node>,
-    params: EvalPromptDocumentMdastParams,
-    prompt_document_component_context: &mut PromptDocumentComponentContext,
+    params: EvalMarkdownMdastParams,
+    component_context: &mut TComponentContext,
+    title: &Option<String>,
+    url: &str,
+) -> Result<String> {
+    let mut result = format!(
+        "[{}]",
+        eval_markdown_children(children, params.regular_element(), component_context)?
+    );
+
+    let link = if is_external_link(url) {
+        url.to_string()
+    } else {
+        match component_context.link_to(url) {
+            Ok(link) => link,
+            Err(err) => return Err(anyhow!(err)),
+        }
+    };
+
+    result.push_str(&format!("({link}"));
+
+    if let Some(title) = title {
+        result.push_str(&format!(" \"{}\"", title));
+    }
+
+    result.push(')');
+
+    Ok(result)
+}
+
+pub fn eval_markdown_children<TComponentContext: MarkdownComponentContext>(
+    children: &Vec<Node>,
+    params: EvalMarkdownMdastParams,
+    component_context: &mut TComponentContext,
 ) -> Result<String> {
     let mut content = String::new();
     let mut is_first_child = true;
 
     for child in children {
-        content.push_str(&eval_prompt_document_mdast(
+        content.push_str(&eval_markdown_mdast(
             params.child(child, is_first_child),
-            prompt_document_component_context,
+            component_context,
         )?);
 
         is_first_child = false;
@@ -75,70 +143,110 @@ pub fn eval_prompt_document_children(
 }
 
 /// Converts Markdown syntax into tidied up Markdown with resolved image paths,
-/// references, and such
-pub fn eval_prompt_document_mdast(
-    params @ EvalPromptDocumentMdastParams {
+/// references, and such. Errors carry the source position of the innermost
+/// node that failed. Blocks in the document root are separated with blank
+/// lines.
+pub fn eval_markdown_mdast<TComponentContext: MarkdownComponentContext>(
+    params: EvalMarkdownMdastParams,
+    component_context: &mut TComponentContext,
+) -> Result<String> {
+    let position = params.mdast.position();
+
+    eval_markdown_node(params, component_context)
+        .map_err(|err| SourcePositionError::attach(err, position))
+}
+
+fn eval_markdown_node<TComponentContext: MarkdownComponentContext>(
+    params @ EvalMarkdownMdastParams {
         mdast,
         is_directly_in_root,
         is_first_child,
         is_in_top_paragraph,
         rhai_template_renderer,
-    }: EvalPromptDocumentMdastParams,
-    prompt_document_component_context: &mut PromptDocumentComponentContext,
+    }: EvalMarkdownMdastParams,
+    component_context: &mut TComponentContext,
 ) -> Result<String> {
     let mut result = String::new();
 
     match mdast {
         Node::Blockquote(Blockquote { children, .. }) => {
-            result.push_str(&into_blockquote(eval_prompt_document_children(
-                children,
-                params.regular_element(),
-                prompt_document_component_context,
-            )?));
+            if let Some(Admonition { children, kind }) = find_admonition_in_blockquote(children) {
+                let content =
+                    eval_markdown_children(&children, params.regular_element(), component_context)?;
+
+                result.push_str(&into_blockquote(format!(
+                    "[!{}]\n{}",
+                    kind.marker(),
+                    content.trim()
+                )));
+            } else {
+                result.push_str(&into_blockquote(eval_markdown_children(
+                    children,
+                    params.regular_element(),
+                    component_context,
+                )?));
+            }
         }
         Node::Break(_) => {
             result.push_str("  \n");
         }
         Node::Code(Code { lang, value, .. }) => {
             result.push_str(&format!("```{}\n", lang.clone().unwrap_or("".to_string())));
-            result.push_str(&escape_html(value));
+            result.push_str(value);
             result.push_str("\n```");
         }
-        Node::Definition(node) => {
-            warn!("Definitions are not supported: {node:?}");
+        Node::Definition(_) => {
+            // definitions are resolved by the references using them
         }
         Node::Delete(Delete { children, .. }) => {
             result.push_str("~~");
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
             result.push_str("~~");
         }
         Node::Emphasis(Emphasis { children, .. }) => {
             result.push('*');
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
             result.push('*');
         }
-        Node::FootnoteDefinition(node) => {
-            warn!("Footnote definitions are not supported: {node:?}");
+        Node::FootnoteDefinition(FootnoteDefinition {
+            children,
+            identifier,
+            label,
+            ..
+        }) => {
+            let content =
+                eval_markdown_children(children, params.regular_element(), component_context)?;
+
+            result.push_str(&format!(
+                "[^{}]: {}",
+                label.as_ref().unwrap_or(identifier),
+                content.trim().lines().collect::<Vec<_>>().join("\n    ")
+            ));
         }
-        Node::FootnoteReference(node) => {
-            warn!("Footnote references are not supported: {node:?}");
+        Node::FootnoteReference(FootnoteReference {
+            identifier, label, ..
+        }) => {
+            result.push_str(&format!("[^{}]", label.as_ref().unwrap_or(identifier)));
         }
         Node::Heading(Heading {
             children, depth, ..
         }) => {
+            let (children, _) = strip_explicit_heading_id(children);
+
             result.push_str(&("#".repeat(*depth as usize)));
-            result.push_str(&eval_prompt_document_children(
-                children,
+            result.push(' ');
+            result.push_str(&eval_markdown_children(
+                &children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
         }
         Node::Html(Html { value, .. }) => {
@@ -147,33 +255,21 @@ pub fn eval_prompt_document_mdast(
         Node::Image(Image {
             alt, url, title, ..
         }) => {
-            result.push_str(&format!("![{}](", escape_html_attribute(alt)));
-
-            let src = if is_external_link(url) {
-                url
-            } else {
-                &match prompt_document_component_context.asset_manager.image(url) {
-                    Ok(src) => src,
-                    Err(err) => return Err(anyhow!(err)),
-                }
-            };
-
-            result.push_str(&escape_html_attribute(src));
-
-            if let Some(title) = title {
-                result.push_str(&format!(" \"{}\"", escape_html_attribute(title)));
-            }
-
-            result.push(')');
+            result.push_str(&eval_image(alt, component_context, title, url)?);
         }
-        Node::ImageReference(node) => {
-            warn!("Image references are not supported: {node:?}");
+        Node::ImageReference(ImageReference {
+            alt, identifier, ..
+        }) => {
+            let LinkDefinition { title, url } =
+                find_link_definition(component_context, identifier)?;
+
+            result.push_str(&eval_image(alt, component_context, &title, &url)?);
         }
         Node::InlineCode(InlineCode { value, .. }) => {
-            result.push_str(&format!("`{}`", escape_html_attribute(value)));
+            result.push_str(&format!("`{value}`"));
         }
-        Node::InlineMath(node) => {
-            warn!("Inline math expressions are not supported: {node:?}");
+        Node::InlineMath(InlineMath { value, .. }) => {
+            result.push_str(&format!("${value}$"));
         }
         Node::Link(Link {
             children,
@@ -181,68 +277,100 @@ pub fn eval_prompt_document_mdast(
             url,
             ..
         }) => {
-            result.push_str(&format!(
-                "[{}]",
-                eval_prompt_document_children(
-                    children,
-                    params.regular_element(),
-                    prompt_document_component_context
-                )?
-            ));
-
-            let link = if is_external_link(url) {
-                url.clone()
-            } else {
-                match prompt_document_component_context
-                    .content_document_linker
-                    .link_to(url)
-                {
-                    Ok(link) => link,
-                    Err(err) => return Err(anyhow!(err)),
-                }
-            };
-
-            result.push_str(&format!("({link}"));
-
-            if let Some(title) = title {
-                result.push_str(&format!(" \"{}\"", title));
-            }
-
-            result.push(')');
-        }
-        Node::LinkReference(node) => {
-            warn!("Link references are not supported: {node:?}");
+            result.push_str(&eval_link(children, params, component_context, title, url)?);
         }
-        Node::List(List { children, .. }) => {
-            result.push('\n');
+        Node::LinkReference(LinkReference {
+            children,
+            identifier,
+            ..
+        }) => {
+            let LinkDefinition { title, url } =
+                find_link_definition(component_context, identifier)?;
 
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_link(
                 children,
-                params.regular_element(),
-                prompt_document_component_context,
+                params,
+                component_context,
+                &title,
+                &url,
             )?);
-
+        }
+        Node::List(List {
+            children,
+            ordered,
+            start,
+            ..
+        }) => {
             result.push('\n');
+
+            let mut number = start.unwrap_or(1);
+
+            for child in children {
+                let (checked, item_children) = match child {
+                    Node::ListItem(ListItem {
+                        checked, children, ..
+                    }) => (*checked, children),
+                    _ => continue,
+                };
+
+                let mut marker = if *ordered {
+                    format!("{number}. ")
+                } else {
+                    "- ".to_string()
+                };
+
+                match checked {
+                    Some(true) => marker.push_str("[x] "),
+                    Some(false) => marker.push_str("[ ] "),
+                    None => {}
+                }
+
+                let content = eval_markdown_children(
+                    item_children,
+                    params.clone().regular_element(),
+                    component_context,
+                )?;
+                let indentation = " ".repeat(marker.len());
+
+                result.push_str(&marker);
+                result.push_str(
+                    &content
+                        .trim()
+                        .lines()
+                        .collect::<Vec<_>>()
+                        .join(&format!("\n{indentation}")),
+                );
+                result.push('\n');
+
+                number += 1;
+            }
         }
         Node::ListItem(ListItem { children, .. }) => {
             result.push_str("- ");
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
         }
-        Node::Math(node) => {
-            warn!("Math expressions are not supported: {node:?}");
+        Node::Math(Math { value, .. }) => {
+            result.push_str(&format!("$$\n{value}\n$$"));
         }
-        Node::MdxjsEsm(node) => {
-            warn!("MDX ESM expressions are not supported: {node:?}");
+        Node::MdxjsEsm(_) => {
+            // reported as a lint when prompts are built
         }
-        Node::MdxFlowExpression(MdxFlowExpression { value, .. })
-        | Node::MdxTextExpression(MdxTextExpression { value, .. }) => {
+        Node::MdxFlowExpression(MdxFlowExpression {
+            position, value, ..
+        })
+        | Node::MdxTextExpression(MdxTextExpression {
+            position, value, ..
+        }) => {
             result.push_str(
                 &rhai_template_renderer
-                    .render_expression(prompt_document_component_context.clone(), value)?
+                    .render_expression(component_context.clone(), value)
+                    .map_err(|err| {
+                        SourcePositionError::attach_to_expression(err, position.as_ref())
+                    })?
                     .to_string(),
             );
         }
@@ -258,16 +386,13 @@ pub fn eval_prompt_document_mdast(
             name,
             ..
         }) => {
-            let evaluated_children = eval_prompt_document_children(
-                children,
-                params.regular_element(),
-                prompt_document_component_context,
-            )?;
+            let evaluated_children =
+                eval_markdown_children(children, params.regular_element(), component_context)?;
 
             result.push_str(&eval_mdx_element(
                 attributes,
                 children,
-                prompt_document_component_context,
+                component_context,
                 evaluated_children,
                 name,
                 rhai_template_renderer,
@@ -275,60 +400,94 @@ pub fn eval_prompt_document_mdast(
         }
         Node::Paragraph(Paragraph { children, .. }) => {
             result.push('\n');
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.paragraph(),
-                prompt_document_component_context,
+                component_context,
             )?);
             result.push('\n');
         }
         Node::Root(Root { children, .. }) => {
-            result.push_str(&eval_prompt_document_children(
-                children,
-                params.directly_in_root(),
-                prompt_document_component_context,
-            )?);
+            let root_params = params.directly_in_root();
+            let mut blocks: Vec<String> = Vec::new();
+            let mut is_first_child = true;
+
+            for child in children {
+                let block = eval_markdown_mdast(
+                    root_params.child(child, is_first_child),
+                    component_context,
+                )?;
+
+                if !block.trim().is_empty() {
+                    blocks.push(block.trim().to_string());
+                }
 
-            prompt_document_component_context.flush()?;
+                is_first_child = false;
+            }
+
+            result.push_str(&blocks.join("\n\n"));
+
+            component_context.finish()?;
         }
         Node::Strong(Strong { children, .. }) => {
-            let potential_role_name: &str = &eval_prompt_document_children(
-                children,
-                params.regular_element(),
-                prompt_document_component_context,
-            )?;
+            let strong_content: &str =
+                &eval_markdown_children(children, params.regular_element(), component_context)?;
 
-            if is_first_child && is_in_top_paragraph {
-                prompt_document_component_context
-                    .switch_role_to(potential_role_name.try_into()?)?;
-            } else {
+            if !(is_first_child
+                && is_in_top_paragraph
+                && component_context.take_leading_strong(strong_content)?)
+            {
                 result.push_str("**");
-                result.push_str(potential_role_name);
+                result.push_str(strong_content);
                 result.push_str("**");
             }
         }
-        Node::Table(Table { children, .. }) => {
-            result.push_str(&eval_prompt_document_children(
-                children,
-                params.regular_element(),
-                prompt_document_component_context,
-            )?);
+        Node::Table(Table {
+            align, children, ..
+        }) => {
+            let mut rows: Vec<String> = Vec::new();
+
+            for child in children {
+                rows.push(eval_markdown_mdast(
+                    params.clone().regular_element().child(child, false),
+                    component_context,
+                )?);
+
+                if rows.len() == 1 {
+                    rows.push(format!(
+                        "|{}|",
+                        align
+                            .iter()
+                            .map(|align_kind| match align_kind {
+                                AlignKind::Center => " :-: ",
+                                AlignKind::Left => " :-- ",
+                                AlignKind::None => " --- ",
+                                AlignKind::Right => " --: ",
+                            })
+                            .collect::<Vec<_>>()
+                            .join("|")
+                    ));
+                }
+            }
+
+            result.push_str(&rows.join("\n"));
         }
         Node::TableCell(TableCell { children, .. }) => {
             result.push_str("| ");
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
+            result.push(' ');
         }
         Node::TableRow(TableRow { children, .. }) => {
-            result.push_str(&eval_prompt_document_children(
+            result.push_str(&eval_markdown_children(
                 children,
                 params.regular_element(),
-                prompt_document_component_context,
+                component_context,
             )?);
-            result.push_str(" |");
+            result.push('|');
         }
         Node::Text(Text { value, .. }) => {
             result.push_str(value);
@@ -339,13 +498,13 @@ pub fn eval_prompt_document_mdast(
         Node::Toml(_) => {
             // ignore frontmatter during this pass
         }
-        Node::Yaml(node) => {
-            warn!("YAML front-matter is not supported, use TOML instead: {node:?}");
+        Node::Yaml(_) => {
+            // reported as a lint when prompts are built
         }
     }
 
     if is_directly_in_root {
-        prompt_document_component_context.append_to_message(trim_chunk(result.clone())?)?;
+        component_context.append_block(result.clone())?;
     }
 
     Ok(result)
@@ -367,12 +526,15 @@ mod test {
     use super::*;
     use crate::asset_manager::AssetManager;
     use crate::asset_path_renderer::AssetPathRenderer;
+    use crate::content_document_component_context::ContentDocumentComponentContext;
     use crate::content_document_front_matter::ContentDocumentFrontMatter;
     use crate::content_document_linker::ContentDocumentLinker;
     use crate::content_document_reference::ContentDocumentReference;
+    use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
     use crate::mcp::content_block::ContentBlock;
     use crate::mcp::jsonrpc::role::Role;
     use crate::mcp::prompt_message::PromptMessage;
+    use crate::prompt_document_component_context::PromptDocumentComponentContext;
     use crate::prompt_document_front_matter::PromptDocumentFrontMatter;
     use crate::string_to_mdast::string_to_mdast;
 
@@ -411,6 +573,7 @@ mod test {
         ContentDocumentLinker {
             content_document_basename_by_id: Arc::new(HashMap::new()),
             content_document_by_basename: Arc::new(content_document_by_basename),
+            heading_ids_by_basename: Arc::new(HashMap::new()),
         }
     }
 
@@ -436,6 +599,7 @@ mod test {
             content_document_linker: linker(),
             current_role: None,
             front_matter: front_matter(),
+            link_definitions: Default::default(),
             prompt_messages: Vec::new(),
             unprocessed_message_chunk: Arc::new(RwLock::new(String::new())),
         })
@@ -443,41 +607,44 @@ mod test {
 
     fn assemble_messages(markdown: &str) -> Result<Vec<PromptMessage>> {
         let rhai_template_renderer = renderer()?;
-        let mut prompt_document_component_context = context()?;
+        let mut component_context = context()?;
         let mdast = string_to_mdast(markdown)?;
 
-        eval_prompt_document_mdast(
-            EvalPromptDocumentMdastParams {
+        eval_markdown_mdast(
+            EvalMarkdownMdastParams {
                 mdast: &mdast,
                 is_directly_in_root: false,
                 is_first_child: true,
                 is_in_top_paragraph: false,
                 rhai_template_renderer: &rhai_template_renderer,
             },
-            &mut prompt_document_component_context,
+            &mut component_context,
         )?;
 
-        Ok(prompt_document_component_context.prompt_messages)
+        Ok(component_context.prompt_messages)
     }
 
     fn render_block(markdown: &str) -> Result<String> {
         let rhai_template_renderer = renderer()?;
-        let mut prompt_document_component_context = context()?;
+        let mut component_context = context()?;
         let mdast = string_to_mdast(markdown)?;
+
+        component_context.link_definitions = find_link_definitions_in_mdast(&mdast);
+
         let block = mdast
             .children()
             .and_then(|children| children.first())
             .ok_or_else(|| anyhow!("Document has no block node"))?;
 
-        eval_prompt_document_mdast(
-            EvalPromptDocumentMdastParams {
+        eval_markdown_mdast(
+            EvalMarkdownMdastParams {
                 mdast: block,
                 is_directly_in_root: false,
                 is_first_child: false,
                 is_in_top_paragraph: false,
                 rhai_template_renderer: &rhai_template_renderer,
             },
-            &mut prompt_document_component_context,
+            &mut component_context,
         )
     }
 
@@ -489,28 +656,6 @@ mod test {
         );
     }
 
-    #[test]
-    fn test_chunk_trim() -> Result<()> {
-        assert_eq!(
-            trim_chunk(
-                r#"
-                : foo bar
-            "#
-                .to_string()
-            )?,
-            "foo bar".to_string(),
-        );
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_chunk_trim_empty() -> Result<()> {
-        assert_eq!(trim_chunk("".to_string())?, "".to_string(),);
-
-        Ok(())
-    }
-
     #[test]
     fn assembles_prompt_messages_by_role() -> Result<()> {
         let messages =
@@ -535,7 +680,7 @@ mod test {
 
     #[test]
     fn renders_heading_marker() -> Result<()> {
-        assert_eq!(render_block("### Section Title")?, "###Section Title");
+        assert_eq!(render_block("### Section Title")?, "### Section Title");
 
         Ok(())
     }
@@ -562,6 +707,35 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn keeps_code_verbatim() -> Result<()> {
+        assert_eq!(
+            render_block("```html\n<p>a & b</p>\n```")?,
+            "```html\n<p>a & b</p>\n```"
+        );
+        assert!(render_block("Use `<br>` here")?.contains("`<br>`"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_footnote_references_and_definitions() -> Result<()> {
+        let rendered = eval_markdown_mdast(
+            EvalMarkdownMdastParams {
+                mdast: &string_to_mdast("Claim[^source]\n\n[^source]: Book")?,
+                is_directly_in_root: false,
+                is_first_child: false,
+                is_in_top_paragraph: false,
+                rhai_template_renderer: &renderer()?,
+            },
+            &mut ContentDocumentComponentContext::mock(),
+        )?;
+
+        assert_eq!(rendered, "Claim[^source]\n\n[^source]: Book");
+
+        Ok(())
+    }
+
     #[test]
     fn renders_list_items() -> Result<()> {
         let rendered = render_block("- first\n- second")?;
@@ -573,6 +747,20 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn preserves_ordered_list_start_and_task_list_state() -> Result<()> {
+        assert_eq!(
+            render_block("3. third\n4. fourth")?,
+            "\n3. third\n4. fourth\n"
+        );
+        assert_eq!(
+            render_block("- [x] done\n- [ ] todo")?,
+            "\n- [x] done\n- [ ] todo\n"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn renders_blockquote() -> Result<()> {
         assert!(render_block("> quoted text")?.contains("> quoted text"));
@@ -580,6 +768,16 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn keeps_alert_marker_in_blockquote() -> Result<()> {
+        assert_eq!(
+            render_block("> [!caution]  \n> Hot *surface*")?,
+            "> [!CAUTION]\n> Hot *surface*"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn resolves_internal_image_and_passes_external_through() -> Result<()> {
         assert!(render_block("![logo](logo.png)")?.contains("/static/logo_ABCDEF12.png"));
@@ -610,8 +808,7 @@ mod test {
     fn renders_table_cells() -> Result<()> {
         let rendered = render_block("| a | b |\n| - | - |\n| 1 | 2 |")?;
 
-        assert!(rendered.contains("| a"));
-        assert!(rendered.contains("| 1"));
+        assert_eq!(rendered, "| a | b |\n| --- | --- |\n| 1 | 2 |");
 
         Ok(())
     }
@@ -670,4 +867,22 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn resolves_reference_links_and_images_through_definitions() -> Result<()> {
+        assert_eq!(
+            render_block("[docs][guide] ![Logo][brand]\n\n[guide]: guide\n[brand]: logo.png")?,
+            "\n[docs](/guide/) ![Logo](/static/logo_ABCDEF12.png)\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn preserves_latex_source_of_math() -> Result<()> {
+        assert_eq!(render_block("$$\nx^2\n$$")?, "$$\nx^2\n$$");
+        assert_eq!(render_block("Area $\\pi r^2$")?, "\nArea $\\pi r^2$\n");
+
+        Ok(())
+    }
 }
@@ -2,7 +2,7 @@ use markdown::mdast::Node;
 use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
 
 #[derive(Clone)]
-pub struct EvalPromptDocumentMdastParams<'eval> {
+pub struct EvalMarkdownMdastParams<'eval> {
     pub mdast: &'eval Node,
     pub is_directly_in_root: bool,
     pub is_first_child: bool,
@@ -10,7 +10,7 @@ pub struct EvalPromptDocumentMdastParams<'eval> {
     pub rhai_template_renderer: &'eval RhaiTemplateRenderer,
 }
 
-impl<'eval> EvalPromptDocumentMdastParams<'eval> {
+impl<'eval> EvalMarkdownMdastParams<'eval> {
     pub fn child(&self, node: &'eval Node, is_first_child: bool) -> Self {
         Self {
             mdast: node,
@@ -0,0 +1,9 @@
+use crate::author::Author;
+use crate::content_document_reference::ContentDocumentReference;
+
+pub struct FeedEntry {
+    pub authors: Vec<Author>,
+    /// Rendered HTML of the document, without its layout
+    pub content: Option<String>,
+    pub reference: ContentDocumentReference,
+}
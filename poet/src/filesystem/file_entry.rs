@@ -9,6 +9,7 @@ use rhai::TypeBuilder;
 
 use crate::filesystem::file_entry_kind::FileEntryKind;
 use crate::filesystem::file_entry_stub::FileEntryStub;
+use crate::project_config::directories_config::DirectoriesConfig;
 
 #[derive(Clone, Debug)]
 pub struct FileEntry {
@@ -32,6 +33,22 @@ impl FileEntry {
             .to_string()
     }
 
+    pub fn from_stub(
+        file_entry_stub: FileEntryStub,
+        directories: &DirectoriesConfig,
+    ) -> Result<Self> {
+        let extension: String = file_entry_stub
+            .extension()
+            .ok_or_else(|| anyhow!("Unable to find file's extension"))?;
+
+        Ok(Self {
+            contents_hash: hash(file_entry_stub.contents.as_bytes()),
+            kind: directories.file_entry_kind(&file_entry_stub.relative_path, &extension),
+            contents: file_entry_stub.contents,
+            relative_path: file_entry_stub.relative_path,
+        })
+    }
+
     fn rhai_relative_path(&mut self) -> String {
         self.relative_path.to_string_lossy().to_string()
     }
@@ -49,25 +66,7 @@ impl TryFrom<FileEntryStub> for FileEntry {
     type Error = anyhow::Error;
 
     fn try_from(file_entry_stub: FileEntryStub) -> Result<Self> {
-        let top_directory: String = file_entry_stub
-            .top_directory()
-            .ok_or_else(|| anyhow!("Unable to find file's top directory"))?;
-        let extension: String = file_entry_stub
-            .extension()
-            .ok_or_else(|| anyhow!("Unable to find file's extension"))?;
-
-        Ok(Self {
-            contents_hash: hash(file_entry_stub.contents.as_bytes()),
-            contents: file_entry_stub.contents,
-            kind: match (top_directory.as_str(), extension.as_str()) {
-                ("authors", "toml") => FileEntryKind::Author,
-                ("content", "md") => FileEntryKind::Content,
-                ("prompts", "md") => FileEntryKind::Prompt,
-                ("shortcodes", "rhai") => FileEntryKind::Shortcode,
-                _ => FileEntryKind::Other,
-            },
-            relative_path: file_entry_stub.relative_path,
-        })
+        Self::from_stub(file_entry_stub, &DirectoriesConfig::default())
     }
 }
 
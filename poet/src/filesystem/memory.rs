@@ -22,6 +22,22 @@ impl Default for Memory {
     }
 }
 
+impl Memory {
+    pub fn read_file_contents_sync(&self, relative_path: &Path) -> Result<ReadFileContentsResult> {
+        let path_str = relative_path
+            .to_str()
+            .ok_or_else(|| anyhow!("Unable to stringify path"))?;
+
+        if let Some(contents) = self.files.get(path_str) {
+            Ok(ReadFileContentsResult::Found {
+                contents: contents.value().to_owned(),
+            })
+        } else {
+            Ok(ReadFileContentsResult::NotFound)
+        }
+    }
+}
+
 #[async_trait]
 impl Filesystem for Memory {
     async fn read_project_files(&self) -> Result<Vec<FileEntry>> {
@@ -38,17 +54,7 @@ impl Filesystem for Memory {
     }
 
     async fn read_file_contents(&self, relative_path: &Path) -> Result<ReadFileContentsResult> {
-        let path_str = relative_path
-            .to_str()
-            .ok_or_else(|| anyhow!("Unable to stringify path"))?;
-
-        if let Some(contents) = self.files.get(path_str) {
-            Ok(ReadFileContentsResult::Found {
-                contents: contents.value().to_owned(),
-            })
-        } else {
-            Ok(ReadFileContentsResult::NotFound)
-        }
+        self.read_file_contents_sync(relative_path)
     }
 
     async fn set_file_contents(&self, path: &Path, contents: &str) -> Result<()> {
@@ -196,6 +196,7 @@ mod tests {
                 "Storage",
                 storage::Storage {
                     base_directory: base_directory.path().to_path_buf(),
+                    directories: Default::default(),
                 },
             )
             .await?;
@@ -276,6 +277,7 @@ mod tests {
         let base_directory = tempdir()?;
         let filesystem = storage::Storage {
             base_directory: base_directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         filesystem
@@ -14,20 +14,22 @@ use super::file_entry::FileEntry;
 use super::read_file_contents_result::ReadFileContentsResult;
 use crate::filesystem::file_entry_stub::FileEntryStub;
 use crate::filesystem::storage::create_parent_directories::create_parent_directories;
+use crate::project_config::directories_config::DirectoriesConfig;
 
 pub struct Storage {
     pub base_directory: PathBuf,
+    pub directories: DirectoriesConfig,
 }
 
 #[async_trait]
 impl Filesystem for Storage {
     async fn read_project_files(&self) -> Result<Vec<FileEntry>> {
-        let mut to_visit: Vec<PathBuf> = vec![
-            self.base_directory.join("authors"),
-            self.base_directory.join("content"),
-            self.base_directory.join("prompts"),
-            self.base_directory.join("shortcodes"),
-        ];
+        let mut to_visit: Vec<PathBuf> = self
+            .directories
+            .all()
+            .iter()
+            .map(|directory| self.base_directory.join(directory))
+            .collect();
         let mut files = Vec::new();
 
         while let Some(current) = to_visit.pop() {
@@ -49,15 +51,15 @@ impl Filesystem for Storage {
                     if let Some(extension) = path.extension() {
                         match extension.to_str() {
                             Some("md") | Some("rhai") | Some("toml") => {
-                                files.push(
+                                files.push(FileEntry::from_stub(
                                     FileEntryStub {
                                         contents: fs::read_to_string(&path).await.context(
                                             format!("Failed to read file: {}", path.display()),
                                         )?,
                                         relative_path,
-                                    }
-                                    .try_into()?,
-                                );
+                                    },
+                                    &self.directories,
+                                )?);
                             }
                             Some(_) => debug!("Skipping path: {}", path.display()),
                             None => {}
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -17,39 +20,68 @@ impl FilesystemHttpRouteIndex {
         self.routes.get(path).map(|entry| entry.value().clone())
     }
 
+    /// Only pages generated from documents have Markdown alternates.
+    pub fn get_markdown_alternate_for_path(&self, path: &str) -> Option<FileEntry> {
+        let FileEntry { relative_path, .. } = self.get_file_entry_for_path(path)?;
+
+        if relative_path.file_name()? != "index.html" {
+            return None;
+        }
+
+        self.get_file_entry_for_path(&relative_path.with_extension("md").display().to_string())
+    }
+
+    /// Aliases never shadow the routes of generated files.
     pub async fn from_filesystem<TFilesystem: Filesystem>(
         filesystem: Arc<TFilesystem>,
+        route_aliases: &BTreeMap<String, PathBuf>,
     ) -> Result<Self> {
         let this: Self = Default::default();
 
         for file in filesystem.read_project_files().await? {
-            this.register_file(file)?;
+            this.register_file(file);
+        }
+
+        for (alias, relative_path) in route_aliases {
+            this.register_alias(alias, relative_path)?;
         }
 
         Ok(this)
     }
 
-    fn register_file(&self, file: FileEntry) -> Result<()> {
-        let filename = file.relative_path.to_string_lossy().to_string();
+    fn register_alias(&self, alias: &str, relative_path: &Path) -> Result<()> {
+        let file = self
+            .get_file_entry_for_path(&relative_path.display().to_string())
+            .ok_or_else(|| {
+                anyhow!(
+                    "Alias '{alias}' points to a file that was not generated: '{}'",
+                    relative_path.display()
+                )
+            })?;
+
+        if alias.is_empty() || alias.ends_with('/') {
+            self.routes
+                .entry(format!("{alias}index.html"))
+                .or_insert_with(|| file.clone());
+        }
 
-        if filename.ends_with("/index.html") {
-            let filename_stripped: String = filename
-                .strip_suffix("index.html")
-                .ok_or_else(|| anyhow!("Unable to strip '/index.html' suffix from: '{filename}'"))?
-                .to_string();
+        self.routes.entry(alias.to_string()).or_insert(file);
 
-            self.routes.insert(filename, file.clone());
-            self.routes.insert(filename_stripped, file.clone());
-        } else if filename == "index.html" {
+        Ok(())
+    }
+
+    /// Every file is served under its own path. Index pages are also served
+    /// under the directory they are in.
+    fn register_file(&self, file: FileEntry) {
+        let filename = file.relative_path.to_string_lossy().to_string();
+
+        if filename == "index.html" {
             self.routes.insert("".to_string(), file.clone());
-            self.routes.insert("index.html".to_string(), file.clone());
-        } else if filename == "sitemap.xml" {
-            self.routes.insert(filename, file.clone());
-        } else {
-            return Err(anyhow!("Unexpected filename: '{filename}'"));
+        } else if let Some(directory) = filename.strip_suffix("/index.html") {
+            self.routes.insert(format!("{directory}/"), file.clone());
         }
 
-        Ok(())
+        self.routes.insert(filename, file);
     }
 }
 
@@ -72,7 +104,7 @@ mod tests {
     fn registers_root_index_under_empty_and_named_routes() -> Result<()> {
         let index = FilesystemHttpRouteIndex::default();
 
-        index.register_file(file_entry("index.html")?)?;
+        index.register_file(file_entry("index.html")?);
 
         assert_eq!(
             index
@@ -89,7 +121,7 @@ mod tests {
     fn registers_nested_index_under_directory_and_full_path() -> Result<()> {
         let index = FilesystemHttpRouteIndex::default();
 
-        index.register_file(file_entry("docs/index.html")?)?;
+        index.register_file(file_entry("docs/index.html")?);
 
         assert!(index.get_file_entry_for_path("docs/index.html").is_some());
         assert!(index.get_file_entry_for_path("docs/").is_some());
@@ -101,7 +133,7 @@ mod tests {
     fn registers_sitemap_under_its_own_path() -> Result<()> {
         let index = FilesystemHttpRouteIndex::default();
 
-        index.register_file(file_entry("sitemap.xml")?)?;
+        index.register_file(file_entry("sitemap.xml")?);
 
         assert!(index.get_file_entry_for_path("sitemap.xml").is_some());
 
@@ -109,14 +141,111 @@ mod tests {
     }
 
     #[test]
-    fn rejects_unexpected_filename() -> Result<()> {
+    fn registers_site_and_collection_feeds_under_their_own_paths() -> Result<()> {
+        let index = FilesystemHttpRouteIndex::default();
+
+        index.register_file(file_entry("feed.xml")?);
+        index.register_file(file_entry("blog/feed.xml")?);
+
+        assert!(index.get_file_entry_for_path("feed.xml").is_some());
+        assert!(index.get_file_entry_for_path("blog/feed.xml").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn registers_any_other_file_under_its_own_path() -> Result<()> {
+        let index = FilesystemHttpRouteIndex::default();
+
+        index.register_file(file_entry("robots.txt")?);
+        index.register_file(file_entry("guide/index.md")?);
+
+        assert!(index.get_file_entry_for_path("robots.txt").is_some());
+        assert!(index.get_file_entry_for_path("guide/index.md").is_some());
+        assert!(index.get_file_entry_for_path("guide/").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serves_generated_page_under_its_aliases() -> Result<()> {
+        let index = FilesystemHttpRouteIndex::default();
+
+        index.register_file(file_entry("guide/index.html")?);
+        index.register_alias("old/guide/", Path::new("guide/index.html"))?;
+
+        assert_eq!(
+            index
+                .get_file_entry_for_path("old/guide/")
+                .map(|entry| entry.relative_path),
+            Some(PathBuf::from("guide/index.html"))
+        );
+        assert!(
+            index
+                .get_file_entry_for_path("old/guide/index.html")
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_let_aliases_shadow_generated_files() -> Result<()> {
+        let index = FilesystemHttpRouteIndex::default();
+
+        index.register_file(file_entry("guide/index.html")?);
+        index.register_file(file_entry("about/index.html")?);
+        index.register_alias("about/", Path::new("guide/index.html"))?;
+
+        assert_eq!(
+            index
+                .get_file_entry_for_path("about/")
+                .map(|entry| entry.relative_path),
+            Some(PathBuf::from("about/index.html"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn finds_markdown_alternate_of_page_and_its_aliases() -> Result<()> {
         let index = FilesystemHttpRouteIndex::default();
 
-        assert!(index.register_file(file_entry("page.html")?).is_err());
+        index.register_file(file_entry("guide/index.html")?);
+        index.register_file(file_entry("guide/index.md")?);
+        index.register_file(file_entry("about/index.html")?);
+        index.register_alias("old/guide/", Path::new("guide/index.html"))?;
+
+        for path in ["guide/", "guide/index.html", "old/guide/"] {
+            assert_eq!(
+                index
+                    .get_markdown_alternate_for_path(path)
+                    .map(|entry| entry.relative_path),
+                Some(PathBuf::from("guide/index.md"))
+            );
+        }
+
+        assert!(index.get_markdown_alternate_for_path("about/").is_none());
+        assert!(
+            index
+                .get_markdown_alternate_for_path("guide/index.md")
+                .is_none()
+        );
 
         Ok(())
     }
 
+    #[test]
+    fn rejects_alias_of_file_that_was_not_generated() {
+        let index = FilesystemHttpRouteIndex::default();
+
+        assert!(
+            index
+                .register_alias("old/", Path::new("missing/index.html"))
+                .is_err()
+        );
+    }
+
     #[test]
     fn returns_none_for_unregistered_path() {
         let index = FilesystemHttpRouteIndex::default();
@@ -0,0 +1,117 @@
+use markdown::mdast::Node;
+use markdown::mdast::Paragraph;
+use markdown::mdast::Text;
+
+use crate::admonition::Admonition;
+use crate::admonition::admonition_kind::AdmonitionKind;
+
+/// Recognizes `> [!NOTE]` and similar alerts. The marker has to be the only
+/// thing on the first line of the blockquote.
+pub fn find_admonition_in_blockquote(children: &[Node]) -> Option<Admonition> {
+    let (Node::Paragraph(first_paragraph), other_children) = children.split_first()? else {
+        return None;
+    };
+    let (Node::Text(Text { position, value }), paragraph_rest) =
+        first_paragraph.children.split_first()?
+    else {
+        return None;
+    };
+    let (marker, remainder) = value.strip_prefix("[!")?.split_once(']')?;
+    let kind = AdmonitionKind::from_marker(marker)?;
+    let remainder = remainder.trim_start_matches([' ', '\t']);
+
+    if !remainder.is_empty() && !remainder.starts_with('\n') {
+        return None;
+    }
+
+    let remainder = remainder.trim_start_matches('\n');
+    let mut paragraph_children: Vec<Node> = Vec::new();
+
+    if remainder.is_empty() {
+        // trailing spaces after the marker produce a hard break
+        paragraph_children.extend(
+            paragraph_rest
+                .iter()
+                .skip_while(|node| matches!(node, Node::Break(_)))
+                .cloned(),
+        );
+    } else {
+        paragraph_children.push(Node::Text(Text {
+            position: position.clone(),
+            value: remainder.to_string(),
+        }));
+        paragraph_children.extend(paragraph_rest.iter().cloned());
+    }
+
+    let mut admonition_children: Vec<Node> = Vec::new();
+
+    if !paragraph_children.is_empty() {
+        admonition_children.push(Node::Paragraph(Paragraph {
+            children: paragraph_children,
+            position: first_paragraph.position.clone(),
+        }));
+    }
+
+    admonition_children.extend(other_children.iter().cloned());
+
+    Some(Admonition {
+        children: admonition_children,
+        kind,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use markdown::mdast::Blockquote;
+
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    fn find_in(markdown: &str) -> Result<Option<Admonition>> {
+        let mdast = string_to_mdast(markdown)?;
+
+        Ok(mdast.children().into_iter().flatten().find_map(|node| {
+            if let Node::Blockquote(Blockquote { children, .. }) = node {
+                find_admonition_in_blockquote(children)
+            } else {
+                None
+            }
+        }))
+    }
+
+    #[test]
+    fn strips_the_marker_from_the_first_paragraph() -> Result<()> {
+        let admonition = find_in("> [!warning]\n> Mind *the* gap")?
+            .ok_or_else(|| anyhow::anyhow!("Expected an admonition"))?;
+
+        assert_eq!(admonition.kind, AdmonitionKind::Warning);
+        assert_eq!(admonition.children.len(), 1);
+        assert!(matches!(
+            admonition.children[0].children().and_then(|children| children.first()),
+            Some(Node::Text(Text { value, .. })) if value == "Mind "
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn drops_the_paragraph_that_only_held_the_marker() -> Result<()> {
+        let admonition = find_in("> [!TIP]\n>\n> Second paragraph")?
+            .ok_or_else(|| anyhow::anyhow!("Expected an admonition"))?;
+
+        assert_eq!(admonition.kind, AdmonitionKind::Tip);
+        assert_eq!(admonition.children.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_regular_blockquotes_and_unknown_markers() -> Result<()> {
+        assert!(find_in("> just a quote")?.is_none());
+        assert!(find_in("> [!TIP] with a title")?.is_none());
+        assert!(find_in("> [!HINT]\n> unknown kind")?.is_none());
+
+        Ok(())
+    }
+}
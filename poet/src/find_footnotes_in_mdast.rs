@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use markdown::mdast::FootnoteDefinition;
+use markdown::mdast::FootnoteReference;
+use markdown::mdast::Node;
+use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
+use syntect::parsing::SyntaxSet;
+
+use crate::content_document_component_context::ContentDocumentComponentContext;
+use crate::eval_content_document_mdast::eval_content_document_children;
+use crate::footnotes::Footnotes;
+use crate::footnotes::footnote::Footnote;
+
+fn find_footnote_definitions_in_mdast<'mdast>(
+    mdast: &'mdast Node,
+    definitions: &mut HashMap<String, &'mdast Vec<Node>>,
+) {
+    if let Node::FootnoteDefinition(FootnoteDefinition {
+        children,
+        identifier,
+        ..
+    }) = mdast
+    {
+        definitions.entry(identifier.clone()).or_insert(children);
+    }
+
+    if let Some(children) = mdast.children() {
+        for child in children {
+            find_footnote_definitions_in_mdast(child, definitions);
+        }
+    }
+}
+
+fn find_footnote_references_in_mdast(mdast: &Node, references: &mut Vec<(String, Option<usize>)>) {
+    match mdast {
+        Node::FootnoteDefinition(_) => {
+            // references inside of definitions are numbered after the
+            // definition itself is referenced
+        }
+        Node::FootnoteReference(FootnoteReference {
+            identifier,
+            position,
+            ..
+        }) => {
+            references.push((
+                identifier.clone(),
+                position.as_ref().map(|position| position.start.offset),
+            ));
+        }
+        _ => {
+            if let Some(children) = mdast.children() {
+                for child in children {
+                    find_footnote_references_in_mdast(child, references);
+                }
+            }
+        }
+    }
+}
+
+/// Numbers footnotes in the order of their first reference, then renders
+/// their definitions. Definitions that are never referenced are left out.
+pub fn find_footnotes_in_mdast(
+    mdast: &Node,
+    component_context: &ContentDocumentComponentContext,
+    rhai_template_renderer: &RhaiTemplateRenderer,
+    syntax_set: &SyntaxSet,
+) -> Result<Footnotes> {
+    let mut definitions: HashMap<String, &Vec<Node>> = HashMap::new();
+    let mut notes: Vec<Footnote> = Vec::new();
+    let mut references: Vec<(String, Option<usize>)> = Vec::new();
+
+    find_footnote_definitions_in_mdast(mdast, &mut definitions);
+    find_footnote_references_in_mdast(mdast, &mut references);
+
+    let mut processed_definitions = 0;
+
+    loop {
+        for (identifier, offset) in references.drain(..) {
+            if let Some(footnote) = notes
+                .iter_mut()
+                .find(|footnote| footnote.identifier == identifier)
+            {
+                footnote.reference_offsets.push(offset);
+            } else if definitions.contains_key(&identifier) {
+                notes.push(Footnote {
+                    content: String::new(),
+                    identifier,
+                    number: notes.len() as i64 + 1,
+                    reference_offsets: vec![offset],
+                });
+            }
+        }
+
+        if processed_definitions == notes.len() {
+            break;
+        }
+
+        for footnote in &notes[processed_definitions..] {
+            if let Some(children) = definitions.get(&footnote.identifier) {
+                for child in children.iter() {
+                    find_footnote_references_in_mdast(child, &mut references);
+                }
+            }
+        }
+
+        processed_definitions = notes.len();
+    }
+
+    // every footnote has its number before any of them is rendered, so
+    // references inside of definitions can use them
+    let numbered_component_context = component_context.clone().with_footnotes(Footnotes {
+        notes: notes.clone(),
+    });
+
+    for footnote in &mut notes {
+        if let Some(children) = definitions.get(&footnote.identifier) {
+            footnote.content = eval_content_document_children(
+                children,
+                &numbered_component_context,
+                rhai_template_renderer,
+                syntax_set,
+            )?;
+        }
+    }
+
+    Ok(Footnotes { notes })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rhai::Engine;
+    use rhai_components::component_syntax::component_registry::ComponentRegistry;
+    use rhai_components::rhai_template_renderer_params::RhaiTemplateRendererParams;
+
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    fn find(markdown: &str) -> Result<Footnotes> {
+        let rhai_template_renderer = RhaiTemplateRenderer::build(RhaiTemplateRendererParams {
+            component_registry: Arc::new(ComponentRegistry::default()),
+            expression_engine: Engine::new_raw(),
+        })?;
+
+        find_footnotes_in_mdast(
+            &string_to_mdast(markdown)?,
+            &ContentDocumentComponentContext::mock(),
+            &rhai_template_renderer,
+            &SyntaxSet::new(),
+        )
+    }
+
+    #[test]
+    fn numbers_footnotes_in_order_of_first_use() -> Result<()> {
+        let footnotes =
+            find("a[^second] b[^first] c[^second]\n\n[^first]: First\n\n[^second]: Second")?;
+
+        assert_eq!(footnotes.notes.len(), 2);
+        assert_eq!(footnotes.notes[0].identifier, "second");
+        assert_eq!(footnotes.notes[0].number, 1);
+        assert_eq!(footnotes.notes[0].reference_offsets.len(), 2);
+        assert_eq!(footnotes.notes[0].content, "<p>Second</p>");
+        assert_eq!(footnotes.notes[1].identifier, "first");
+        assert_eq!(footnotes.notes[1].number, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn numbers_footnotes_referenced_only_from_other_footnotes_last() -> Result<()> {
+        let footnotes = find(
+            "a[^outer] b[^other]\n\n[^outer]: See[^inner]\n\n[^inner]: Inner\n\n[^other]: Other",
+        )?;
+
+        let identifiers: Vec<&str> = footnotes
+            .notes
+            .iter()
+            .map(|footnote| footnote.identifier.as_str())
+            .collect();
+
+        assert_eq!(identifiers, vec!["outer", "other", "inner"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_numbered_references_inside_of_footnotes() -> Result<()> {
+        let footnotes = find("a[^outer]\n\n[^outer]: See[^inner]\n\n[^inner]: Inner")?;
+
+        assert_eq!(
+            footnotes.notes[0].content,
+            "<p>See<sup><a href=\"#footnote-inner\" id=\"footnote-reference-inner\" role=\"doc-noteref\">2</a></sup></p>"
+        );
+        assert_eq!(footnotes.notes[1].identifier, "inner");
+        assert_eq!(footnotes.notes[1].reference_offsets.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn skips_unreferenced_definitions() -> Result<()> {
+        assert!(find("no references\n\n[^unused]: Unused")?.is_empty());
+
+        Ok(())
+    }
+}
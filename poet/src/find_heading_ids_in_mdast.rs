@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use markdown::mdast::Heading;
+use markdown::mdast::Node;
+use markdown::unist::Position;
+
+use crate::heading_ids::HeadingIds;
+use crate::mdast_children_to_heading_id::mdast_children_to_heading_id;
+use crate::source_position_error::SourcePositionError;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
+
+struct HeadingIdCandidate {
+    explicit_id: Option<String>,
+    generated_id: String,
+    position: Position,
+}
+
+fn find_candidates_in_mdast(mdast: &Node, candidates: &mut Vec<HeadingIdCandidate>) -> Result<()> {
+    if let Node::Heading(Heading {
+        children,
+        position: Some(position),
+        ..
+    }) = mdast
+    {
+        let (children, explicit_id) = strip_explicit_heading_id(children);
+
+        candidates.push(HeadingIdCandidate {
+            explicit_id,
+            generated_id: mdast_children_to_heading_id(&children)?,
+            position: position.clone(),
+        });
+    }
+
+    if let Some(children) = mdast.children() {
+        for child in children {
+            find_candidates_in_mdast(child, candidates)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Explicit `{#id}` ids are reserved first, then generated ids get a numeric
+/// suffix (`examples`, `examples-1`) if they collide with an earlier one.
+pub fn find_heading_ids_in_mdast(mdast: &Node) -> Result<HeadingIds> {
+    let mut candidates: Vec<HeadingIdCandidate> = Vec::new();
+
+    find_candidates_in_mdast(mdast, &mut candidates)?;
+
+    let mut ids_by_offset: BTreeMap<usize, String> = BTreeMap::new();
+    let mut used_ids: HashSet<String> = HashSet::new();
+
+    for candidate in &candidates {
+        if let Some(explicit_id) = &candidate.explicit_id {
+            if !used_ids.insert(explicit_id.clone()) {
+                return Err(SourcePositionError::attach(
+                    anyhow!("Duplicate heading id: #{explicit_id}"),
+                    Some(&candidate.position),
+                ));
+            }
+
+            ids_by_offset.insert(candidate.position.start.offset, explicit_id.clone());
+        }
+    }
+
+    for candidate in &candidates {
+        if candidate.explicit_id.is_some() {
+            continue;
+        }
+
+        let mut id = candidate.generated_id.clone();
+        let mut suffix = 0;
+
+        while used_ids.contains(&id) {
+            suffix += 1;
+            id = format!("{}-{suffix}", candidate.generated_id);
+        }
+
+        used_ids.insert(id.clone());
+        ids_by_offset.insert(candidate.position.start.offset, id);
+    }
+
+    Ok(HeadingIds {
+        ids_by_offset: Arc::new(ids_by_offset),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    fn ids(markdown: &str) -> Result<Vec<String>> {
+        Ok(find_heading_ids_in_mdast(&string_to_mdast(markdown)?)?
+            .ids_by_offset
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    #[test]
+    fn suffixes_repeated_headings() -> Result<()> {
+        assert_eq!(
+            ids("## Examples\n\n## Examples\n\n## Examples")?,
+            vec!["examples", "examples-1", "examples-2"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserves_explicit_ids_before_generating_others() -> Result<()> {
+        assert_eq!(
+            ids("## Setup\n\n## Install {#setup}")?,
+            vec!["setup-1", "setup"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_duplicate_explicit_ids() -> Result<()> {
+        assert!(ids("## One {#same}\n\n## Two {#same}").is_err());
+
+        Ok(())
+    }
+}
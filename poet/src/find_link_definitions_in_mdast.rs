@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use markdown::mdast::Definition;
+use markdown::mdast::Node;
+
+use crate::link_definitions::LinkDefinitions;
+use crate::link_definitions::link_definition::LinkDefinition;
+
+fn find_definitions_in_mdast(mdast: &Node, definitions: &mut HashMap<String, LinkDefinition>) {
+    if let Node::Definition(Definition {
+        identifier,
+        title,
+        url,
+        ..
+    }) = mdast
+    {
+        // The first definition of an identifier takes precedence
+        definitions
+            .entry(identifier.clone())
+            .or_insert_with(|| LinkDefinition {
+                title: title.clone(),
+                url: url.clone(),
+            });
+    }
+
+    if let Some(children) = mdast.children() {
+        for child in children {
+            find_definitions_in_mdast(child, definitions);
+        }
+    }
+}
+
+pub fn find_link_definitions_in_mdast(mdast: &Node) -> LinkDefinitions {
+    let mut definitions = HashMap::new();
+
+    find_definitions_in_mdast(mdast, &mut definitions);
+
+    LinkDefinitions {
+        definitions: Arc::new(definitions),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    #[test]
+    fn collects_definitions_with_first_one_taking_precedence() -> Result<()> {
+        let source = "[Guide][docs]\n\n[docs]: guide \"The guide\"\n[DOCS]: ignored\n";
+        let mdast = string_to_mdast(source)?;
+        let link_definitions = find_link_definitions_in_mdast(&mdast);
+
+        assert_eq!(
+            link_definitions.get("docs"),
+            Some(&LinkDefinition {
+                title: Some("The guide".to_string()),
+                url: "guide".to_string(),
+            })
+        );
+        assert!(
+            link_definitions
+                .undefined_references_in_mdast(&mdast, source)
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
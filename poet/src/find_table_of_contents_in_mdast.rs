@@ -4,6 +4,7 @@ use markdown::mdast::Delete;
 use markdown::mdast::Emphasis;
 use markdown::mdast::Heading as MdastHeading;
 use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
 use markdown::mdast::List;
 use markdown::mdast::ListItem;
 use markdown::mdast::MdxJsxFlowElement;
@@ -20,7 +21,7 @@ use syntect::parsing::SyntaxSet;
 
 use crate::content_document_component_context::ContentDocumentComponentContext;
 use crate::eval_content_document_mdast::eval_content_document_children;
-use crate::mdast_children_to_heading_id::mdast_children_to_heading_id;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
 use crate::table_of_contents::TableOfContents;
 use crate::table_of_contents::heading::Heading;
 
@@ -36,6 +37,7 @@ fn find_headings_in_mdast(
         | Node::Delete(Delete { children, .. })
         | Node::Emphasis(Emphasis { children, .. })
         | Node::Link(Link { children, .. })
+        | Node::LinkReference(LinkReference { children, .. })
         | Node::List(List { children, .. })
         | Node::ListItem(ListItem { children, .. })
         | Node::MdxJsxFlowElement(MdxJsxFlowElement { children, .. })
@@ -59,17 +61,21 @@ fn find_headings_in_mdast(
             Ok(())
         }
         Node::Heading(MdastHeading {
-            children, depth, ..
+            children,
+            depth,
+            position,
         }) => {
+            let (children, _) = strip_explicit_heading_id(children);
+
             headings.push(Heading {
                 content: eval_content_document_children(
-                    children,
+                    &children,
                     component_context,
                     rhai_template_renderer,
                     syntax_set,
                 )?,
                 depth: *depth as i64,
-                id: mdast_children_to_heading_id(children)?,
+                id: component_context.heading_ids.id_for(&children, position)?,
             });
 
             Ok(())
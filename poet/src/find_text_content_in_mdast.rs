@@ -4,6 +4,7 @@ use markdown::mdast::Delete;
 use markdown::mdast::Emphasis;
 use markdown::mdast::Heading;
 use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
 use markdown::mdast::List;
 use markdown::mdast::ListItem;
 use markdown::mdast::MdxJsxFlowElement;
@@ -26,6 +27,7 @@ pub fn find_text_content_in_mdast(mdast: &Node) -> Result<String> {
         | Node::Emphasis(Emphasis { children, .. })
         | Node::Heading(Heading { children, .. })
         | Node::Link(Link { children, .. })
+        | Node::LinkReference(LinkReference { children, .. })
         | Node::List(List { children, .. })
         | Node::ListItem(ListItem { children, .. })
         | Node::MdxJsxFlowElement(MdxJsxFlowElement { children, .. })
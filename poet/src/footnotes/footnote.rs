@@ -0,0 +1,73 @@
+use markdown::unist::Position;
+use rhai::Array;
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::TypeBuilder;
+
+#[derive(Clone)]
+pub struct Footnote {
+    pub content: String,
+    pub identifier: String,
+    pub number: i64,
+    /// Source offsets of every reference to this footnote, in document order.
+    pub reference_offsets: Vec<Option<usize>>,
+}
+
+impl Footnote {
+    pub fn id(&self) -> String {
+        format!("footnote-{}", self.identifier)
+    }
+
+    pub fn reference_id(&self, index: usize) -> String {
+        if index == 0 {
+            format!("footnote-reference-{}", self.identifier)
+        } else {
+            format!("footnote-reference-{}-{}", self.identifier, index + 1)
+        }
+    }
+
+    pub fn reference_ids(&self) -> Vec<String> {
+        (0..self.reference_offsets.len())
+            .map(|index| self.reference_id(index))
+            .collect()
+    }
+
+    pub fn reference_index(&self, position: &Option<Position>) -> usize {
+        let offset = position.as_ref().map(|position| position.start.offset);
+
+        self.reference_offsets
+            .iter()
+            .position(|reference_offset| *reference_offset == offset)
+            .unwrap_or(0)
+    }
+
+    fn rhai_content(&mut self) -> String {
+        self.content.clone()
+    }
+
+    fn rhai_id(&mut self) -> String {
+        self.id()
+    }
+
+    fn rhai_number(&mut self) -> i64 {
+        self.number
+    }
+
+    fn rhai_reference_ids(&mut self) -> Array {
+        self.reference_ids()
+            .into_iter()
+            .map(Dynamic::from)
+            .collect()
+    }
+}
+
+impl CustomType for Footnote {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Footnote")
+            .with_get("content", Self::rhai_content)
+            .with_get("id", Self::rhai_id)
+            .with_get("number", Self::rhai_number)
+            .with_get("reference_ids", Self::rhai_reference_ids);
+    }
+}
@@ -0,0 +1,101 @@
+pub mod footnote;
+
+use rhai::Array;
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::TypeBuilder;
+use rhai_components::escape_html_attribute::escape_html_attribute;
+
+use crate::footnotes::footnote::Footnote;
+
+#[derive(Clone, Default)]
+pub struct Footnotes {
+    /// Footnotes ordered by their first reference in the document.
+    pub notes: Vec<Footnote>,
+}
+
+impl Footnotes {
+    pub fn get(&self, identifier: &str) -> Option<&Footnote> {
+        self.notes
+            .iter()
+            .find(|footnote| footnote.identifier == identifier)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    pub fn render_endnotes(&self) -> String {
+        if self.notes.is_empty() {
+            return String::new();
+        }
+
+        let mut result = String::from(r#"<section class="footnotes" role="doc-endnotes"><ol>"#);
+
+        for footnote in &self.notes {
+            let mut backreferences = String::new();
+
+            for (index, reference_id) in footnote.reference_ids().iter().enumerate() {
+                backreferences.push_str(&format!(
+                    r##" <a href="#{}" role="doc-backlink">↩{}</a>"##,
+                    escape_html_attribute(reference_id),
+                    if index == 0 {
+                        String::new()
+                    } else {
+                        format!("<sup>{}</sup>", index + 1)
+                    }
+                ));
+            }
+
+            result.push_str(&format!(
+                r#"<li id="{}">"#,
+                escape_html_attribute(&footnote.id())
+            ));
+
+            // Keep the back references inside the last paragraph, so they do
+            // not end up on a line of their own.
+            match footnote.content.strip_suffix("</p>") {
+                Some(content) => {
+                    result.push_str(content);
+                    result.push_str(&backreferences);
+                    result.push_str("</p>");
+                }
+                None => {
+                    result.push_str(&footnote.content);
+                    result.push_str(&backreferences);
+                }
+            }
+
+            result.push_str("</li>");
+        }
+
+        result.push_str("</ol></section>");
+
+        result
+    }
+
+    fn rhai_endnotes(&mut self) -> String {
+        self.render_endnotes()
+    }
+
+    fn rhai_is_empty(&mut self) -> bool {
+        self.is_empty()
+    }
+
+    fn rhai_notes(&mut self) -> Array {
+        self.notes
+            .iter()
+            .map(|footnote| Dynamic::from(footnote.clone()))
+            .collect::<_>()
+    }
+}
+
+impl CustomType for Footnotes {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Footnotes")
+            .with_get("endnotes", Self::rhai_endnotes)
+            .with_get("is_empty", Self::rhai_is_empty)
+            .with_get("notes", Self::rhai_notes);
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use anyhow::anyhow;
+use atom_syndication::Content;
+use atom_syndication::Entry;
+use atom_syndication::Feed;
+use atom_syndication::Link;
+use atom_syndication::Person;
+use atom_syndication::Text;
+use chrono::Utc;
+
+use crate::feed_entry::FeedEntry;
+
+/// Entries are updated when their document was last updated, or published
+/// otherwise. Undated documents count as updated by this build.
+pub fn create_feed(
+    title: String,
+    feed_link: String,
+    site_link: String,
+    feed_entries: Vec<FeedEntry>,
+) -> Result<String> {
+    let now = Utc::now().fixed_offset();
+    let mut entries: Vec<Entry> = Vec::new();
+
+    for FeedEntry {
+        authors,
+        content,
+        reference,
+    } in feed_entries
+    {
+        let link = reference.canonical_link().map_err(|err| anyhow!(err))?;
+        let front_matter = reference.front_matter;
+
+        entries.push(Entry {
+            authors: authors
+                .into_iter()
+                .map(|author| Person {
+                    name: author.data.name,
+                    ..Default::default()
+                })
+                .collect(),
+            content: content.map(|content| Content {
+                content_type: Some("html".to_string()),
+                value: Some(content),
+                ..Default::default()
+            }),
+            id: link.clone(),
+            links: vec![Link {
+                href: link,
+                ..Default::default()
+            }],
+            published: front_matter
+                .publish_at
+                .map(|publish_at| publish_at.fixed_offset()),
+            summary: Some(Text::plain(front_matter.description)),
+            title: Text::plain(front_matter.title),
+            updated: front_matter
+                .last_updated_at
+                .or(front_matter.publish_at)
+                .map(|updated| updated.fixed_offset())
+                .unwrap_or(now),
+            ..Default::default()
+        });
+    }
+
+    entries.sort_by(|a, b| b.updated.cmp(&a.updated).then_with(|| a.id.cmp(&b.id)));
+
+    let feed = Feed {
+        id: feed_link.clone(),
+        links: vec![
+            Link {
+                href: feed_link,
+                rel: "self".to_string(),
+                ..Default::default()
+            },
+            Link {
+                href: site_link,
+                ..Default::default()
+            },
+        ],
+        title: Text::plain(title),
+        updated: entries.first().map(|entry| entry.updated).unwrap_or(now),
+        entries,
+        ..Default::default()
+    };
+
+    Ok(feed.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+
+    use super::*;
+    use crate::author::Author;
+    use crate::author_data::AuthorData;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+    use crate::content_document_reference::ContentDocumentReference;
+
+    fn entry(basename: &str, day: u32, content: Option<&str>) -> FeedEntry {
+        let mut front_matter = ContentDocumentFrontMatter::mock(basename);
+
+        front_matter.last_updated_at = Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).single();
+
+        FeedEntry {
+            authors: vec![Author {
+                basename: "alice".to_string().into(),
+                data: AuthorData::mock("Alice"),
+            }],
+            content: content.map(str::to_string),
+            reference: ContentDocumentReference {
+                basename_path: basename.into(),
+                front_matter,
+                generated_page_base_path: "https://example.com/".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn lists_most_recently_updated_entries_first() -> Result<()> {
+        let feed = create_feed(
+            "Example".to_string(),
+            "https://example.com/feed.xml".to_string(),
+            "https://example.com/".to_string(),
+            vec![entry("older", 1, None), entry("newer", 2, None)],
+        )?;
+
+        assert!(feed.contains("<title>Example</title>"));
+        assert!(feed.contains("<updated>2025-01-02T00:00:00+00:00</updated>"));
+        assert!(feed.contains("<name>Alice</name>"));
+        assert!(feed.find("https://example.com/newer/") < feed.find("https://example.com/older/"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn includes_rendered_content_when_given() -> Result<()> {
+        let feed = create_feed(
+            "Example".to_string(),
+            "https://example.com/feed.xml".to_string(),
+            "https://example.com/".to_string(),
+            vec![entry("post", 1, Some("<p>Hello</p>"))],
+        )?;
+
+        assert!(feed.contains("<content type=\"html\">&lt;p&gt;Hello&lt;/p&gt;</content>"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use dashmap::DashMap;
+
+use crate::content_document_basename::ContentDocumentBasename;
+use crate::content_document_collection_ranked::ContentDocumentCollectionRanked;
+use crate::content_document_reference::ContentDocumentReference;
+use crate::project_config::site_config::SiteConfig;
+
+fn write_document_links<'a>(
+    llms_txt: &mut String,
+    section_name: &str,
+    references: impl Iterator<Item = &'a ContentDocumentReference>,
+) -> Result<()> {
+    let mut references = references
+        .filter(|reference| reference.front_matter.render)
+        .peekable();
+
+    if references.peek().is_none() {
+        return Ok(());
+    }
+
+    writeln!(llms_txt, "\n## {section_name}\n")?;
+
+    for reference in references {
+        writeln!(
+            llms_txt,
+            "- [{}]({}): {}",
+            reference.front_matter.title,
+            reference.canonical_link().map_err(|err| anyhow!(err))?,
+            reference.front_matter.description
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Follows the https://llmstxt.org convention. The site title and summary
+/// fall back to the ones of the home page. Collections are listed in
+/// hierarchy order, documents outside any collection come last.
+pub fn create_llms_txt<'a>(
+    site: &SiteConfig,
+    collections: &[&ContentDocumentCollectionRanked],
+    content_documents: impl Iterator<Item = &'a ContentDocumentReference>,
+) -> Result<String> {
+    let mut content_documents: Vec<&ContentDocumentReference> = content_documents.collect();
+
+    content_documents.sort();
+
+    let home_page = content_documents
+        .iter()
+        .find(|reference| reference.basename_path == Path::new("index"));
+    let title = site
+        .title
+        .clone()
+        .or_else(|| home_page.map(|reference| reference.front_matter.title.clone()))
+        .unwrap_or_default();
+    let summary = site
+        .description
+        .clone()
+        .or_else(|| home_page.map(|reference| reference.front_matter.description.clone()));
+    let mut llms_txt = format!("# {title}\n");
+
+    if let Some(summary) = summary {
+        writeln!(llms_txt, "\n> {summary}")?;
+    }
+
+    for collection in collections {
+        write_document_links(
+            &mut llms_txt,
+            &collection.name,
+            collection.hierarchy.flat.iter(),
+        )?;
+    }
+
+    write_document_links(
+        &mut llms_txt,
+        "Pages",
+        content_documents
+            .into_iter()
+            .filter(|reference| reference.front_matter.collections.placements.is_empty()),
+    )?;
+
+    Ok(llms_txt)
+}
+
+/// Documents are in the same order as in llms.txt, each one appears once.
+/// Only rendered documents have evaluated Markdown contents.
+pub fn create_llms_full_txt<'a>(
+    collections: &[&ContentDocumentCollectionRanked],
+    content_documents: impl Iterator<Item = &'a ContentDocumentReference>,
+    markdown_contents: &DashMap<ContentDocumentBasename, String>,
+) -> Result<String> {
+    let mut content_documents: Vec<&ContentDocumentReference> = content_documents
+        .filter(|reference| reference.front_matter.collections.placements.is_empty())
+        .collect();
+
+    content_documents.sort();
+
+    let mut llms_full_txt = String::new();
+    let mut written_basenames: HashSet<ContentDocumentBasename> = HashSet::new();
+
+    for reference in collections
+        .iter()
+        .flat_map(|collection| collection.hierarchy.flat.iter())
+        .chain(content_documents)
+    {
+        let basename = reference.basename();
+
+        if !reference.front_matter.render || written_basenames.contains(&basename) {
+            continue;
+        }
+
+        let Some(markdown_content) = markdown_contents.get(&basename) else {
+            continue;
+        };
+
+        if !llms_full_txt.is_empty() {
+            llms_full_txt.push_str("\n---\n\n");
+        }
+
+        writeln!(
+            llms_full_txt,
+            "# {}\n\nSource: {}\n\n{}",
+            reference.front_matter.title,
+            reference.canonical_link().map_err(|err| anyhow!(err))?,
+            markdown_content.value()
+        )?;
+
+        written_basenames.insert(basename);
+    }
+
+    Ok(llms_full_txt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_document_collection::ContentDocumentCollection;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+    use crate::content_document_front_matter::collection_placement::CollectionPlacement;
+    use crate::content_document_in_collection::ContentDocumentInCollection;
+
+    fn reference(basename: &str, collection: Option<&str>) -> ContentDocumentReference {
+        let mut front_matter = ContentDocumentFrontMatter::mock(basename);
+
+        front_matter.description = format!("About {basename}");
+
+        if let Some(collection) = collection {
+            front_matter
+                .collections
+                .placements
+                .push(CollectionPlacement {
+                    after: None,
+                    name: collection.to_string(),
+                    parent: None,
+                });
+        }
+
+        ContentDocumentReference {
+            basename_path: basename.into(),
+            front_matter,
+            generated_page_base_path: "https://example.com/".to_string(),
+        }
+    }
+
+    #[test]
+    fn lists_documents_by_collection() -> Result<()> {
+        let guide = reference("guide", Some("Docs"));
+        let references = [reference("index", None), guide.clone()];
+        let docs: ContentDocumentCollectionRanked = ContentDocumentCollection {
+            documents: vec![ContentDocumentInCollection {
+                collection_placement: guide.front_matter.collections.placements[0].clone(),
+                reference: guide,
+            }],
+            name: "Docs".to_string(),
+        }
+        .try_into()?;
+
+        let llms_txt = create_llms_txt(&SiteConfig::default(), &[&docs], references.iter())?;
+
+        assert_eq!(
+            llms_txt,
+            "# index\n\n> About index\n\n## Docs\n\n- [guide](https://example.com/guide/): About guide\n\n## Pages\n\n- [index](https://example.com/): About index\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+/// Lets every crawler in, and points it to the sitemap.
+pub fn create_robots_txt(sitemap_link: &str) -> String {
+    format!("User-agent: *\nAllow: /\n\nSitemap: {sitemap_link}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_sitemap() {
+        assert_eq!(
+            create_robots_txt("https://example.com/sitemap.xml"),
+            "User-agent: *\nAllow: /\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+}
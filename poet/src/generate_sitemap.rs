@@ -2,30 +2,81 @@ use std::path::Path;
 
 use anyhow::Result;
 use anyhow::anyhow;
-use chrono::Utc;
+use sitemap_rs::sitemap::Sitemap;
+use sitemap_rs::sitemap_index::SitemapIndex;
 use sitemap_rs::url::Url;
 use sitemap_rs::url_set::UrlSet;
 
 use crate::content_document_reference::ContentDocumentReference;
 
-pub fn create_sitemap<'a>(
+/// Search engines do not read more URLs than that from a single sitemap
+const URLS_PER_SITEMAP: usize = 50_000;
+
+fn create_url_set(urls: Vec<Url>) -> Result<String> {
+    let url_set = UrlSet::new(urls)?;
+    let mut buf: Vec<u8> = Vec::new();
+    url_set.write(&mut buf)?;
+
+    Ok(String::from_utf8(buf)?)
+}
+
+fn split_sitemaps(
+    base_path: &str,
+    mut urls: Vec<Url>,
+    urls_per_sitemap: usize,
+) -> Result<Vec<(String, String)>> {
+    if urls.len() <= urls_per_sitemap {
+        return Ok(vec![("sitemap.xml".to_string(), create_url_set(urls)?)]);
+    }
+
+    let mut sitemaps: Vec<(String, String)> = Vec::new();
+    let mut index_entries: Vec<Sitemap> = Vec::new();
+
+    while !urls.is_empty() {
+        let rest = urls.split_off(urls_per_sitemap.min(urls.len()));
+        let filename = format!("sitemap-{}.xml", sitemaps.len() + 1);
+
+        index_entries.push(Sitemap::new(format!("{base_path}{filename}"), None));
+        sitemaps.push((filename, create_url_set(urls)?));
+
+        urls = rest;
+    }
+
+    let sitemap_index = SitemapIndex::new(index_entries)?;
+    let mut buf: Vec<u8> = Vec::new();
+    sitemap_index.write(&mut buf)?;
+
+    sitemaps.insert(0, ("sitemap.xml".to_string(), String::from_utf8(buf)?));
+
+    Ok(sitemaps)
+}
+
+/// Returns sitemap files by their paths. Once there are too many URLs for a
+/// single sitemap, `sitemap.xml` becomes an index of numbered sitemaps.
+/// Listing pages of paginated documents are linked with default priority.
+pub fn create_sitemaps<'a>(
+    base_path: &str,
     content_documents: impl Iterator<Item = &'a ContentDocumentReference>,
-) -> Result<String> {
-    let last_modified = Utc::now().fixed_offset();
+    listing_page_links: impl Iterator<Item = String>,
+) -> Result<Vec<(String, String)>> {
     let mut urls: Vec<Url> = Vec::new();
 
     for reference in content_documents {
         let url = reference.canonical_link().map_err(|e| anyhow!(e))?;
-        let priority = if reference.basename_path == Path::new("index") {
-            0.8
-        } else {
-            0.5
+        let front_matter = &reference.front_matter;
+        let priority = match front_matter.priority {
+            Some(priority) => priority.0,
+            None if reference.basename_path == Path::new("index") => 0.8,
+            None => 0.5,
         };
 
         urls.push(Url::new(
             url,
-            Some(last_modified),
-            None,
+            front_matter
+                .last_updated_at
+                .or(front_matter.publish_at)
+                .map(|date| date.fixed_offset()),
+            front_matter.changefreq.map(Into::into),
             Some(priority),
             None,
             None,
@@ -33,17 +84,19 @@ pub fn create_sitemap<'a>(
         )?);
     }
 
-    let url_set = UrlSet::new(urls)?;
-    let mut buf: Vec<u8> = Vec::new();
-    url_set.write(&mut buf)?;
+    for url in listing_page_links {
+        urls.push(Url::new(url, None, None, Some(0.5), None, None, None)?);
+    }
 
-    Ok(String::from_utf8(buf)?)
+    split_sitemaps(base_path, urls, URLS_PER_SITEMAP)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::content_document_front_matter::ContentDocumentFrontMatter;
+    use crate::content_document_front_matter::sitemap_change_frequency::SitemapChangeFrequency;
+    use crate::content_document_front_matter::sitemap_priority::SitemapPriority;
 
     fn reference(basename: &str) -> ContentDocumentReference {
         ContentDocumentReference {
@@ -53,10 +106,29 @@ mod tests {
         }
     }
 
+    fn sitemap<'a>(
+        content_documents: impl Iterator<Item = &'a ContentDocumentReference>,
+        listing_page_links: impl Iterator<Item = String>,
+    ) -> Result<String> {
+        let mut sitemaps = create_sitemaps(
+            "https://example.com/",
+            content_documents,
+            listing_page_links,
+        )?;
+
+        assert_eq!(sitemaps.len(), 1);
+
+        let (filename, sitemap) = sitemaps.remove(0);
+
+        assert_eq!(filename, "sitemap.xml");
+
+        Ok(sitemap)
+    }
+
     #[test]
     fn index_document_receives_high_priority() -> Result<()> {
         let references = [reference("index")];
-        let sitemap = create_sitemap(references.iter())?;
+        let sitemap = sitemap(references.iter(), std::iter::empty())?;
 
         assert!(sitemap.contains("https://example.com/"));
         assert!(sitemap.contains("<priority>0.8</priority>"));
@@ -67,11 +139,94 @@ mod tests {
     #[test]
     fn non_index_document_receives_default_priority() -> Result<()> {
         let references = [reference("guide")];
-        let sitemap = create_sitemap(references.iter())?;
+        let sitemap = sitemap(references.iter(), std::iter::empty())?;
 
         assert!(sitemap.contains("https://example.com/guide/"));
         assert!(sitemap.contains("<priority>0.5</priority>"));
 
         Ok(())
     }
+
+    #[test]
+    fn listing_pages_are_included() -> Result<()> {
+        let references = [reference("blog/index")];
+        let sitemap = sitemap(
+            references.iter(),
+            ["https://example.com/blog/page/2/".to_string()].into_iter(),
+        )?;
+
+        assert!(sitemap.contains("https://example.com/blog/"));
+        assert!(sitemap.contains("https://example.com/blog/page/2/"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn takes_metadata_from_front_matter() -> Result<()> {
+        let mut guide = reference("guide");
+
+        guide.front_matter.changefreq = Some(SitemapChangeFrequency::Weekly);
+        guide.front_matter.last_updated_at = Some("2025-03-01T00:00:00Z".parse()?);
+        guide.front_matter.priority = Some(SitemapPriority(0.3));
+
+        let references = [guide];
+        let sitemap = sitemap(references.iter(), std::iter::empty())?;
+
+        assert!(sitemap.contains("<lastmod>2025-03-01T00:00:00+00:00</lastmod>"));
+        assert!(sitemap.contains("<changefreq>weekly</changefreq>"));
+        assert!(sitemap.contains("<priority>0.3</priority>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn leaves_out_lastmod_of_undated_documents() -> Result<()> {
+        let references = [reference("guide")];
+
+        assert!(!sitemap(references.iter(), std::iter::empty())?.contains("<lastmod>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn splits_into_sitemap_index_over_the_limit() -> Result<()> {
+        let urls = (0..5)
+            .map(|index| {
+                Url::new(
+                    format!("https://example.com/{index}/"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let sitemaps = split_sitemaps("https://example.com/", urls, 2)?;
+        let filenames: Vec<&str> = sitemaps
+            .iter()
+            .map(|(filename, _)| filename.as_str())
+            .collect();
+
+        assert_eq!(
+            filenames,
+            [
+                "sitemap.xml",
+                "sitemap-1.xml",
+                "sitemap-2.xml",
+                "sitemap-3.xml"
+            ]
+        );
+        assert!(sitemaps[0].1.contains("<sitemapindex"));
+        assert!(
+            sitemaps[0]
+                .1
+                .contains("<loc>https://example.com/sitemap-3.xml</loc>")
+        );
+        assert!(sitemaps[3].1.contains("https://example.com/4/"));
+        assert!(!sitemaps[3].1.contains("https://example.com/3/"));
+
+        Ok(())
+    }
 }
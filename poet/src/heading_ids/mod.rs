@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use markdown::mdast::Node;
+use markdown::unist::Position;
+
+use crate::mdast_children_to_heading_id::mdast_children_to_heading_id;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
+
+/// Ids of every heading in a document, unique within that document.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct HeadingIds {
+    /// Keyed by the offset at which the heading starts in the source file.
+    pub ids_by_offset: Arc<BTreeMap<usize, String>>,
+}
+
+impl HeadingIds {
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids_by_offset
+            .values()
+            .any(|heading_id| heading_id == id)
+    }
+
+    /// Headings that were not parsed from the document (and have no known
+    /// position) fall back to the id derived from their contents.
+    pub fn id_for(&self, children: &[Node], position: &Option<Position>) -> Result<String> {
+        if let Some(id) = position
+            .as_ref()
+            .and_then(|position| self.ids_by_offset.get(&position.start.offset))
+        {
+            return Ok(id.clone());
+        }
+
+        match strip_explicit_heading_id(children) {
+            (_, Some(id)) => Ok(id),
+            (children, None) => mdast_children_to_heading_id(&children),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use rhai_components::escape_html_attribute::escape_html_attribute;
+
+const MARKDOWN_ALTERNATE_LINK_MARKER: &str = "rel=\"alternate\" type=\"text/markdown\"";
+
+/// Every page with a `<head>` links to its Markdown alternate, so layouts do
+/// not have to. Layouts that already link to it are left alone.
+pub fn insert_markdown_alternate_link(page: &str, markdown_link: &str) -> String {
+    if page.contains(MARKDOWN_ALTERNATE_LINK_MARKER) {
+        return page.to_string();
+    }
+
+    match page.find("</head>") {
+        Some(head_end) => format!(
+            "{}<link {MARKDOWN_ALTERNATE_LINK_MARKER} href=\"{}\">{}",
+            &page[..head_end],
+            escape_html_attribute(markdown_link),
+            &page[head_end..]
+        ),
+        None => page.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_link_at_the_end_of_head() {
+        assert_eq!(
+            insert_markdown_alternate_link(
+                "<html><head><title>T</title></head><body></body></html>",
+                "/docs/index.md"
+            ),
+            "<html><head><title>T</title><link rel=\"alternate\" type=\"text/markdown\" href=\"/docs/index.md\"></head><body></body></html>"
+        );
+    }
+
+    #[test]
+    fn leaves_pages_without_head_or_with_a_link_alone() {
+        assert_eq!(
+            insert_markdown_alternate_link("<main></main>", "/index.md"),
+            "<main></main>"
+        );
+
+        let linked = "<head><link rel=\"alternate\" type=\"text/markdown\" href=\"/a.md\"></head>";
+
+        assert_eq!(insert_markdown_alternate_link(linked, "/index.md"), linked);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod admonition;
 pub mod anyhow_error_aggregate;
 pub mod app_dir_desktop_entry;
 pub mod assert_valid_desktop_entry_string;
@@ -14,7 +15,9 @@ pub mod build_prompt_document_controller;
 pub mod build_prompt_document_controller_collection;
 pub mod build_prompt_document_controller_params;
 pub mod build_timer;
+pub mod check_project;
 pub mod cmd;
+pub mod code_tokenizer;
 pub mod compile_shortcodes;
 pub mod content_document;
 pub mod content_document_basename;
@@ -25,40 +28,62 @@ pub mod content_document_front_matter;
 pub mod content_document_hierarchy;
 pub mod content_document_in_collection;
 pub mod content_document_linker;
+pub mod content_document_lint;
 pub mod content_document_reference;
 pub mod content_document_source;
 pub mod content_document_tree_node;
 pub mod copy_esbuild_metafile_assets_to;
+pub mod diagnostic;
+pub mod document_dependencies;
+pub mod document_dependency_recorder;
 pub mod document_error;
 pub mod document_error_collection;
 pub mod esbuild_metafile_holder;
 pub mod eval_content_document_mdast;
+pub mod eval_markdown_mdast;
+pub mod eval_markdown_mdast_params;
 pub mod eval_mdx_element;
-pub mod eval_prompt_document_mdast;
-pub mod eval_prompt_document_mdast_params;
 pub mod external_asset;
+pub mod feed_entry;
 pub mod filesystem;
 pub mod filesystem_http_route_index;
 pub mod filesystem_http_route_index_holder;
+pub mod find_admonition_in_blockquote;
+pub mod find_footnotes_in_mdast;
 pub mod find_front_matter_in_mdast;
+pub mod find_heading_ids_in_mdast;
+pub mod find_link_definitions_in_mdast;
 pub mod find_table_of_contents_in_mdast;
 pub mod find_text_content_in_mdast;
 pub mod flexible_datetime;
+pub mod footnotes;
+pub mod generate_feed;
+pub mod generate_llms_txt;
+pub mod generate_robots_txt;
 pub mod generate_sitemap;
+pub mod heading_ids;
 pub mod holder;
+pub mod insert_markdown_alternate_link;
 pub mod is_external_link;
 pub mod is_image_path;
 pub mod is_valid_desktop_entry_string;
+pub mod link_checker;
+pub mod link_definitions;
+pub mod lsp;
+pub mod markdown_component_context;
 pub mod mcp;
 pub mod mcp_resource_provider_content_documents;
 pub mod mdast_children_to_heading_id;
-pub mod mdast_to_tantivy_document;
+pub mod mdast_to_tantivy_documents;
+pub mod paginator;
 pub mod parse_markdown_metadata_line;
+pub mod project_config;
 pub mod prompt_controller_collection_holder;
 pub mod prompt_document_component_context;
 pub mod prompt_document_controller;
 pub mod prompt_document_front_matter;
 pub mod read_esbuild_metafile_or_default;
+pub mod render_code_frame;
 pub mod rhai_helpers;
 pub mod rhai_template_renderer_factory;
 pub mod rhai_template_renderer_holder;
@@ -70,5 +95,14 @@ pub mod search_index_reader;
 pub mod search_index_reader_holder;
 pub mod search_index_schema;
 pub mod search_tool;
+pub mod search_tool_found_document;
+pub mod seo;
+pub mod serde_struct_fields;
+pub mod source_position_error;
 pub mod string_to_mdast;
+pub mod strip_explicit_heading_id;
 pub mod table_of_contents;
+pub mod taxonomy;
+pub mod taxonomy_index;
+pub mod taxonomy_term;
+pub mod taxonomy_term_component_context;
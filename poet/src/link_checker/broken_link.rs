@@ -0,0 +1,23 @@
+use std::fmt;
+
+use markdown::unist::Position;
+
+#[derive(Clone, Debug)]
+pub struct BrokenLink {
+    pub message: String,
+    pub position: Option<Position>,
+    pub url: String,
+}
+
+impl fmt::Display for BrokenLink {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.position {
+            Some(position) => write!(
+                formatter,
+                "{}:{}: {}",
+                position.start.line, position.start.column, self.message
+            ),
+            None => write!(formatter, "{}", self.message),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+pub mod broken_link;
+
+use markdown::mdast::Image;
+use markdown::mdast::ImageReference;
+use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
+use markdown::mdast::Node;
+
+use crate::asset_manager::AssetManager;
+use crate::content_document_linker::ContentDocumentLinker;
+use crate::heading_ids::HeadingIds;
+use crate::is_external_link::is_external_link;
+use crate::link_checker::broken_link::BrokenLink;
+use crate::link_definitions::LinkDefinitions;
+
+enum LinkTargetKind {
+    Image,
+    Link,
+}
+
+/// Checks internal links, heading fragments, and images of a document before
+/// it is rendered, so every problem can be reported at once.
+pub struct LinkChecker {
+    pub asset_manager: AssetManager,
+    pub content_document_linker: ContentDocumentLinker,
+}
+
+impl LinkChecker {
+    /// References without a definition are skipped, they are reported by
+    /// `LinkDefinitions::undefined_references_in_mdast`.
+    pub fn check(
+        &self,
+        mdast: &Node,
+        heading_ids: &HeadingIds,
+        link_definitions: &LinkDefinitions,
+    ) -> Vec<BrokenLink> {
+        let mut broken_links = Vec::new();
+
+        self.check_node(mdast, heading_ids, link_definitions, &mut broken_links);
+
+        broken_links
+    }
+
+    fn check_node(
+        &self,
+        mdast: &Node,
+        heading_ids: &HeadingIds,
+        link_definitions: &LinkDefinitions,
+        broken_links: &mut Vec<BrokenLink>,
+    ) {
+        let link_target = match mdast {
+            Node::Image(Image { position, url, .. }) => {
+                Some((LinkTargetKind::Image, position, url.as_str()))
+            }
+            Node::ImageReference(ImageReference {
+                identifier,
+                position,
+                ..
+            }) => link_definitions
+                .get(identifier)
+                .map(|definition| (LinkTargetKind::Image, position, definition.url.as_str())),
+            Node::Link(Link { position, url, .. }) => {
+                Some((LinkTargetKind::Link, position, url.as_str()))
+            }
+            Node::LinkReference(LinkReference {
+                identifier,
+                position,
+                ..
+            }) => link_definitions
+                .get(identifier)
+                .map(|definition| (LinkTargetKind::Link, position, definition.url.as_str())),
+            _ => None,
+        };
+
+        if let Some((kind, position, url)) = link_target
+            && let Err(message) = self.check_url(heading_ids, kind, url)
+        {
+            broken_links.push(BrokenLink {
+                message,
+                position: position.clone(),
+                url: url.to_string(),
+            });
+        }
+
+        if let Some(children) = mdast.children() {
+            for child in children {
+                self.check_node(child, heading_ids, link_definitions, broken_links);
+            }
+        }
+    }
+
+    fn check_url(
+        &self,
+        heading_ids: &HeadingIds,
+        kind: LinkTargetKind,
+        url: &str,
+    ) -> Result<(), String> {
+        if is_external_link(url) {
+            return Ok(());
+        }
+
+        match kind {
+            LinkTargetKind::Image => self.asset_manager.image(url).map(|_| ()),
+            LinkTargetKind::Link => self
+                .content_document_linker
+                .link_from(heading_ids, url)
+                .map(|_| ()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::content_document_component_context::ContentDocumentComponentContext;
+    use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+    use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
+    use crate::string_to_mdast::string_to_mdast;
+
+    #[test]
+    fn reports_every_broken_internal_link_with_its_position() -> Result<()> {
+        let ContentDocumentComponentContext {
+            asset_manager,
+            content_document_linker,
+            ..
+        } = ContentDocumentComponentContext::mock();
+        let link_checker = LinkChecker {
+            asset_manager,
+            content_document_linker,
+        };
+        let mdast = string_to_mdast(
+            "See [a](missing) and [site](https://example.com).\n\n![logo][logo]\n\n[logo]: logo.png\n",
+        )?;
+
+        let broken_links = link_checker.check(
+            &mdast,
+            &find_heading_ids_in_mdast(&mdast)?,
+            &find_link_definitions_in_mdast(&mdast),
+        );
+
+        assert_eq!(
+            broken_links
+                .iter()
+                .map(|broken_link| broken_link.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "1:5: Document does not exist: missing",
+                "3:1: Image asset not found: 'logo.png'",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_same_page_fragments_against_own_headings() -> Result<()> {
+        let ContentDocumentComponentContext {
+            asset_manager,
+            content_document_linker,
+            ..
+        } = ContentDocumentComponentContext::mock();
+        let link_checker = LinkChecker {
+            asset_manager,
+            content_document_linker,
+        };
+        let mdast = string_to_mdast("# Usage\n\nSee [usage](#usage) and [setup](#setup).\n")?;
+
+        let broken_links = link_checker.check(
+            &mdast,
+            &find_heading_ids_in_mdast(&mdast)?,
+            &find_link_definitions_in_mdast(&mdast),
+        );
+
+        assert_eq!(
+            broken_links
+                .iter()
+                .map(|broken_link| broken_link.to_string())
+                .collect::<Vec<_>>(),
+            vec!["3:25: Neither a document id nor a heading of this document: #setup"]
+        );
+
+        Ok(())
+    }
+}
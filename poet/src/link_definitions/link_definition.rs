@@ -0,0 +1,5 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkDefinition {
+    pub title: Option<String>,
+    pub url: String,
+}
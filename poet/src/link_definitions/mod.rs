@@ -0,0 +1,191 @@
+pub mod link_definition;
+pub mod undefined_reference;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use markdown::mdast::Node;
+use markdown::mdast::Text;
+use markdown::unist::Point;
+use markdown::unist::Position;
+
+use crate::link_definitions::link_definition::LinkDefinition;
+use crate::link_definitions::undefined_reference::UndefinedReference;
+
+fn is_unescaped_at(source: &str, index: usize, bracket: u8) -> bool {
+    source.as_bytes().get(index) == Some(&bracket)
+        && (index == 0 || source.as_bytes()[index - 1] != b'\\')
+}
+
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn point_after(start: &Point, text: &str) -> Point {
+    let mut point = start.clone();
+
+    for character in text.chars() {
+        if character == '\n' {
+            point.line += 1;
+            point.column = 1;
+        } else {
+            point.column += 1;
+        }
+
+        point.offset += character.len_utf8();
+    }
+
+    point
+}
+
+/// Opening bracket of the label that ends right before `end`, if it is in
+/// the same piece of text.
+fn find_label_start(source: &str, end: usize) -> Option<usize> {
+    (0..end)
+        .rev()
+        .take_while(|index| !is_unescaped_at(source, *index, b']'))
+        .find(|index| is_unescaped_at(source, *index, b'['))
+}
+
+/// Finds `[text][label]` and `[label][]` in the source of a text node.
+/// Markdown only turns those into references when the label is defined,
+/// otherwise they are left as text.
+fn find_bracketed_references(source: &str) -> Vec<(String, usize, usize)> {
+    let mut references = Vec::new();
+    let mut index = 0;
+
+    while index < source.len() {
+        if !is_unescaped_at(source, index, b']') || !is_unescaped_at(source, index + 1, b'[') {
+            index += 1;
+
+            continue;
+        }
+
+        let Some(label_end) = (index + 2..source.len())
+            .take_while(|label_index| !is_unescaped_at(source, *label_index, b'['))
+            .find(|label_index| is_unescaped_at(source, *label_index, b']'))
+        else {
+            index += 1;
+
+            continue;
+        };
+
+        let text_start = find_label_start(source, index);
+        let second_label = &source[index + 2..label_end];
+
+        if !second_label.trim().is_empty() {
+            references.push((
+                second_label.to_string(),
+                text_start.unwrap_or(index),
+                label_end + 1,
+            ));
+        } else if let Some(text_start) = text_start {
+            references.push((
+                source[text_start + 1..index].to_string(),
+                text_start,
+                label_end + 1,
+            ));
+        }
+
+        index = label_end + 1;
+    }
+
+    references
+}
+
+/// Reference-style link definitions (`[ref]: url "title"`) of a single
+/// document, keyed by their normalized identifier.
+#[derive(Clone, Default)]
+pub struct LinkDefinitions {
+    pub definitions: Arc<HashMap<String, LinkDefinition>>,
+}
+
+impl LinkDefinitions {
+    pub fn get(&self, identifier: &str) -> Option<&LinkDefinition> {
+        self.definitions.get(identifier)
+    }
+
+    /// Lists link and image references that have no matching definition, in
+    /// document order. The source is needed, because those are only text in
+    /// the mdast.
+    pub fn undefined_references_in_mdast(
+        &self,
+        mdast: &Node,
+        source: &str,
+    ) -> Vec<UndefinedReference> {
+        let mut undefined_references = Vec::new();
+
+        self.find_undefined_references(mdast, source, &mut undefined_references);
+
+        undefined_references
+    }
+
+    fn find_undefined_references(
+        &self,
+        mdast: &Node,
+        source: &str,
+        undefined_references: &mut Vec<UndefinedReference>,
+    ) {
+        if let Node::Text(Text {
+            position: Some(position),
+            ..
+        }) = mdast
+            && let Some(text_source) = source.get(position.start.offset..position.end.offset)
+        {
+            for (label, start, end) in find_bracketed_references(text_source) {
+                if !self.definitions.contains_key(&normalize_label(&label)) {
+                    undefined_references.push(UndefinedReference {
+                        label,
+                        position: Some(Position {
+                            end: point_after(&position.start, &text_source[..end]),
+                            start: point_after(&position.start, &text_source[..start]),
+                        }),
+                    });
+                }
+            }
+        }
+
+        if let Some(children) = mdast.children() {
+            for child in children {
+                self.find_undefined_references(child, source, undefined_references);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
+    use crate::string_to_mdast::string_to_mdast;
+
+    #[test]
+    fn reports_references_without_definitions() -> Result<()> {
+        let source = "See [the docs][missing], [guide][] and [the\nhome page][home].\n\nNot \\[a reference\\]\\[either\\].\n\n[home]: /\n";
+        let mdast = string_to_mdast(source)?;
+        let undefined_references =
+            find_link_definitions_in_mdast(&mdast).undefined_references_in_mdast(&mdast, source);
+
+        assert_eq!(
+            undefined_references
+                .iter()
+                .map(|reference| reference.label.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["missing", "guide"]
+        );
+        assert_eq!(
+            undefined_references[0]
+                .position
+                .as_ref()
+                .map(|position| (position.start.column, position.end.column)),
+            Some((5, 24))
+        );
+
+        Ok(())
+    }
+}
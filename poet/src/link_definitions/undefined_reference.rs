@@ -0,0 +1,7 @@
+use markdown::unist::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UndefinedReference {
+    pub label: String,
+    pub position: Option<Position>,
+}
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::notification::did_change::DidChange;
+use crate::lsp::notification::did_close::DidClose;
+use crate::lsp::notification::did_open::DidOpen;
+use crate::lsp::notification::did_save::DidSave;
+use crate::lsp::notification::exit::Exit;
+use crate::lsp::notification::initialized::Initialized;
+use crate::lsp::request::completion::Completion;
+use crate::lsp::request::definition::Definition;
+use crate::lsp::request::initialize::Initialize;
+use crate::lsp::request::shutdown::Shutdown;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "method")]
+pub enum ClientToServerMessage {
+    #[serde(rename = "exit")]
+    Exit(Exit),
+    #[serde(rename = "initialize")]
+    Initialize(Initialize),
+    #[serde(rename = "initialized")]
+    Initialized(Initialized),
+    #[serde(rename = "shutdown")]
+    Shutdown(Shutdown),
+    #[serde(rename = "textDocument/completion")]
+    TextDocumentCompletion(Completion),
+    #[serde(rename = "textDocument/definition")]
+    TextDocumentDefinition(Definition),
+    #[serde(rename = "textDocument/didChange")]
+    TextDocumentDidChange(DidChange),
+    #[serde(rename = "textDocument/didClose")]
+    TextDocumentDidClose(DidClose),
+    #[serde(rename = "textDocument/didOpen")]
+    TextDocumentDidOpen(DidOpen),
+    #[serde(rename = "textDocument/didSave")]
+    TextDocumentDidSave(DidSave),
+}
+
+impl ClientToServerMessage {
+    /// Method names of the variants above, so a request for any other method
+    /// can be told apart from one with malformed params.
+    pub const METHODS: &[&str] = &[
+        "exit",
+        "initialize",
+        "initialized",
+        "shutdown",
+        "textDocument/completion",
+        "textDocument/definition",
+        "textDocument/didChange",
+        "textDocument/didClose",
+        "textDocument/didOpen",
+        "textDocument/didSave",
+    ];
+}
@@ -0,0 +1,211 @@
+fn is_identifier_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '-'
+}
+
+/// What is being typed right before the cursor.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CompletionContext {
+    ComponentName {
+        prefix: String,
+    },
+    ComponentProp {
+        component: String,
+        prefix: String,
+    },
+    DocumentBasename {
+        prefix: String,
+    },
+    FrontMatterKey {
+        prefix: String,
+    },
+    /// Without a basename, the heading is in the current document.
+    HeadingId {
+        basename: Option<String>,
+        prefix: String,
+    },
+}
+
+impl CompletionContext {
+    pub fn find(line_prefix: &str, is_in_front_matter: bool) -> Option<Self> {
+        if is_in_front_matter {
+            let prefix = line_prefix.trim_start();
+
+            return prefix
+                .chars()
+                .all(is_identifier_char)
+                .then(|| Self::FrontMatterKey {
+                    prefix: prefix.to_string(),
+                });
+        }
+
+        let link_target = Self::find_link_target(line_prefix);
+        let tag = Self::find_tag(line_prefix);
+
+        match (link_target, tag) {
+            (Some((link_start, link_context)), Some((tag_start, tag_context))) => {
+                if link_start > tag_start {
+                    Some(link_context)
+                } else {
+                    Some(tag_context)
+                }
+            }
+            (Some((_, context)), None) | (None, Some((_, context))) => Some(context),
+            (None, None) => None,
+        }
+    }
+
+    /// Markdown link destinations and `link_to("…")` arguments.
+    fn find_link_target(line_prefix: &str) -> Option<(usize, Self)> {
+        let markdown_link = line_prefix
+            .rfind("](")
+            .map(|index| (index, &line_prefix[index + 2..]))
+            .filter(|(_, target)| !target.contains([')', ' ']));
+        let link_to = line_prefix
+            .rfind("link_to(\"")
+            .map(|index| (index, &line_prefix[index + 9..]))
+            .filter(|(_, target)| !target.contains('"'));
+
+        let (start, target) = match (markdown_link, link_to) {
+            (Some(markdown_link), Some(link_to)) => {
+                if markdown_link.0 > link_to.0 {
+                    markdown_link
+                } else {
+                    link_to
+                }
+            }
+            (Some(found), None) | (None, Some(found)) => found,
+            (None, None) => return None,
+        };
+
+        if target.contains("://") {
+            return None;
+        }
+
+        let context = match target.split_once('#') {
+            Some((basename, prefix)) => Self::HeadingId {
+                basename: (!basename.is_empty()).then(|| basename.to_string()),
+                prefix: prefix.to_string(),
+            },
+            None => Self::DocumentBasename {
+                prefix: target.to_string(),
+            },
+        };
+
+        Some((start, context))
+    }
+
+    /// Component names right after `<` or `</`, and props inside the
+    /// opening tag.
+    fn find_tag(line_prefix: &str) -> Option<(usize, Self)> {
+        let start = line_prefix.rfind('<')?;
+        let tag = &line_prefix[start + 1..];
+        let tag = tag.strip_prefix('/').unwrap_or(tag);
+
+        if tag.contains('>') {
+            return None;
+        }
+
+        let name: String = tag.chars().take_while(|c| is_identifier_char(*c)).collect();
+
+        if name.chars().next().is_some_and(|c| !c.is_uppercase()) {
+            return None;
+        }
+
+        if name.len() == tag.len() {
+            return Some((start, Self::ComponentName { prefix: name }));
+        }
+
+        let attributes = &tag[name.len()..];
+
+        if name.is_empty()
+            || !attributes.starts_with(char::is_whitespace)
+            || attributes.matches('"').count() % 2 == 1
+            || attributes.matches('{').count() != attributes.matches('}').count()
+        {
+            return None;
+        }
+
+        let prefix: String = attributes
+            .chars()
+            .rev()
+            .take_while(|c| is_identifier_char(*c))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+
+        if !attributes[..attributes.len() - prefix.len()].ends_with(char::is_whitespace) {
+            return None;
+        }
+
+        Some((
+            start,
+            Self::ComponentProp {
+                component: name,
+                prefix,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_link_targets_and_heading_fragments() {
+        assert_eq!(
+            CompletionContext::find("See [the guide](gui", false),
+            Some(CompletionContext::DocumentBasename {
+                prefix: "gui".to_string()
+            })
+        );
+        assert_eq!(
+            CompletionContext::find("{link_to(\"guide#inst", false),
+            Some(CompletionContext::HeadingId {
+                basename: Some("guide".to_string()),
+                prefix: "inst".to_string()
+            })
+        );
+        assert_eq!(
+            CompletionContext::find("[Up](#", false),
+            Some(CompletionContext::HeadingId {
+                basename: None,
+                prefix: String::new()
+            })
+        );
+        assert_eq!(CompletionContext::find("[Done](guide) and", false), None);
+        assert_eq!(CompletionContext::find("[Out](https://exa", false), None);
+    }
+
+    #[test]
+    fn finds_component_names_and_props() {
+        assert_eq!(
+            CompletionContext::find("Text <Prim", false),
+            Some(CompletionContext::ComponentName {
+                prefix: "Prim".to_string()
+            })
+        );
+        assert_eq!(
+            CompletionContext::find("<Note kind=\"warn\" ti", false),
+            Some(CompletionContext::ComponentProp {
+                component: "Note".to_string(),
+                prefix: "ti".to_string()
+            })
+        );
+        assert_eq!(CompletionContext::find("<Note kind=\"wa", false), None);
+        assert_eq!(CompletionContext::find("<div cla", false), None);
+        assert_eq!(CompletionContext::find("<Note />", false), None);
+    }
+
+    #[test]
+    fn finds_front_matter_keys_only_at_the_start_of_a_line() {
+        assert_eq!(
+            CompletionContext::find("lay", true),
+            Some(CompletionContext::FrontMatterKey {
+                prefix: "lay".to_string()
+            })
+        );
+        assert_eq!(CompletionContext::find("layout = \"", true), None);
+    }
+}
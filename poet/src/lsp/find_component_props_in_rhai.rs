@@ -0,0 +1,60 @@
+use std::collections::BTreeSet;
+
+fn is_identifier_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Props a component reads, either as `props.name` or `props["name"]`.
+pub fn find_component_props_in_rhai(contents: &str) -> BTreeSet<String> {
+    let mut props = BTreeSet::new();
+
+    for (index, _) in contents.match_indices("props") {
+        let is_standalone = contents[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|character| !is_identifier_char(character));
+
+        if !is_standalone {
+            continue;
+        }
+
+        let rest = &contents[index + "props".len()..];
+        let name: String = if let Some(accessed) = rest.strip_prefix('.') {
+            accessed
+                .chars()
+                .take_while(|c| is_identifier_char(*c))
+                .collect()
+        } else if let Some(indexed) = rest.strip_prefix("[\"") {
+            indexed.chars().take_while(|c| *c != '"').collect()
+        } else {
+            continue;
+        };
+
+        if !name.is_empty() {
+            props.insert(name);
+        }
+    }
+
+    props
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_accessed_and_indexed_props() {
+        let props = find_component_props_in_rhai(
+            r#"fn template(context, props, content) {
+  let kind = props["kind"];
+
+  component { <aside class={props.variant} data-other={myprops.ignored}>{content}</aside> }
+}"#,
+        );
+
+        assert_eq!(
+            props.into_iter().collect::<Vec<_>>(),
+            vec!["kind".to_string(), "variant".to_string()]
+        );
+    }
+}
@@ -0,0 +1,744 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use anyhow::Result;
+use dashmap::DashMap;
+use log::debug;
+use log::error;
+use log::warn;
+use serde_json::Value;
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncWrite;
+use tokio::sync::Mutex;
+use tokio::sync::RwLock;
+use url::Url;
+
+use crate::check_project::check_project;
+use crate::content_document_front_matter::ContentDocumentFrontMatter;
+use crate::diagnostic::Diagnostic;
+use crate::filesystem::storage::Storage;
+use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+use crate::lsp::client_to_server_message::ClientToServerMessage;
+use crate::lsp::completion_context::CompletionContext;
+use crate::lsp::lsp_diagnostic::LspDiagnostic;
+use crate::lsp::notification::did_change::DidChange;
+use crate::lsp::notification::did_close::DidClose;
+use crate::lsp::notification::did_open::DidOpen;
+use crate::lsp::notification::did_save::DidSave;
+use crate::lsp::notification::publish_diagnostics::PublishDiagnostics;
+use crate::lsp::notification::publish_diagnostics::PublishDiagnosticsParams;
+use crate::lsp::project_index::ProjectIndex;
+use crate::lsp::read_message::read_message;
+use crate::lsp::request::completion::Completion;
+use crate::lsp::request::definition::Definition;
+use crate::lsp::request::initialize::Initialize;
+use crate::lsp::request::shutdown::Shutdown;
+use crate::lsp::response::completion_item::CompletionItem;
+use crate::lsp::response::completion_item_kind::CompletionItemKind;
+use crate::lsp::response::error_response::ErrorResponse;
+use crate::lsp::response::initialize_result::CompletionOptions;
+use crate::lsp::response::initialize_result::InitializeResult;
+use crate::lsp::response::initialize_result::ServerCapabilities;
+use crate::lsp::response::initialize_result::TEXT_DOCUMENT_SYNC_FULL;
+use crate::lsp::response::initialize_result::TextDocumentSyncOptions;
+use crate::lsp::response::location::Location;
+use crate::lsp::server_to_client_message::ServerToClientMessage;
+use crate::lsp::server_to_client_message::ServerToClientNotification;
+use crate::lsp::text_document::TextDocument;
+use crate::lsp::text_document_position_params::TextDocumentPositionParams;
+use crate::lsp::write_message::write_message;
+use crate::mcp::jsonrpc::JSONRPC_VERSION;
+use crate::mcp::jsonrpc::id::Id;
+use crate::mcp::jsonrpc::implementation::Implementation;
+use crate::mcp::jsonrpc::response::success::Success;
+use crate::project_config::ProjectConfig;
+use crate::prompt_document_front_matter::PromptDocumentFrontMatter;
+use crate::serde_struct_fields::serde_struct_fields;
+use crate::string_to_mdast::string_to_mdast;
+
+fn path_of(uri: &str) -> Option<PathBuf> {
+    Url::parse(uri).ok()?.to_file_path().ok()
+}
+
+fn completion_items<'label>(
+    labels: impl Iterator<Item = &'label str>,
+    kind: CompletionItemKind,
+    prefix: &str,
+) -> Vec<CompletionItem> {
+    labels
+        .filter(|label| label.starts_with(prefix))
+        .map(|label| CompletionItem {
+            detail: None,
+            kind,
+            label: label.to_string(),
+        })
+        .collect()
+}
+
+/// Speaks the language server protocol, with the project directory as the
+/// only workspace.
+pub struct LanguageServer {
+    documents: DashMap<String, TextDocument>,
+    /// After `shutdown`, only `exit` is expected.
+    is_shut_down: AtomicBool,
+    project_index: RwLock<ProjectIndex>,
+    published_diagnostics: Mutex<BTreeSet<String>>,
+    project_config: ProjectConfig,
+    source_filesystem: Arc<Storage>,
+}
+
+impl LanguageServer {
+    pub fn new(source_filesystem: Arc<Storage>, project_config: ProjectConfig) -> Self {
+        Self {
+            documents: Default::default(),
+            is_shut_down: Default::default(),
+            project_index: Default::default(),
+            published_diagnostics: Default::default(),
+            project_config,
+            source_filesystem,
+        }
+    }
+
+    /// Handles messages until the client sends `exit`, or closes the stream.
+    pub async fn serve<TReader, TWriter>(
+        &self,
+        mut reader: TReader,
+        mut writer: TWriter,
+    ) -> Result<()>
+    where
+        TReader: AsyncBufRead + Unpin,
+        TWriter: AsyncWrite + Unpin,
+    {
+        while let Some(body) = read_message(&mut reader).await? {
+            let incoming: Value = match serde_json::from_str(&body) {
+                Ok(incoming) => incoming,
+                Err(err) => {
+                    write_message(&mut writer, &ErrorResponse::parse(err.to_string())).await?;
+
+                    continue;
+                }
+            };
+
+            let id = incoming
+                .get("id")
+                .and_then(|id| serde_json::from_value::<Id>(id.clone()).ok());
+
+            if self.is_shut_down.load(Ordering::Relaxed)
+                && incoming.get("method").and_then(Value::as_str) != Some("exit")
+            {
+                match id {
+                    Some(id) => {
+                        write_message(
+                            &mut writer,
+                            &ErrorResponse::invalid_request(
+                                id,
+                                "Server is shut down, only exit is expected".to_string(),
+                            ),
+                        )
+                        .await?
+                    }
+                    None => debug!("Ignoring notification after shutdown"),
+                }
+
+                continue;
+            }
+
+            let message = match serde_json::from_value::<ClientToServerMessage>(incoming.clone()) {
+                Ok(ClientToServerMessage::Exit(_)) => break,
+                Ok(message) => message,
+                Err(err) => {
+                    let is_supported_method = incoming
+                        .get("method")
+                        .and_then(Value::as_str)
+                        .is_some_and(|method| ClientToServerMessage::METHODS.contains(&method));
+
+                    match id {
+                        Some(id) if !is_supported_method => {
+                            write_message(
+                                &mut writer,
+                                &ErrorResponse::method_not_found(id, err.to_string()),
+                            )
+                            .await?
+                        }
+                        Some(id) => {
+                            write_message(
+                                &mut writer,
+                                &ErrorResponse::invalid_params(id, err.to_string()),
+                            )
+                            .await?
+                        }
+                        None => debug!("Ignoring notification: {err}"),
+                    }
+
+                    continue;
+                }
+            };
+
+            for outgoing in self.respond_to(message).await {
+                write_message(&mut writer, &outgoing).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn respond_to(&self, message: ClientToServerMessage) -> Vec<ServerToClientMessage> {
+        match message {
+            ClientToServerMessage::Exit(_) => vec![],
+            ClientToServerMessage::Initialize(Initialize { id, .. }) => {
+                self.refresh_project_index().await;
+
+                vec![ServerToClientMessage::Initialize(Success {
+                    id,
+                    jsonrpc: JSONRPC_VERSION.to_string(),
+                    result: InitializeResult {
+                        capabilities: ServerCapabilities {
+                            completion_provider: CompletionOptions {
+                                trigger_characters: ["(", "\"", "#", "<", " "]
+                                    .map(str::to_string)
+                                    .to_vec(),
+                            },
+                            definition_provider: true,
+                            text_document_sync: TextDocumentSyncOptions {
+                                change: TEXT_DOCUMENT_SYNC_FULL,
+                                open_close: true,
+                                save: true,
+                            },
+                        },
+                        server_info: Implementation {
+                            description: None,
+                            name: "poet".to_string(),
+                            title: None,
+                            version: env!("CARGO_PKG_VERSION").to_string(),
+                        },
+                    },
+                })]
+            }
+            ClientToServerMessage::Initialized(_) => self.publish_diagnostics().await,
+            ClientToServerMessage::Shutdown(Shutdown { id, .. }) => {
+                self.is_shut_down.store(true, Ordering::Relaxed);
+
+                vec![ServerToClientMessage::Shutdown(Success {
+                    id,
+                    jsonrpc: JSONRPC_VERSION.to_string(),
+                    result: (),
+                })]
+            }
+            ClientToServerMessage::TextDocumentCompletion(Completion { id, params, .. }) => {
+                vec![ServerToClientMessage::Completion(Success {
+                    id,
+                    jsonrpc: JSONRPC_VERSION.to_string(),
+                    result: self.complete(params).await,
+                })]
+            }
+            ClientToServerMessage::TextDocumentDefinition(Definition { id, params, .. }) => {
+                vec![ServerToClientMessage::Definition(Success {
+                    id,
+                    jsonrpc: JSONRPC_VERSION.to_string(),
+                    result: self.find_definition(params).await,
+                })]
+            }
+            ClientToServerMessage::TextDocumentDidChange(DidChange { params, .. }) => {
+                if let Some(change) = params.content_changes.into_iter().next_back() {
+                    self.documents
+                        .insert(params.text_document.uri, TextDocument { text: change.text });
+                }
+
+                vec![]
+            }
+            ClientToServerMessage::TextDocumentDidClose(DidClose { params, .. }) => {
+                self.documents.remove(&params.text_document.uri);
+
+                vec![]
+            }
+            ClientToServerMessage::TextDocumentDidOpen(DidOpen { params, .. }) => {
+                self.documents.insert(
+                    params.text_document.uri,
+                    TextDocument {
+                        text: params.text_document.text,
+                    },
+                );
+
+                vec![]
+            }
+            ClientToServerMessage::TextDocumentDidSave(DidSave { .. }) => {
+                self.refresh_project_index().await;
+                self.publish_diagnostics().await
+            }
+        }
+    }
+
+    async fn complete(
+        &self,
+        TextDocumentPositionParams {
+            position,
+            text_document,
+        }: TextDocumentPositionParams,
+    ) -> Vec<CompletionItem> {
+        let Some(document) = self.text_document(&text_document.uri).await else {
+            return vec![];
+        };
+        let Some(context) = document.line_prefix(position).and_then(|line_prefix| {
+            CompletionContext::find(line_prefix, document.is_in_front_matter(position.line))
+        }) else {
+            return vec![];
+        };
+        let project_index = self.project_index.read().await;
+
+        match context {
+            CompletionContext::ComponentName { prefix } => project_index
+                .components
+                .iter()
+                .filter(|(name, _)| name.starts_with(&prefix))
+                .map(|(name, component)| CompletionItem {
+                    detail: component
+                        .path
+                        .strip_prefix(&self.source_filesystem.base_directory)
+                        .ok()
+                        .map(|relative_path| relative_path.display().to_string()),
+                    kind: CompletionItemKind::Class,
+                    label: name.clone(),
+                })
+                .collect(),
+            CompletionContext::ComponentProp { component, prefix } => {
+                match project_index.components.get(&component) {
+                    Some(component) => completion_items(
+                        component.props.iter().map(String::as_str),
+                        CompletionItemKind::Property,
+                        &prefix,
+                    ),
+                    None => vec![],
+                }
+            }
+            CompletionContext::DocumentBasename { prefix } => completion_items(
+                project_index
+                    .heading_ids_by_basename
+                    .keys()
+                    .map(String::as_str),
+                CompletionItemKind::File,
+                &prefix,
+            ),
+            CompletionContext::FrontMatterKey { prefix } => {
+                let fields = if self.is_prompt(&text_document.uri) {
+                    serde_struct_fields::<PromptDocumentFrontMatter>()
+                } else {
+                    serde_struct_fields::<ContentDocumentFrontMatter>()
+                };
+
+                completion_items(fields.iter().copied(), CompletionItemKind::Field, &prefix)
+            }
+            CompletionContext::HeadingId {
+                basename: Some(basename),
+                prefix,
+            } => completion_items(
+                project_index
+                    .heading_ids_by_basename
+                    .get(&basename)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+                CompletionItemKind::Reference,
+                &prefix,
+            ),
+            CompletionContext::HeadingId {
+                basename: None,
+                prefix,
+            } => {
+                // the open document might have headings that are not saved yet
+                let heading_ids: Vec<String> = match string_to_mdast(&document.text)
+                    .and_then(|mdast| find_heading_ids_in_mdast(&mdast))
+                {
+                    Ok(heading_ids) => heading_ids.ids_by_offset.values().cloned().collect(),
+                    Err(_) => self
+                        .content_basename(&text_document.uri)
+                        .and_then(|basename| project_index.heading_ids_by_basename.get(&basename))
+                        .cloned()
+                        .unwrap_or_default(),
+                };
+
+                completion_items(
+                    heading_ids.iter().map(String::as_str),
+                    CompletionItemKind::Reference,
+                    &prefix,
+                )
+            }
+        }
+    }
+
+    fn content_basename(&self, uri: &str) -> Option<String> {
+        let path = path_of(uri)?;
+        let relative_path = path
+            .strip_prefix(
+                self.source_filesystem
+                    .base_directory
+                    .join(&self.source_filesystem.directories.content),
+            )
+            .ok()?;
+
+        Some(relative_path.with_extension("").display().to_string())
+    }
+
+    async fn find_definition(
+        &self,
+        TextDocumentPositionParams {
+            position,
+            text_document,
+        }: TextDocumentPositionParams,
+    ) -> Option<Location> {
+        let name = self
+            .text_document(&text_document.uri)
+            .await?
+            .component_at(position)?;
+        let project_index = self.project_index.read().await;
+        let component = project_index.components.get(&name)?;
+
+        Some(Location {
+            range: Default::default(),
+            uri: Url::from_file_path(&component.path).ok()?.to_string(),
+        })
+    }
+
+    fn is_prompt(&self, uri: &str) -> bool {
+        path_of(uri).is_some_and(|path| {
+            path.starts_with(
+                self.source_filesystem
+                    .base_directory
+                    .join(&self.source_filesystem.directories.prompts),
+            )
+        })
+    }
+
+    /// Diagnostics come from a full build of the saved files. Documents that
+    /// no longer have problems get an empty list, so editors clear them.
+    async fn publish_diagnostics(&self) -> Vec<ServerToClientMessage> {
+        let diagnostics =
+            match check_project(self.source_filesystem.clone(), &self.project_config).await {
+                Ok(warnings) => warnings,
+                Err(err) => Diagnostic::from_build_error(&err),
+            };
+        let mut diagnostics_by_uri: BTreeMap<String, Vec<LspDiagnostic>> = BTreeMap::new();
+
+        for diagnostic in &diagnostics {
+            match diagnostic
+                .source_path
+                .as_ref()
+                .and_then(|source_path| self.uri_of(Path::new(source_path)))
+            {
+                Some(uri) => diagnostics_by_uri
+                    .entry(uri)
+                    .or_default()
+                    .push(diagnostic.into()),
+                None => warn!("{diagnostic}"),
+            }
+        }
+
+        let mut published_diagnostics = self.published_diagnostics.lock().await;
+
+        for uri in published_diagnostics.iter() {
+            diagnostics_by_uri.entry(uri.clone()).or_default();
+        }
+
+        *published_diagnostics = diagnostics_by_uri
+            .iter()
+            .filter(|(_, diagnostics)| !diagnostics.is_empty())
+            .map(|(uri, _)| uri.clone())
+            .collect();
+
+        diagnostics_by_uri
+            .into_iter()
+            .map(|(uri, diagnostics)| {
+                ServerToClientMessage::Notification(ServerToClientNotification::PublishDiagnostics(
+                    PublishDiagnostics {
+                        jsonrpc: JSONRPC_VERSION.to_string(),
+                        params: PublishDiagnosticsParams { diagnostics, uri },
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    async fn refresh_project_index(&self) {
+        match ProjectIndex::build(self.source_filesystem.clone()).await {
+            Ok(project_index) => *self.project_index.write().await = project_index,
+            Err(err) => error!("Failed to index project: {err:#}"),
+        }
+    }
+
+    /// Open documents take precedence over their saved contents.
+    async fn text_document(&self, uri: &str) -> Option<TextDocument> {
+        if let Some(document) = self.documents.get(uri) {
+            return Some(document.clone());
+        }
+
+        let text = tokio::fs::read_to_string(path_of(uri)?).await.ok()?;
+
+        Some(TextDocument { text })
+    }
+
+    fn uri_of(&self, relative_path: &Path) -> Option<String> {
+        Url::from_file_path(self.source_filesystem.base_directory.join(relative_path))
+            .ok()
+            .map(|uri| uri.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use anyhow::anyhow;
+    use serde_json::json;
+    use tempfile::tempdir;
+    use tokio::io::BufReader;
+    use tokio::io::DuplexStream;
+    use tokio::io::ReadHalf;
+    use tokio::io::WriteHalf;
+    use tokio::io::duplex;
+    use tokio::io::split;
+
+    use super::*;
+    use crate::filesystem::Filesystem as _;
+
+    const LAYOUT: &str =
+        "fn template(context, props, content) { component { <html>{content}</html> } }";
+    const NOTE: &str = "fn template(context, props, content) { component { <aside class={props.kind}>{content}</aside> } }";
+
+    struct LspTestClient {
+        reader: BufReader<ReadHalf<DuplexStream>>,
+        writer: WriteHalf<DuplexStream>,
+    }
+
+    impl LspTestClient {
+        async fn send(&mut self, message: Value) -> Result<()> {
+            write_message(&mut self.writer, &message).await
+        }
+
+        /// Skips notifications until the response to the given request.
+        async fn response_to(&mut self, id: i32) -> Result<Value> {
+            loop {
+                let body = read_message(&mut self.reader)
+                    .await?
+                    .ok_or_else(|| anyhow!("Server closed the stream"))?;
+                let message: Value = serde_json::from_str(&body)?;
+
+                if message["id"] == json!(id) {
+                    return Ok(message);
+                }
+            }
+        }
+
+        async fn notification(&mut self, method: &str) -> Result<Value> {
+            loop {
+                let body = read_message(&mut self.reader)
+                    .await?
+                    .ok_or_else(|| anyhow!("Server closed the stream"))?;
+                let message: Value = serde_json::from_str(&body)?;
+
+                if message["method"] == json!(method) {
+                    return Ok(message);
+                }
+            }
+        }
+    }
+
+    fn labels(response: &Value) -> Vec<String> {
+        response["result"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|item| item["label"].as_str().map(str::to_string))
+            .collect()
+    }
+
+    fn completion(id: i32, uri: &str, line: u32, character: u32) -> Value {
+        json!({
+            "id": id,
+            "jsonrpc": "2.0",
+            "method": "textDocument/completion",
+            "params": {
+                "position": { "character": character, "line": line },
+                "textDocument": { "uri": uri },
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn completes_and_resolves_project_symbols_over_stdio_framing() -> Result<()> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().canonicalize()?,
+            directories: Default::default(),
+        });
+
+        for (relative_path, contents) in [
+            ("shortcodes/Layout.rhai", LAYOUT),
+            ("shortcodes/Note.rhai", NOTE),
+            (
+                "content/guide.md",
+                "+++\ndescription = \"Guide\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\n## Install\n\n## Usage {#use}\n",
+            ),
+            (
+                "content/index.md",
+                "+++\ndescription = \"Home\"\nlayout = \"Layout\"\ntitle = \"Home\"\n+++\n\n[Gone](gone)\n",
+            ),
+        ] {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), contents)
+                .await?;
+        }
+
+        let index_uri =
+            Url::from_file_path(source_filesystem.base_directory.join("content/index.md"))
+                .map_err(|_| anyhow!("Invalid path"))?
+                .to_string();
+        let language_server = LanguageServer::new(source_filesystem.clone(), Default::default());
+        let (client_stream, server_stream) = duplex(64 * 1024);
+        let (server_reader, server_writer) = split(server_stream);
+        let (client_reader, client_writer) = split(client_stream);
+        let mut client = LspTestClient {
+            reader: BufReader::new(client_reader),
+            writer: client_writer,
+        };
+
+        let client_session = async {
+            client
+                .send(json!({ "id": 1, "jsonrpc": "2.0", "method": "initialize", "params": {} }))
+                .await?;
+
+            let initialize = client.response_to(1).await?;
+
+            client
+                .send(json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }))
+                .await?;
+
+            let published = client
+                .notification("textDocument/publishDiagnostics")
+                .await?;
+
+            client
+                .send(json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/didOpen",
+                    "params": {
+                        "textDocument": {
+                            "languageId": "markdown",
+                            "text": "+++\nlay\n+++\n\n[Guide](gu\n\n{link_to(\"guide#\n\n<No\n\n<Note k\n\n<Note />\n",
+                            "uri": index_uri,
+                            "version": 1,
+                        },
+                    },
+                }))
+                .await?;
+            client.send(completion(2, &index_uri, 1, 3)).await?;
+
+            let front_matter_keys = client.response_to(2).await?;
+
+            client.send(completion(3, &index_uri, 4, 10)).await?;
+
+            let basenames = client.response_to(3).await?;
+
+            client.send(completion(4, &index_uri, 6, 16)).await?;
+
+            let heading_ids = client.response_to(4).await?;
+
+            client.send(completion(5, &index_uri, 8, 3)).await?;
+
+            let components = client.response_to(5).await?;
+
+            client.send(completion(6, &index_uri, 10, 7)).await?;
+
+            let props = client.response_to(6).await?;
+
+            client
+                .send(json!({
+                    "id": 7,
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/definition",
+                    "params": {
+                        "position": { "character": 3, "line": 12 },
+                        "textDocument": { "uri": index_uri },
+                    },
+                }))
+                .await?;
+
+            let definition = client.response_to(7).await?;
+
+            client
+                .send(json!({ "id": 8, "jsonrpc": "2.0", "method": "workspace/symbol", "params": {} }))
+                .await?;
+
+            let unknown = client.response_to(8).await?;
+
+            client
+                .send(json!({
+                    "id": 11,
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/definition",
+                    "params": {},
+                }))
+                .await?;
+
+            let malformed = client.response_to(11).await?;
+
+            client
+                .send(json!({ "id": 9, "jsonrpc": "2.0", "method": "shutdown" }))
+                .await?;
+            client.response_to(9).await?;
+            client.send(completion(10, &index_uri, 1, 3)).await?;
+
+            let after_shutdown = client.response_to(10).await?;
+
+            client
+                .send(json!({ "jsonrpc": "2.0", "method": "exit" }))
+                .await?;
+
+            assert_eq!(
+                initialize["result"]["capabilities"]["definitionProvider"],
+                json!(true)
+            );
+            assert_eq!(published["params"]["uri"], json!(index_uri));
+            assert_eq!(
+                published["params"]["diagnostics"][0]["range"]["start"],
+                json!({ "character": 0, "line": 6 })
+            );
+            assert_eq!(
+                published["params"]["diagnostics"][0]["code"],
+                json!("broken-link")
+            );
+            assert_eq!(labels(&front_matter_keys), vec!["layout".to_string()]);
+            assert_eq!(labels(&basenames), vec!["guide".to_string()]);
+            assert_eq!(
+                labels(&heading_ids),
+                vec!["install".to_string(), "use".to_string()]
+            );
+            assert_eq!(labels(&components), vec!["Note".to_string()]);
+            assert_eq!(labels(&props), vec!["kind".to_string()]);
+            assert!(
+                definition["result"]["uri"]
+                    .as_str()
+                    .is_some_and(|uri| uri.ends_with("/shortcodes/Note.rhai"))
+            );
+            assert_eq!(unknown["error"]["code"], json!(-32601));
+            assert_eq!(malformed["error"]["code"], json!(-32602));
+            assert_eq!(after_shutdown["error"]["code"], json!(-32600));
+
+            Ok::<(), anyhow::Error>(())
+        };
+
+        let (served, session) = tokio::join!(
+            language_server.serve(BufReader::new(server_reader), server_writer),
+            client_session
+        );
+
+        served?;
+        session?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::diagnostic_severity::DiagnosticSeverity;
+use crate::lsp::lsp_range::LspRange;
+
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LspDiagnostic {
+    pub code: String,
+    pub message: String,
+    pub range: LspRange,
+    pub severity: u8,
+    pub source: String,
+}
+
+impl From<&Diagnostic> for LspDiagnostic {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self {
+            code: diagnostic.code.to_string(),
+            message: diagnostic.message.clone(),
+            range: diagnostic
+                .range
+                .as_ref()
+                .map(LspRange::from)
+                .unwrap_or_default(),
+            severity: match diagnostic.severity {
+                DiagnosticSeverity::Error => SEVERITY_ERROR,
+                DiagnosticSeverity::Warning => SEVERITY_WARNING,
+            },
+            source: "poet".to_string(),
+        }
+    }
+}
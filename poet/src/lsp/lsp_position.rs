@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Zero-based, with the character offset counted in UTF-16 code units.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LspPosition {
+    pub character: u32,
+    pub line: u32,
+}
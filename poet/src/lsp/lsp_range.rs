@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::diagnostic::diagnostic_range::DiagnosticRange;
+use crate::lsp::lsp_position::LspPosition;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LspRange {
+    pub end: LspPosition,
+    pub start: LspPosition,
+}
+
+impl From<&DiagnosticRange> for LspRange {
+    fn from(DiagnosticRange { end, start }: &DiagnosticRange) -> Self {
+        Self {
+            end: LspPosition {
+                character: end.column.saturating_sub(1) as u32,
+                line: end.line.saturating_sub(1) as u32,
+            },
+            start: LspPosition {
+                character: start.column.saturating_sub(1) as u32,
+                line: start.line.saturating_sub(1) as u32,
+            },
+        }
+    }
+}
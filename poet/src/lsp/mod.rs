@@ -0,0 +1,17 @@
+pub mod client_to_server_message;
+pub mod completion_context;
+pub mod find_component_props_in_rhai;
+pub mod language_server;
+pub mod lsp_diagnostic;
+pub mod lsp_position;
+pub mod lsp_range;
+pub mod notification;
+pub mod project_index;
+pub mod read_message;
+pub mod request;
+pub mod response;
+pub mod server_to_client_message;
+pub mod text_document;
+pub mod text_document_identifier;
+pub mod text_document_position_params;
+pub mod write_message;
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::text_document_identifier::TextDocumentIdentifier;
+
+/// Documents are synchronized in full, so every change holds the whole text.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidChangeParams {
+    #[serde(rename = "contentChanges")]
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+    #[serde(rename = "textDocument")]
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidChange {
+    pub jsonrpc: String,
+    pub params: DidChangeParams,
+}
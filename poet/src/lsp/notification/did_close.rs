@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::text_document_identifier::TextDocumentIdentifier;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidCloseParams {
+    #[serde(rename = "textDocument")]
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidClose {
+    pub jsonrpc: String,
+    pub params: DidCloseParams,
+}
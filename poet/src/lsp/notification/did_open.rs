@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TextDocumentItem {
+    #[serde(rename = "languageId")]
+    pub language_id: String,
+    pub text: String,
+    pub uri: String,
+    pub version: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidOpenParams {
+    #[serde(rename = "textDocument")]
+    pub text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DidOpen {
+    pub jsonrpc: String,
+    pub params: DidOpenParams,
+}
@@ -0,0 +1,7 @@
+pub mod did_change;
+pub mod did_close;
+pub mod did_open;
+pub mod did_save;
+pub mod exit;
+pub mod initialized;
+pub mod publish_diagnostics;
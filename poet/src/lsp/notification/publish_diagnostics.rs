@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::lsp_diagnostic::LspDiagnostic;
+
+/// An empty list of diagnostics clears the ones previously published for
+/// the document.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublishDiagnosticsParams {
+    pub diagnostics: Vec<LspDiagnostic>,
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublishDiagnostics {
+    pub jsonrpc: String,
+    pub params: PublishDiagnosticsParams,
+}
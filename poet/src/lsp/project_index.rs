@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use log::debug;
+use rhai_components::builds_engine::BuildsEngine as _;
+
+use crate::filesystem::Filesystem as _;
+use crate::filesystem::storage::Storage;
+use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+use crate::lsp::find_component_props_in_rhai::find_component_props_in_rhai;
+use crate::rhai_template_renderer_factory::RhaiTemplateRendererFactory;
+use crate::string_to_mdast::string_to_mdast;
+
+#[derive(Clone, Debug, Default)]
+pub struct IndexedComponent {
+    pub path: PathBuf,
+    pub props: BTreeSet<String>,
+}
+
+/// What the editor can complete, read from the files on disk. Files that
+/// do not parse are skipped, their problems are reported as diagnostics.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectIndex {
+    pub components: BTreeMap<String, IndexedComponent>,
+    pub heading_ids_by_basename: BTreeMap<String, Vec<String>>,
+}
+
+impl ProjectIndex {
+    pub async fn build(source_filesystem: Arc<Storage>) -> Result<Self> {
+        let mut heading_ids_by_basename = BTreeMap::new();
+        let mut props_by_path = BTreeMap::new();
+        let rhai_template_factory = RhaiTemplateRendererFactory::new(
+            source_filesystem.base_directory.clone(),
+            source_filesystem.directories.shortcodes.clone(),
+        );
+
+        for file in source_filesystem.read_project_files().await? {
+            if file.kind.is_content() {
+                let basename = file.get_stem_relative_to(&source_filesystem.directories.content);
+
+                match string_to_mdast(&file.contents)
+                    .and_then(|mdast| find_heading_ids_in_mdast(&mdast))
+                {
+                    Ok(heading_ids) => {
+                        heading_ids_by_basename.insert(
+                            basename,
+                            heading_ids.ids_by_offset.values().cloned().collect(),
+                        );
+                    }
+                    Err(err) => {
+                        debug!("Skipping headings of '{basename}': {err:#}");
+
+                        heading_ids_by_basename.insert(basename, Vec::new());
+                    }
+                }
+            } else if file.kind.is_shortcode() {
+                props_by_path.insert(
+                    file.relative_path.display().to_string(),
+                    find_component_props_in_rhai(&file.contents),
+                );
+                rhai_template_factory.register_component_file(file);
+            }
+        }
+
+        let components = rhai_template_factory
+            .component_registry()
+            .components
+            .iter()
+            .map(|entry| {
+                let component_reference = entry.value();
+
+                (
+                    component_reference.name.clone(),
+                    IndexedComponent {
+                        path: source_filesystem
+                            .base_directory
+                            .join(&component_reference.path),
+                        props: props_by_path
+                            .get(&component_reference.path)
+                            .cloned()
+                            .unwrap_or_default(),
+                    },
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            components,
+            heading_ids_by_basename,
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use anyhow::anyhow;
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncBufReadExt as _;
+use tokio::io::AsyncReadExt as _;
+
+/// Reads the body of the next message, or `None` when the stream ends.
+pub async fn read_message<TReader>(reader: &mut TReader) -> Result<Option<String>>
+where
+    TReader: AsyncBufRead + Unpin,
+{
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header).await? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = Some(value.trim().parse()?);
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| anyhow!("Message is missing a Content-Length header"))?;
+    let mut body = vec![0; content_length];
+
+    reader.read_exact(&mut body).await?;
+
+    Ok(Some(String::from_utf8(body)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use tokio::io::BufReader;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_consecutive_messages_until_the_stream_ends() -> Result<()> {
+        let mut reader = BufReader::new(
+            "Content-Length: 2\r\n\r\n{}Content-Type: application/vscode-jsonrpc\r\nContent-Length: 4\r\n\r\nnull"
+                .as_bytes(),
+        );
+
+        assert_eq!(read_message(&mut reader).await?, Some("{}".to_string()));
+        assert_eq!(read_message(&mut reader).await?, Some("null".to_string()));
+        assert_eq!(read_message(&mut reader).await?, None);
+
+        Ok(())
+    }
+}
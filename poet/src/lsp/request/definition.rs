@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::text_document_position_params::TextDocumentPositionParams;
+use crate::mcp::jsonrpc::id::Id;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Definition {
+    pub id: Id,
+    pub jsonrpc: String,
+    pub params: TextDocumentPositionParams,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::mcp::jsonrpc::id::Id;
+
+/// Client capabilities are not inspected, the project directory is given on
+/// the command line.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Initialize {
+    pub id: Id,
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub params: Value,
+}
@@ -0,0 +1,4 @@
+pub mod completion;
+pub mod definition;
+pub mod initialize;
+pub mod shutdown;
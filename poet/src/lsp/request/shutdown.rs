@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::mcp::jsonrpc::id::Id;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Shutdown {
+    pub id: Id,
+    pub jsonrpc: String,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use crate::lsp::response::completion_item_kind::CompletionItemKind;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CompletionItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub kind: CompletionItemKind,
+    pub label: String,
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+use serde::Serializer;
+
+/// Subset of the kinds defined by the protocol, serialized as their numeric
+/// values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompletionItemKind {
+    Class = 7,
+    Field = 5,
+    File = 17,
+    Property = 10,
+    Reference = 18,
+}
+
+impl Serialize for CompletionItemKind {
+    fn serialize<TSerializer>(
+        &self,
+        serializer: TSerializer,
+    ) -> Result<TSerializer::Ok, TSerializer::Error>
+    where
+        TSerializer: Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
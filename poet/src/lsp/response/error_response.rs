@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::mcp::jsonrpc::JSONRPC_VERSION;
+use crate::mcp::jsonrpc::id::Id;
+
+const ERROR_INVALID_PARAMS: i32 = -32602;
+const ERROR_INVALID_REQUEST: i32 = -32600;
+const ERROR_METHOD_NOT_FOUND: i32 = -32601;
+const ERROR_PARSE_ERROR: i32 = -32700;
+
+#[derive(Debug, Serialize)]
+pub struct ResponseError {
+    pub code: i32,
+    pub message: String,
+}
+
+/// Requests that could not be parsed have no id, which is sent as `null`.
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    pub error: ResponseError,
+    pub id: Option<Id>,
+    pub jsonrpc: String,
+}
+
+impl ErrorResponse {
+    pub fn invalid_params(id: Id, message: String) -> Self {
+        Self::new(Some(id), ERROR_INVALID_PARAMS, message)
+    }
+
+    pub fn invalid_request(id: Id, message: String) -> Self {
+        Self::new(Some(id), ERROR_INVALID_REQUEST, message)
+    }
+
+    pub fn method_not_found(id: Id, message: String) -> Self {
+        Self::new(Some(id), ERROR_METHOD_NOT_FOUND, message)
+    }
+
+    pub fn parse(message: String) -> Self {
+        Self::new(None, ERROR_PARSE_ERROR, message)
+    }
+
+    fn new(id: Option<Id>, code: i32, message: String) -> Self {
+        Self {
+            error: ResponseError { code, message },
+            id,
+            jsonrpc: JSONRPC_VERSION.to_string(),
+        }
+    }
+}
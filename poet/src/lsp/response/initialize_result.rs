@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+use crate::mcp::jsonrpc::implementation::Implementation;
+
+/// Full document synchronization, see `TextDocumentSyncKind` in the protocol.
+pub const TEXT_DOCUMENT_SYNC_FULL: u8 = 1;
+
+#[derive(Debug, Serialize)]
+pub struct CompletionOptions {
+    #[serde(rename = "triggerCharacters")]
+    pub trigger_characters: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TextDocumentSyncOptions {
+    pub change: u8,
+    #[serde(rename = "openClose")]
+    pub open_close: bool,
+    pub save: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerCapabilities {
+    #[serde(rename = "completionProvider")]
+    pub completion_provider: CompletionOptions,
+    #[serde(rename = "definitionProvider")]
+    pub definition_provider: bool,
+    #[serde(rename = "textDocumentSync")]
+    pub text_document_sync: TextDocumentSyncOptions,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InitializeResult {
+    pub capabilities: ServerCapabilities,
+    #[serde(rename = "serverInfo")]
+    pub server_info: Implementation,
+}
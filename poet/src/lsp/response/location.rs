@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+use crate::lsp::lsp_range::LspRange;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Location {
+    pub range: LspRange,
+    pub uri: String,
+}
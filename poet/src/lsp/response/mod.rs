@@ -0,0 +1,5 @@
+pub mod completion_item;
+pub mod completion_item_kind;
+pub mod error_response;
+pub mod initialize_result;
+pub mod location;
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use crate::lsp::notification::publish_diagnostics::PublishDiagnostics;
+use crate::lsp::response::completion_item::CompletionItem;
+use crate::lsp::response::initialize_result::InitializeResult;
+use crate::lsp::response::location::Location;
+use crate::mcp::jsonrpc::response::success::Success;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "method")]
+pub enum ServerToClientNotification {
+    #[serde(rename = "textDocument/publishDiagnostics")]
+    PublishDiagnostics(PublishDiagnostics),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ServerToClientMessage {
+    Completion(Success<Vec<CompletionItem>>),
+    Definition(Success<Option<Location>>),
+    Initialize(Success<InitializeResult>),
+    Notification(ServerToClientNotification),
+    Shutdown(Success<()>),
+}
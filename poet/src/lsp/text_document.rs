@@ -0,0 +1,122 @@
+use crate::lsp::lsp_position::LspPosition;
+
+fn is_component_name_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Latest known contents of a document open in the editor.
+#[derive(Clone, Debug)]
+pub struct TextDocument {
+    pub text: String,
+}
+
+impl TextDocument {
+    /// Component referenced by the `<Component` or `</Component` tag under
+    /// the cursor.
+    pub fn component_at(&self, position: LspPosition) -> Option<String> {
+        let (line, cursor) = self.line_and_offset(position)?;
+        let start = line[..cursor]
+            .rfind(|character: char| !is_component_name_char(character))
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let end = line[cursor..]
+            .find(|character: char| !is_component_name_char(character))
+            .map(|index| cursor + index)
+            .unwrap_or(line.len());
+        let name = &line[start..end];
+        let before = &line[..start];
+
+        if (before.ends_with('<') || before.ends_with("</"))
+            && name.starts_with(|character: char| character.is_uppercase())
+        {
+            Some(name.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Front matter is the `+++` fenced block at the very start of the
+    /// document.
+    pub fn is_in_front_matter(&self, line: u32) -> bool {
+        let mut lines = self.text.lines();
+
+        if lines.next().map(str::trim_end) != Some("+++") || line == 0 {
+            return false;
+        }
+
+        !lines
+            .take(line as usize - 1)
+            .any(|previous_line| previous_line.trim_end() == "+++")
+    }
+
+    pub fn line_prefix(&self, position: LspPosition) -> Option<&str> {
+        let (line, cursor) = self.line_and_offset(position)?;
+
+        Some(&line[..cursor])
+    }
+
+    /// The line, and the byte offset of the cursor within it.
+    fn line_and_offset(
+        &self,
+        LspPosition { character, line }: LspPosition,
+    ) -> Option<(&str, usize)> {
+        let line = self.text.lines().nth(line as usize)?;
+        let mut utf16_offset = 0;
+
+        for (byte_offset, character_in_line) in line.char_indices() {
+            if utf16_offset >= character as usize {
+                return Some((line, byte_offset));
+            }
+
+            utf16_offset += character_in_line.len_utf16();
+        }
+
+        Some((line, line.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(line: u32, character: u32) -> LspPosition {
+        LspPosition { character, line }
+    }
+
+    #[test]
+    fn finds_component_under_the_cursor() {
+        let document = TextDocument {
+            text: "# Hi\n\n<Note kind=\"x\">ü</Note>\n".to_string(),
+        };
+
+        assert_eq!(
+            document.component_at(position(2, 3)),
+            Some("Note".to_string())
+        );
+        assert_eq!(
+            document.component_at(position(2, 21)),
+            Some("Note".to_string())
+        );
+        assert_eq!(document.component_at(position(2, 8)), None);
+    }
+
+    #[test]
+    fn detects_front_matter_lines() {
+        let document = TextDocument {
+            text: "+++\ntitle = \"Hi\"\n+++\n\nBody\n".to_string(),
+        };
+
+        assert!(!document.is_in_front_matter(0));
+        assert!(document.is_in_front_matter(1));
+        assert!(!document.is_in_front_matter(3));
+    }
+
+    #[test]
+    fn counts_the_cursor_in_utf16_code_units() {
+        let document = TextDocument {
+            text: "😀 [a](gu".to_string(),
+        };
+
+        assert_eq!(document.line_prefix(position(0, 3)), Some("😀 "));
+    }
+}
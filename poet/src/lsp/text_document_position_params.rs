@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp::lsp_position::LspPosition;
+use crate::lsp::text_document_identifier::TextDocumentIdentifier;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TextDocumentPositionParams {
+    pub position: LspPosition,
+    #[serde(rename = "textDocument")]
+    pub text_document: TextDocumentIdentifier,
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use serde::Serialize;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt as _;
+
+pub async fn write_message<TWriter, TMessage>(
+    writer: &mut TWriter,
+    message: &TMessage,
+) -> Result<()>
+where
+    TWriter: AsyncWrite + Unpin,
+    TMessage: Serialize,
+{
+    let body = serde_json::to_string(message)?;
+
+    writer
+        .write_all(format!("Content-Length: {}\r\n\r\n{body}", body.len()).as_bytes())
+        .await?;
+    writer.flush().await?;
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use clap::Parser;
 use clap::Subcommand;
+use poet::cmd::check::Check;
 use poet::cmd::handler::Handler;
+use poet::cmd::lsp::Lsp;
 use poet::cmd::make::app_dir::AppDir;
 use poet::cmd::make::static_pages::StaticPages;
 use poet::cmd::serve::Serve;
@@ -16,6 +18,10 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Validates your content files without generating any output
+    Check(Check),
+    /// Starts a language server over stdio, for editors
+    Lsp(Lsp),
     /// Produce various output formats based on your content files
     Make {
         #[command(subcommand)]
@@ -37,6 +43,8 @@ enum Make {
 
 fn get_handler() -> Option<Box<dyn Handler>> {
     match Cli::parse().command {
+        Some(Commands::Check(handler)) => Some(Box::new(handler)),
+        Some(Commands::Lsp(handler)) => Some(Box::new(handler)),
         Some(Commands::Make { command }) => match command {
             Make::AppDir(handler) => Some(Box::new(handler)),
             Make::StaticPages(handler) => Some(Box::new(handler)),
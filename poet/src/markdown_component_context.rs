@@ -0,0 +1,37 @@
+use anyhow::Result;
+use rhai::CustomType;
+
+use crate::asset_manager::AssetManager;
+use crate::content_document_linker::ContentDocumentLinker;
+use crate::link_definitions::LinkDefinitions;
+
+/// Component context that documents can be evaluated back into Markdown
+/// with. Hooks let the context react to the document structure, and do
+/// nothing by default.
+pub trait MarkdownComponentContext: Clone + CustomType {
+    fn asset_manager(&self) -> &AssetManager;
+
+    fn content_document_linker(&self) -> &ContentDocumentLinker;
+
+    fn link_definitions(&self) -> &LinkDefinitions;
+
+    fn link_to(&self, path: &str) -> Result<String, String> {
+        self.content_document_linker().link_to(path)
+    }
+
+    /// Receives every block placed directly in the document root.
+    fn append_block(&mut self, _block: String) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called once the entire document is evaluated.
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Receives the strong text that opens a top-level paragraph. Returns
+    /// `true` if it was consumed and should not be rendered.
+    fn take_leading_strong(&mut self, _text: &str) -> Result<bool> {
+        Ok(false)
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::atomic;
 use actix_web::rt;
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::Utc;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 
@@ -46,8 +47,7 @@ impl ResourceProvider for McpResourceProviderContentDocuments {
             .0
             .must_get_build_project_result()
             .await?
-            .content_document_sources
-            .values()
+            .published_content_document_sources(Utc::now())
             .skip(offset)
             .take(limit)
             .map(|content_document_source| {
@@ -81,7 +81,15 @@ impl ResourceProvider for McpResourceProviderContentDocuments {
         let basename: ContentDocumentBasename = path.into();
         let build_project_result = self.0.must_get_build_project_result().await?;
 
-        match build_project_result.content_document_sources.get(&basename) {
+        match build_project_result
+            .content_document_sources
+            .get(&basename)
+            .filter(|content_document_source| {
+                !content_document_source
+                    .reference
+                    .front_matter
+                    .is_draft_at(Utc::now())
+            }) {
             Some(content_document_source) => Ok(Some(ResourceContentParts {
                 parts: vec![ResourceContent::Text(TextResourceContent {
                     mime_type: self.mime_type(),
@@ -155,6 +163,7 @@ mod tests {
         let directory = tempdir()?;
         let source_filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         source_filesystem
@@ -169,6 +178,12 @@ mod tests {
                 "+++\ndescription = \"Guide description\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\nbody\n",
             )
             .await?;
+        source_filesystem
+            .set_file_contents(
+                Path::new("content/draft.md"),
+                "+++\ndescription = \"Draft\"\ndraft = true\nlayout = \"Layout\"\ntitle = \"Draft\"\n+++\n\nbody\n",
+            )
+            .await?;
 
         let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
         let authors = build_authors(source_filesystem.clone()).await?;
@@ -178,12 +193,20 @@ mod tests {
                 base_path: "/".to_string(),
             },
             authors,
+            components: Default::default(),
             esbuild_metafile: Default::default(),
+            feed_full_content: false,
             generated_page_base_path: "/".to_string(),
+            generate_feeds: false,
+            generate_llms_txt: false,
             generate_sitemap: false,
-            is_watching: false,
+            include_drafts: true,
+            is_watching: true,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: Default::default(),
             source_filesystem,
+            taxonomies: Default::default(),
         })
         .await?
         .into())
@@ -238,6 +261,12 @@ mod tests {
                 .await?
                 .is_none()
         );
+        assert!(
+            provider
+                .read_resource_contents(reference("draft"))
+                .await?
+                .is_none()
+        );
 
         Ok(())
     }
@@ -0,0 +1,399 @@
+use std::mem::replace;
+use std::sync::Arc;
+
+use anyhow::Result;
+use markdown::mdast::Blockquote;
+use markdown::mdast::Code;
+use markdown::mdast::Delete;
+use markdown::mdast::Emphasis;
+use markdown::mdast::Heading as MdastHeading;
+use markdown::mdast::InlineCode;
+use markdown::mdast::InlineMath;
+use markdown::mdast::Link;
+use markdown::mdast::LinkReference;
+use markdown::mdast::List;
+use markdown::mdast::ListItem;
+use markdown::mdast::Math;
+use markdown::mdast::MdxJsxFlowElement;
+use markdown::mdast::MdxJsxTextElement;
+use markdown::mdast::Node;
+use markdown::mdast::Paragraph;
+use markdown::mdast::Root;
+use markdown::mdast::Strong;
+use markdown::mdast::Table;
+use markdown::mdast::TableCell;
+use markdown::mdast::TableRow;
+use markdown::mdast::Text;
+use tantivy::TantivyDocument;
+use tantivy::schema::Field;
+
+use crate::admonition::Admonition;
+use crate::find_admonition_in_blockquote::find_admonition_in_blockquote;
+use crate::heading_ids::HeadingIds;
+use crate::search_index_fields::SearchIndexFields;
+use crate::strip_explicit_heading_id::strip_explicit_heading_id;
+
+#[derive(Clone, Copy)]
+enum ParentElementType {
+    Callout,
+    Heading,
+    List,
+    Other,
+    Paragraph,
+    Table,
+}
+
+impl ParentElementType {
+    fn text_field(&self, fields: &SearchIndexFields) -> Option<Field> {
+        match self {
+            Self::Callout => Some(fields.callout),
+            Self::Heading => Some(fields.header),
+            Self::List => Some(fields.list),
+            Self::Other => None,
+            Self::Paragraph => Some(fields.paragraph),
+            Self::Table => Some(fields.table),
+        }
+    }
+
+    /// Callouts, lists and tables keep their own fields for everything they
+    /// contain.
+    fn enter(self, element_type: Self) -> Self {
+        match self {
+            Self::Callout | Self::List | Self::Table => self,
+            Self::Heading | Self::Other | Self::Paragraph => element_type,
+        }
+    }
+}
+
+/// Every heading starts a new section, so search results can point to the
+/// part of the document that matched.
+struct Sections {
+    current: TantivyDocument,
+    finished: Vec<TantivyDocument>,
+}
+
+impl Sections {
+    fn start(&mut self, fields: &SearchIndexFields, heading_id: &str) {
+        let mut document = TantivyDocument::new();
+
+        document.add_field_value(fields.heading_id, heading_id);
+
+        self.finished.push(replace(&mut self.current, document));
+    }
+
+    fn into_documents(mut self) -> Vec<TantivyDocument> {
+        self.finished.push(self.current);
+        self.finished
+    }
+}
+
+fn traverse_mdast_children(
+    sections: &mut Sections,
+    children: &Vec<Node>,
+    fields: Arc<SearchIndexFields>,
+    heading_ids: &HeadingIds,
+    parent_element_type: &ParentElementType,
+) -> Result<()> {
+    for child in children {
+        traverse_mdast(
+            sections,
+            child,
+            fields.clone(),
+            heading_ids,
+            parent_element_type,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn traverse_mdast(
+    sections: &mut Sections,
+    mdast: &Node,
+    fields: Arc<SearchIndexFields>,
+    heading_ids: &HeadingIds,
+    parent_element_type: &ParentElementType,
+) -> Result<()> {
+    match mdast {
+        Node::Blockquote(Blockquote { children, .. }) => {
+            if let Some(Admonition { children, .. }) = find_admonition_in_blockquote(children) {
+                traverse_mdast_children(
+                    sections,
+                    &children,
+                    fields,
+                    heading_ids,
+                    &ParentElementType::Callout,
+                )
+            } else {
+                traverse_mdast_children(
+                    sections,
+                    children,
+                    fields,
+                    heading_ids,
+                    parent_element_type,
+                )
+            }
+        }
+        Node::Code(Code { value, .. }) | Node::InlineCode(InlineCode { value, .. }) => {
+            sections.current.add_field_value(fields.code, value);
+
+            Ok(())
+        }
+        Node::Delete(Delete { children, .. })
+        | Node::Emphasis(Emphasis { children, .. })
+        | Node::Link(Link { children, .. })
+        | Node::LinkReference(LinkReference { children, .. })
+        | Node::MdxJsxTextElement(MdxJsxTextElement { children, .. })
+        | Node::Root(Root { children, .. })
+        | Node::Strong(Strong { children, .. })
+        | Node::TableCell(TableCell { children, .. })
+        | Node::TableRow(TableRow { children, .. }) => {
+            traverse_mdast_children(sections, children, fields, heading_ids, parent_element_type)
+        }
+        Node::Heading(MdastHeading {
+            children, position, ..
+        }) => {
+            sections.start(&fields, &heading_ids.id_for(children, position)?);
+
+            let (children, _) = strip_explicit_heading_id(children);
+
+            traverse_mdast_children(
+                sections,
+                &children,
+                fields,
+                heading_ids,
+                &parent_element_type.enter(ParentElementType::Heading),
+            )
+        }
+        Node::List(List { children, .. }) | Node::ListItem(ListItem { children, .. }) => {
+            traverse_mdast_children(
+                sections,
+                children,
+                fields,
+                heading_ids,
+                &parent_element_type.enter(ParentElementType::List),
+            )
+        }
+        // text placed directly in components is indexed like a paragraph
+        Node::MdxJsxFlowElement(MdxJsxFlowElement { children, .. })
+        | Node::Paragraph(Paragraph { children, .. }) => traverse_mdast_children(
+            sections,
+            children,
+            fields,
+            heading_ids,
+            &parent_element_type.enter(ParentElementType::Paragraph),
+        ),
+        Node::Table(Table { children, .. }) => traverse_mdast_children(
+            sections,
+            children,
+            fields,
+            heading_ids,
+            &parent_element_type.enter(ParentElementType::Table),
+        ),
+        Node::InlineMath(InlineMath { value, .. }) | Node::Math(Math { value, .. }) => {
+            // keep the LaTeX source searchable, wherever the formula is
+            sections.current.add_field_value(
+                parent_element_type
+                    .text_field(&fields)
+                    .unwrap_or(fields.paragraph),
+                value,
+            );
+
+            Ok(())
+        }
+        Node::Text(Text { value, .. }) => {
+            // do not index loose text outside of any element
+            if let Some(field) = parent_element_type.text_field(&fields) {
+                sections.current.add_field_value(field, value);
+            }
+
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The first document holds whatever comes before the first heading, even
+/// when that is nothing.
+pub fn mdast_to_tantivy_documents(
+    fields: Arc<SearchIndexFields>,
+    heading_ids: &HeadingIds,
+    mdast: &Node,
+) -> Result<Vec<TantivyDocument>> {
+    let mut sections = Sections {
+        current: TantivyDocument::new(),
+        finished: Vec::new(),
+    };
+
+    traverse_mdast(
+        &mut sections,
+        mdast,
+        fields,
+        heading_ids,
+        &ParentElementType::Other,
+    )?;
+
+    Ok(sections.into_documents())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use tantivy::schema::Field;
+    use tantivy::schema::Value as _;
+
+    use super::*;
+    use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+    use crate::search_index_schema::SearchIndexSchema;
+    use crate::string_to_mdast::string_to_mdast;
+
+    fn field_text(document: &TantivyDocument, field: Field) -> Option<String> {
+        document
+            .get_first(field)
+            .and_then(|value| value.as_str())
+            .map(|text| text.to_string())
+    }
+
+    fn sections(fields: Arc<SearchIndexFields>, mdast: &Node) -> Result<Vec<TantivyDocument>> {
+        mdast_to_tantivy_documents(fields, &find_heading_ids_in_mdast(mdast)?, mdast)
+    }
+
+    #[test]
+    fn starts_a_section_at_every_heading() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast("Intro\n\n# Title {#custom}\n\nBody paragraph\n\n## Title")?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(documents.len(), 3);
+        assert_eq!(field_text(&documents[0], fields.heading_id), None);
+        assert_eq!(
+            field_text(&documents[0], fields.paragraph),
+            Some("Intro".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[1], fields.heading_id),
+            Some("custom".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[1], fields.header),
+            Some("Title".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[1], fields.paragraph),
+            Some("Body paragraph".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[2], fields.heading_id),
+            Some("title".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn routes_alert_text_to_callout() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast("> [!IMPORTANT]\n> Back up first\n\n> Plain quote")?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(
+            field_text(&documents[0], fields.callout),
+            Some("Back up first".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[0], fields.paragraph),
+            Some("Plain quote".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_index_text_outside_heading_or_paragraph() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = Node::Root(Root {
+            children: vec![Node::Text(Text {
+                value: "loose".to_string(),
+                position: None,
+            })],
+            position: None,
+        });
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(field_text(&documents[0], fields.header), None);
+        assert_eq!(field_text(&documents[0], fields.paragraph), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn indexes_text_of_reference_links() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast("[Installation guide][install]\n\n[install]: guide")?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(
+            field_text(&documents[0], fields.paragraph),
+            Some("Installation guide".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn indexes_latex_source_of_math() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast("$$\nE = mc^2\n$$")?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(
+            field_text(&documents[0], fields.paragraph),
+            Some("E = mc^2".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn routes_code_lists_and_tables_to_their_own_fields() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast(
+            "```rust\nfn main() {}\n```\n\n- List item\n\n| Cell |\n| - |\n| Value |",
+        )?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(
+            field_text(&documents[0], fields.code),
+            Some("fn main() {}".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[0], fields.list),
+            Some("List item".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[0], fields.table),
+            Some("Cell".to_string())
+        );
+        assert_eq!(field_text(&documents[0], fields.paragraph), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn indexes_text_placed_directly_in_components() -> Result<()> {
+        let fields = Arc::new(SearchIndexSchema::default().fields);
+        let mdast = string_to_mdast("<Note>\nInside `inline_code`\n</Note>")?;
+        let documents = sections(fields.clone(), &mdast)?;
+
+        assert_eq!(
+            field_text(&documents[0], fields.paragraph),
+            Some("Inside ".to_string())
+        );
+        assert_eq!(
+            field_text(&documents[0], fields.code),
+            Some("inline_code".to_string())
+        );
+
+        Ok(())
+    }
+}
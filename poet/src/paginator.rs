@@ -0,0 +1,140 @@
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::TypeBuilder;
+
+use crate::content_document_reference::ContentDocumentReference;
+use crate::rhai_helpers::optional_string_to_dynamic;
+
+/// One listing page of a paginated document. Pages are numbered from 1.
+#[derive(Clone, Debug)]
+pub struct Paginator {
+    pub current_page: usize,
+    pub items: Vec<ContentDocumentReference>,
+    pub next_link: Option<String>,
+    pub previous_link: Option<String>,
+    pub total_pages: usize,
+}
+
+impl Paginator {
+    /// There is always at least one page, even when there is nothing to
+    /// list.
+    pub fn paginate(
+        paginating_document: &ContentDocumentReference,
+        items: &[ContentDocumentReference],
+        per_page: usize,
+    ) -> Result<Vec<Self>, String> {
+        if per_page == 0 {
+            return Err("Pagination needs at least one item per page".to_string());
+        }
+
+        let total_pages = items.len().div_ceil(per_page).max(1);
+        let mut pages: Vec<Self> = Vec::with_capacity(total_pages);
+
+        for current_page in 1..=total_pages {
+            let items = items
+                .iter()
+                .skip((current_page - 1) * per_page)
+                .take(per_page)
+                .cloned()
+                .collect();
+            let next_link = if current_page < total_pages {
+                Some(paginating_document.page_canonical_link(current_page + 1)?)
+            } else {
+                None
+            };
+            let previous_link = if current_page > 1 {
+                Some(paginating_document.page_canonical_link(current_page - 1)?)
+            } else {
+                None
+            };
+
+            pages.push(Self {
+                current_page,
+                items,
+                next_link,
+                previous_link,
+                total_pages,
+            });
+        }
+
+        Ok(pages)
+    }
+
+    fn rhai_current_page(&mut self) -> i64 {
+        self.current_page as i64
+    }
+
+    fn rhai_items(&mut self) -> rhai::Array {
+        self.items.iter().cloned().map(Dynamic::from).collect()
+    }
+
+    fn rhai_next_link(&mut self) -> Dynamic {
+        optional_string_to_dynamic(&self.next_link)
+    }
+
+    fn rhai_previous_link(&mut self) -> Dynamic {
+        optional_string_to_dynamic(&self.previous_link)
+    }
+
+    fn rhai_total_pages(&mut self) -> i64 {
+        self.total_pages as i64
+    }
+}
+
+impl CustomType for Paginator {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Paginator")
+            .with_get("current_page", Self::rhai_current_page)
+            .with_get("items", Self::rhai_items)
+            .with_get("next_link", Self::rhai_next_link)
+            .with_get("previous_link", Self::rhai_previous_link)
+            .with_get("total_pages", Self::rhai_total_pages);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+
+    fn reference(basename: &str) -> ContentDocumentReference {
+        ContentDocumentReference {
+            basename_path: basename.into(),
+            front_matter: ContentDocumentFrontMatter::mock(basename),
+            generated_page_base_path: "/".to_string(),
+        }
+    }
+
+    #[test]
+    fn splits_items_into_linked_pages() -> Result<(), String> {
+        let items: Vec<ContentDocumentReference> =
+            ["a", "b", "c"].into_iter().map(reference).collect();
+        let pages = Paginator::paginate(&reference("blog/index"), &items, 2)?;
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].items.len(), 2);
+        assert_eq!(pages[0].previous_link, None);
+        assert_eq!(pages[0].next_link.as_deref(), Some("/blog/page/2/"));
+        assert_eq!(pages[1].items.len(), 1);
+        assert_eq!(pages[1].previous_link.as_deref(), Some("/blog/"));
+        assert_eq!(pages[1].next_link, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn produces_single_empty_page_without_items() -> Result<(), String> {
+        let pages = Paginator::paginate(&reference("blog/index"), &[], 10)?;
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].total_pages, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_zero_items_per_page() {
+        assert!(Paginator::paginate(&reference("blog/index"), &[], 0).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BuildConfig {
+    /// Put rendered documents into feed entries, not just their descriptions
+    pub feed_full_content: Option<bool>,
+    pub feeds: Option<bool>,
+    pub llms_txt: Option<bool>,
+    pub public_path: Option<String>,
+    pub sitemap: Option<bool>,
+}
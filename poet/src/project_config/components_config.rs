@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Shortcodes that take over rendering Markdown elements. Nothing is taken
+/// over unless configured, so shortcodes named like the elements stay
+/// regular components.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComponentsConfig {
+    /// Receives `kind` and `title` props, and the evaluated alert contents
+    pub admonition: Option<String>,
+    /// Places the rendered endnotes, for example leaving them to the layout
+    pub endnotes: Option<String>,
+    /// Receives `display` and `source` props, and the rendered MathML
+    pub math: Option<String>,
+    /// Receives `align`, `header` and `rows` props, and the rendered table
+    pub table: Option<String>,
+}
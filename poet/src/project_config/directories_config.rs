@@ -0,0 +1,74 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::filesystem::file_entry_kind::FileEntryKind;
+
+/// Where the project files are, relative to the project root
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DirectoriesConfig {
+    pub authors: PathBuf,
+    pub content: PathBuf,
+    pub prompts: PathBuf,
+    pub shortcodes: PathBuf,
+}
+
+impl DirectoriesConfig {
+    pub fn all(&self) -> [&PathBuf; 4] {
+        [
+            &self.authors,
+            &self.content,
+            &self.prompts,
+            &self.shortcodes,
+        ]
+    }
+
+    pub fn file_entry_kind(&self, relative_path: &Path, extension: &str) -> FileEntryKind {
+        match extension {
+            "toml" if relative_path.starts_with(&self.authors) => FileEntryKind::Author,
+            "md" if relative_path.starts_with(&self.content) => FileEntryKind::Content,
+            "md" if relative_path.starts_with(&self.prompts) => FileEntryKind::Prompt,
+            "rhai" if relative_path.starts_with(&self.shortcodes) => FileEntryKind::Shortcode,
+            _ => FileEntryKind::Other,
+        }
+    }
+}
+
+impl Default for DirectoriesConfig {
+    fn default() -> Self {
+        Self {
+            authors: PathBuf::from("authors"),
+            content: PathBuf::from("content"),
+            prompts: PathBuf::from("prompts"),
+            shortcodes: PathBuf::from("shortcodes"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_files_by_configured_directories() {
+        let directories = DirectoriesConfig {
+            content: PathBuf::from("docs/pages"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            directories.file_entry_kind(Path::new("docs/pages/intro.md"), "md"),
+            FileEntryKind::Content
+        );
+        assert_eq!(
+            directories.file_entry_kind(Path::new("content/intro.md"), "md"),
+            FileEntryKind::Other
+        );
+        assert_eq!(
+            directories.file_entry_kind(Path::new("shortcodes/Note.rhai"), "rhai"),
+            FileEntryKind::Shortcode
+        );
+    }
+}
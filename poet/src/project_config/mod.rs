@@ -0,0 +1,183 @@
+pub mod build_config;
+pub mod components_config;
+pub mod directories_config;
+pub mod server_config;
+pub mod site_config;
+pub mod taxonomy_config;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Context as _;
+use anyhow::Result;
+use anyhow::anyhow;
+use serde::Deserialize;
+use tokio::fs;
+
+use self::build_config::BuildConfig;
+use self::components_config::ComponentsConfig;
+use self::directories_config::DirectoriesConfig;
+use self::server_config::ServerConfig;
+use self::site_config::SiteConfig;
+use self::taxonomy_config::TaxonomyConfig;
+use crate::content_document_front_matter::ContentDocumentFrontMatter;
+use crate::serde_struct_fields::serde_struct_fields;
+
+pub const PROJECT_CONFIG_FILE_NAME: &str = "poet.toml";
+
+/// Contents of `poet.toml` in the project root. Every setting is optional,
+/// and command line flags take precedence over it.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub build: BuildConfig,
+    pub components: ComponentsConfig,
+    pub directories: DirectoriesConfig,
+    pub server: ServerConfig,
+    pub site: SiteConfig,
+    pub taxonomies: BTreeMap<String, TaxonomyConfig>,
+}
+
+impl ProjectConfig {
+    pub async fn load(project_directory: &Path) -> Result<Self> {
+        let config_path = project_directory.join(PROJECT_CONFIG_FILE_NAME);
+
+        if !config_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&config_path)
+            .await
+            .context(format!("Failed to read file: {}", config_path.display()))?;
+
+        let config: Self = toml::from_str(&contents)
+            .context(format!("Failed to parse file: {}", config_path.display()))?;
+
+        // Terms are read from front matter keys named after the taxonomy
+        let front_matter_fields = serde_struct_fields::<ContentDocumentFrontMatter>();
+
+        if let Some(taxonomy_name) = config
+            .taxonomies
+            .keys()
+            .find(|taxonomy_name| front_matter_fields.contains(&taxonomy_name.as_str()))
+        {
+            return Err(anyhow!(
+                "Taxonomy name is already used by a front matter field: '{taxonomy_name}'"
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Generated pages are linked relative to the site's base URL, unless
+    /// the build specifies otherwise.
+    pub fn public_path(&self) -> Option<String> {
+        self.build
+            .public_path
+            .clone()
+            .or_else(|| self.site.base_url.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indoc::indoc;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn defaults_when_config_file_is_missing() -> Result<()> {
+        let project_directory = tempdir()?;
+        let config = ProjectConfig::load(project_directory.path()).await?;
+
+        assert_eq!(config.components.table, None);
+        assert_eq!(config.directories.content, PathBuf::from("content"));
+        assert_eq!(config.server.assets_path, "assets");
+        assert_eq!(config.server.mcp_path, "/mcp/streamable");
+        assert_eq!(config.public_path(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reads_config_file_from_project_directory() -> Result<()> {
+        let project_directory = tempdir()?;
+
+        fs::write(
+            project_directory.path().join(PROJECT_CONFIG_FILE_NAME),
+            indoc! {r#"
+                [components]
+                admonition = "Callout"
+                endnotes = "Notes"
+                math = "Formula"
+                table = "DataTable"
+
+                [directories]
+                content = "pages"
+
+                [server]
+                addr = "127.0.0.1:9000"
+
+                [site]
+                base_url = "https://example.com/"
+                title = "Example"
+            "#},
+        )
+        .await?;
+
+        let config = ProjectConfig::load(project_directory.path()).await?;
+
+        assert_eq!(config.components.admonition.as_deref(), Some("Callout"));
+        assert_eq!(config.components.endnotes.as_deref(), Some("Notes"));
+        assert_eq!(config.components.math.as_deref(), Some("Formula"));
+        assert_eq!(config.components.table.as_deref(), Some("DataTable"));
+        assert_eq!(config.directories.content, PathBuf::from("pages"));
+        assert_eq!(config.directories.prompts, PathBuf::from("prompts"));
+        assert_eq!(config.server.addr, Some("127.0.0.1:9000".parse()?));
+        assert_eq!(config.site.title.as_deref(), Some("Example"));
+        assert_eq!(
+            config.public_path().as_deref(),
+            Some("https://example.com/")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_settings() -> Result<()> {
+        let project_directory = tempdir()?;
+
+        fs::write(
+            project_directory.path().join(PROJECT_CONFIG_FILE_NAME),
+            "[site]\nname = \"Example\"\n",
+        )
+        .await?;
+
+        assert!(ProjectConfig::load(project_directory.path()).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_taxonomies_named_like_front_matter_fields() -> Result<()> {
+        let project_directory = tempdir()?;
+
+        fs::write(
+            project_directory.path().join(PROJECT_CONFIG_FILE_NAME),
+            "[taxonomies.authors]\n",
+        )
+        .await?;
+
+        assert!(
+            ProjectConfig::load(project_directory.path())
+                .await
+                .is_err_and(|err| err.to_string()
+                    == "Taxonomy name is already used by a front matter field: 'authors'")
+        );
+
+        Ok(())
+    }
+}
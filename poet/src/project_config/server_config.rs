@@ -0,0 +1,28 @@
+use std::net::SocketAddr;
+
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub addr: Option<SocketAddr>,
+    /// Both the URL path assets are served from, and the directory they are
+    /// served from, relative to the project root
+    pub assets_path: String,
+    pub mcp_path: String,
+    /// Name the watch server introduces itself with to MCP clients
+    pub name: String,
+    pub title: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            addr: None,
+            assets_path: "assets".to_string(),
+            mcp_path: "/mcp/streamable".to_string(),
+            name: "poet".to_string(),
+            title: "Poet".to_string(),
+        }
+    }
+}
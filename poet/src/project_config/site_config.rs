@@ -0,0 +1,70 @@
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::TypeBuilder;
+use serde::Deserialize;
+
+use crate::rhai_helpers::optional_string_to_dynamic;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SiteConfig {
+    pub base_url: Option<String>,
+    pub description: Option<String>,
+    pub title: Option<String>,
+}
+
+impl SiteConfig {
+    fn rhai_base_url(&mut self) -> Dynamic {
+        optional_string_to_dynamic(&self.base_url)
+    }
+
+    fn rhai_description(&mut self) -> Dynamic {
+        optional_string_to_dynamic(&self.description)
+    }
+
+    fn rhai_title(&mut self) -> Dynamic {
+        optional_string_to_dynamic(&self.title)
+    }
+}
+
+impl CustomType for SiteConfig {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("SiteConfig")
+            .with_get("base_url", Self::rhai_base_url)
+            .with_get("description", Self::rhai_description)
+            .with_get("title", Self::rhai_title);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rhai::Engine;
+    use rhai::Scope;
+
+    use super::*;
+
+    #[test]
+    fn exposes_missing_values_as_unit_in_rhai() -> Result<()> {
+        let mut engine = Engine::new();
+        let mut scope = Scope::new();
+
+        engine.build_type::<SiteConfig>();
+        scope.push(
+            "site",
+            SiteConfig {
+                title: Some("Example".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            engine.eval_with_scope::<String>(&mut scope, "site.title")?,
+            "Example"
+        );
+        assert!(engine.eval_with_scope::<bool>(&mut scope, "site.base_url == ()")?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaxonomyConfig {
+    /// Shortcode that renders the listing page of every term. Taxonomies
+    /// without a layout can still be used in templates and search.
+    pub layout: Option<String>,
+}
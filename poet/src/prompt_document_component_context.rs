@@ -13,11 +13,26 @@ use rhai::TypeBuilder;
 
 use crate::asset_manager::AssetManager;
 use crate::content_document_linker::ContentDocumentLinker;
+use crate::link_definitions::LinkDefinitions;
+use crate::markdown_component_context::MarkdownComponentContext;
 use crate::mcp::jsonrpc::role::Role;
 use crate::mcp::prompt_message::PromptMessage;
 use crate::prompt_document_front_matter::PromptDocumentFrontMatter;
 use crate::prompt_document_front_matter::argument_with_input::ArgumentWithInput;
 
+fn trim_chunk(chunk: String) -> Result<String> {
+    if chunk.is_empty() {
+        return Ok(chunk);
+    }
+
+    Ok(chunk
+        .trim()
+        .strip_prefix(':')
+        .ok_or_else(|| anyhow!("Unable to strip chunk prefix from '{chunk}'"))?
+        .trim_start()
+        .to_string())
+}
+
 #[derive(Clone)]
 pub struct PromptDocumentComponentContext {
     pub arguments: HashMap<String, ArgumentWithInput>,
@@ -25,6 +40,7 @@ pub struct PromptDocumentComponentContext {
     pub content_document_linker: ContentDocumentLinker,
     pub current_role: Option<Role>,
     pub front_matter: PromptDocumentFrontMatter,
+    pub link_definitions: LinkDefinitions,
     pub prompt_messages: Vec<PromptMessage>,
     pub unprocessed_message_chunk: Arc<RwLock<String>>,
 }
@@ -140,6 +156,36 @@ impl CustomType for PromptDocumentComponentContext {
     }
 }
 
+/// Every top-level block continues the message of the current role, and
+/// a leading strong text switches to another role.
+impl MarkdownComponentContext for PromptDocumentComponentContext {
+    fn asset_manager(&self) -> &AssetManager {
+        &self.asset_manager
+    }
+
+    fn content_document_linker(&self) -> &ContentDocumentLinker {
+        &self.content_document_linker
+    }
+
+    fn link_definitions(&self) -> &LinkDefinitions {
+        &self.link_definitions
+    }
+
+    fn append_block(&mut self, block: String) -> Result<()> {
+        self.append_to_message(trim_chunk(block)?)
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.flush()
+    }
+
+    fn take_leading_strong(&mut self, text: &str) -> Result<bool> {
+        self.switch_role_to(text.try_into()?)?;
+
+        Ok(true)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use esbuild_metafile::EsbuildMetaFile;
@@ -164,6 +210,7 @@ mod tests {
         ContentDocumentLinker {
             content_document_basename_by_id: Arc::new(HashMap::new()),
             content_document_by_basename: Arc::new(content_document_by_basename),
+            heading_ids_by_basename: Arc::new(HashMap::new()),
         }
     }
 
@@ -183,11 +230,34 @@ mod tests {
                 description: "description".to_string(),
                 title: "title".to_string(),
             },
+            link_definitions: Default::default(),
             prompt_messages: Vec::new(),
             unprocessed_message_chunk: Arc::new(RwLock::new(String::new())),
         }
     }
 
+    #[test]
+    fn test_chunk_trim() -> Result<()> {
+        assert_eq!(
+            trim_chunk(
+                r#"
+                : foo bar
+            "#
+                .to_string()
+            )?,
+            "foo bar".to_string(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_trim_empty() -> Result<()> {
+        assert_eq!(trim_chunk("".to_string())?, "".to_string(),);
+
+        Ok(())
+    }
+
     #[test]
     fn flush_fails_when_chunk_present_without_role() -> Result<()> {
         let mut context = context();
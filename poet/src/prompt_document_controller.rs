@@ -9,8 +9,12 @@ use rhai_components::rhai_template_renderer::RhaiTemplateRenderer;
 use crate::asset_manager::AssetManager;
 use crate::asset_path_renderer::AssetPathRenderer;
 use crate::content_document_linker::ContentDocumentLinker;
-use crate::eval_prompt_document_mdast::eval_prompt_document_mdast;
-use crate::eval_prompt_document_mdast_params::EvalPromptDocumentMdastParams;
+use crate::diagnostic::diagnostic_code::DiagnosticCode;
+use crate::document_error::DocumentError;
+use crate::eval_markdown_mdast::eval_markdown_mdast;
+use crate::eval_markdown_mdast_params::EvalMarkdownMdastParams;
+use crate::filesystem::file_entry::FileEntry;
+use crate::find_link_definitions_in_mdast::find_link_definitions_in_mdast;
 use crate::mcp::jsonrpc::request::prompts_get::PromptsGet;
 use crate::mcp::jsonrpc::request::prompts_get::PromptsGetParams;
 use crate::mcp::jsonrpc::response::success::prompts_get_result::PromptsGetResult;
@@ -25,6 +29,7 @@ pub struct PromptDocumentController {
     pub asset_path_renderer: AssetPathRenderer,
     pub content_document_linker: ContentDocumentLinker,
     pub esbuild_metafile: Arc<EsbuildMetaFile>,
+    pub file_entry: FileEntry,
     pub front_matter: PromptDocumentFrontMatter,
     pub name: String,
     pub mdast: Node,
@@ -78,12 +83,13 @@ impl PromptController for PromptDocumentController {
             content_document_linker: self.content_document_linker.clone(),
             current_role: Default::default(),
             front_matter: self.front_matter.clone(),
+            link_definitions: find_link_definitions_in_mdast(&self.mdast),
             prompt_messages: Default::default(),
             unprocessed_message_chunk: Default::default(),
         };
 
-        eval_prompt_document_mdast(
-            EvalPromptDocumentMdastParams {
+        eval_markdown_mdast(
+            EvalMarkdownMdastParams {
                 mdast: &self.mdast,
                 is_directly_in_root: false,
                 is_first_child: false,
@@ -91,7 +97,13 @@ impl PromptController for PromptDocumentController {
                 rhai_template_renderer: &self.rhai_template_renderer,
             },
             &mut prompt_document_component_context,
-        )?;
+        )
+        .map_err(|err| DocumentError {
+            basename: self.name.clone(),
+            code: DiagnosticCode::RenderFailed,
+            err,
+            source_file: Some(self.file_entry.clone()),
+        })?;
 
         Ok(PromptsGetResult {
             description: Some(self.front_matter.description.clone()),
@@ -116,6 +128,7 @@ mod tests {
     use crate::mcp::jsonrpc::role::Role;
     use crate::mcp::prompt_message::PromptMessage;
     use crate::rhai_template_renderer_factory::RhaiTemplateRendererFactory;
+    use crate::string_to_mdast::string_to_mdast;
 
     fn build_controller() -> Result<PromptDocumentController> {
         let contents: String = indoc! {r#"
@@ -223,4 +236,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn keeps_the_document_error_when_rendering_fails() -> Result<()> {
+        let mut prompt_controller = build_controller()?;
+
+        prompt_controller.mdast = string_to_mdast("**user**: {missing_function()}\n")?;
+
+        let outcome = prompt_controller
+            .respond_to(PromptsGet {
+                id: "1".into(),
+                jsonrpc: JSONRPC_VERSION.to_string(),
+                params: PromptsGetParams {
+                    arguments: HashMap::from([(
+                        "objective".to_string(),
+                        "ride a horse".to_string(),
+                    )]),
+                    meta: None,
+                    name: "help-me-finish-task".to_string(),
+                },
+            })
+            .await;
+
+        assert!(outcome.is_err_and(|err| {
+            err.downcast_ref::<DocumentError>()
+                .is_some_and(|document_error| {
+                    document_error.basename == "help-me-finish-task"
+                        && document_error.code == DiagnosticCode::RenderFailed
+                })
+        }));
+
+        Ok(())
+    }
 }
@@ -67,6 +67,7 @@ mod tests {
         let directory = tempdir()?;
         let storage = Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         storage
@@ -88,6 +89,7 @@ mod tests {
         let directory = tempdir()?;
         let storage = Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         let metafile = read_esbuild_metafile_or_default(Arc::new(storage)).await?;
@@ -102,6 +104,7 @@ mod tests {
         let directory = tempdir()?;
         let storage = Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         };
 
         std::fs::create_dir(directory.path().join("esbuild-meta.json"))?;
@@ -0,0 +1,49 @@
+/// Renders the lines leading up to `line`, with a caret under `column`.
+pub fn render_code_frame(contents: &str, line: usize, column: usize) -> String {
+    let first_line = line.saturating_sub(2).max(1);
+    let gutter_width = line.to_string().len();
+    let mut result = String::new();
+
+    for (index, source_line) in contents
+        .lines()
+        .enumerate()
+        .skip(first_line - 1)
+        .take(line + 1 - first_line)
+    {
+        let line_number = index + 1;
+        let marker = if line_number == line { '>' } else { ' ' };
+
+        result.push_str(&format!(
+            "{marker} {line_number:>gutter_width$} | {source_line}\n"
+        ));
+    }
+
+    result.push_str(&format!(
+        "  {:>gutter_width$} | {}^\n",
+        "",
+        " ".repeat(column.saturating_sub(1))
+    ));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_column_below_preceding_lines() {
+        assert_eq!(
+            render_code_frame("first\nsecond\nthird {oops}\nfourth", 3, 7),
+            "  1 | first\n  2 | second\n> 3 | third {oops}\n    |       ^\n"
+        );
+    }
+
+    #[test]
+    fn starts_at_the_first_line() {
+        assert_eq!(
+            render_code_frame("{oops}\nnext", 1, 1),
+            "> 1 | {oops}\n    | ^\n"
+        );
+    }
+}
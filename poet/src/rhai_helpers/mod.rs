@@ -1,3 +1,5 @@
+mod optional_string_to_dynamic;
 mod render_hierarchy;
 
+pub use self::optional_string_to_dynamic::optional_string_to_dynamic;
 pub use self::render_hierarchy::render_hierarchy;
@@ -0,0 +1,10 @@
+use rhai::Dynamic;
+
+/// Missing values are `()` in Rhai, so templates can test them with
+/// `if value != () { ... }`.
+pub fn optional_string_to_dynamic(value: &Option<String>) -> Dynamic {
+    match value {
+        Some(value) => Dynamic::from(value.clone()),
+        None => Dynamic::UNIT,
+    }
+}
@@ -21,12 +21,20 @@ use crate::content_document_hierarchy::ContentDocumentHierarchy;
 use crate::content_document_reference::ContentDocumentReference;
 use crate::content_document_tree_node::ContentDocumentTreeNode;
 use crate::filesystem::file_entry::FileEntry;
+use crate::footnotes::Footnotes;
+use crate::footnotes::footnote::Footnote;
+use crate::paginator::Paginator;
+use crate::project_config::site_config::SiteConfig;
 use crate::prompt_document_component_context::PromptDocumentComponentContext;
 use crate::prompt_document_front_matter::PromptDocumentFrontMatter;
 use crate::prompt_document_front_matter::argument_with_input::ArgumentWithInput;
 use crate::rhai_helpers::render_hierarchy;
+use crate::seo::Seo;
 use crate::table_of_contents::TableOfContents;
 use crate::table_of_contents::heading::Heading;
+use crate::taxonomy::Taxonomy;
+use crate::taxonomy_term::TaxonomyTerm;
+use crate::taxonomy_term_component_context::TaxonomyTermComponentContext;
 
 pub struct RhaiTemplateRendererFactory {
     base_directory: PathBuf,
@@ -48,8 +56,8 @@ impl RhaiTemplateRendererFactory {
 
         self.component_registry
             .register_component(ComponentReference {
-                name: component_name.clone(),
-                path: component_name,
+                name: component_name,
+                path: file_entry.relative_path.display().to_string(),
             });
     }
 }
@@ -76,10 +84,18 @@ impl BuildsEngine for RhaiTemplateRendererFactory {
         engine.build_type::<ContentDocumentReference>();
         engine.build_type::<ContentDocumentTreeNode>();
         engine.build_type::<FileEntry>();
+        engine.build_type::<Footnote>();
+        engine.build_type::<Footnotes>();
         engine.build_type::<Heading>();
+        engine.build_type::<Paginator>();
         engine.build_type::<PromptDocumentComponentContext>();
         engine.build_type::<PromptDocumentFrontMatter>();
+        engine.build_type::<Seo>();
+        engine.build_type::<SiteConfig>();
         engine.build_type::<TableOfContents>();
+        engine.build_type::<Taxonomy>();
+        engine.build_type::<TaxonomyTerm>();
+        engine.build_type::<TaxonomyTermComponentContext>();
 
         engine.register_fn("render_hierarchy", render_hierarchy);
 
@@ -4,17 +4,25 @@ use std::sync::RwLock;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use chrono::Utc;
+use markdown::mdast::Node;
 use rayon::iter::IntoParallelRefIterator as _;
 use rayon::iter::ParallelIterator as _;
+use slug::slugify;
 use tantivy::Index;
 use tantivy::IndexReader;
 use tantivy::IndexWriter;
 use tantivy::ReloadPolicy;
+use tantivy::schema::Facet;
 
 use crate::anyhow_error_aggregate::AnyhowErrorAggregate;
+use crate::code_tokenizer::CODE_TOKENIZER_NAME;
+use crate::code_tokenizer::CodeTokenizer;
 use crate::content_document_basename::ContentDocumentBasename;
+use crate::content_document_reference::ContentDocumentReference;
 use crate::content_document_source::ContentDocumentSource;
-use crate::mdast_to_tantivy_document::mdast_to_tantivy_document;
+use crate::find_heading_ids_in_mdast::find_heading_ids_in_mdast;
+use crate::mdast_to_tantivy_documents::mdast_to_tantivy_documents;
 use crate::search_index_fields::SearchIndexFields;
 use crate::search_index_reader::SearchIndexReader;
 use crate::search_index_schema::SearchIndexSchema;
@@ -33,6 +41,10 @@ impl SearchIndex {
 
         let index = Index::create_in_ram(schema.clone());
 
+        index
+            .tokenizers()
+            .register(CODE_TOKENIZER_NAME, CodeTokenizer::text_analyzer());
+
         Self {
             fields: Arc::new(fields),
             index,
@@ -40,35 +52,74 @@ impl SearchIndex {
         }
     }
 
+    /// Adds one Tantivy document per section. Title and description only go
+    /// to the first one, so matching them does not return every section.
+    fn index_document(
+        &self,
+        index_writer: &RwLock<IndexWriter>,
+        mdast: &Node,
+        reference: &ContentDocumentReference,
+    ) -> Result<()> {
+        let basename_string: String = reference.basename().to_string();
+        let heading_ids = find_heading_ids_in_mdast(mdast)?;
+
+        for (section_index, mut document) in
+            mdast_to_tantivy_documents(self.fields.clone(), &heading_ids, mdast)?
+                .into_iter()
+                .enumerate()
+        {
+            document.add_field_value(self.fields.basename, &basename_string);
+
+            if section_index == 0 {
+                document.add_field_value(self.fields.title, &reference.front_matter.title);
+                document
+                    .add_field_value(self.fields.description, &reference.front_matter.description);
+            }
+
+            for (taxonomy_name, term_names) in &reference.front_matter.taxonomies {
+                for term_name in term_names {
+                    document.add_facet(
+                        self.fields.taxonomies,
+                        Facet::from_path([taxonomy_name.clone(), slugify(term_name)]),
+                    );
+                }
+            }
+
+            index_writer
+                .read()
+                .expect("Search index read lock is poisoned")
+                .add_document(document)?;
+        }
+
+        Ok(())
+    }
+
     pub fn index(self) -> Result<SearchIndexReader> {
         let error_collection: AnyhowErrorAggregate = Default::default();
-        let fields = self.fields.clone();
+        let now = Utc::now();
         let index_writer: Arc<RwLock<IndexWriter>> =
             Arc::new(RwLock::new(self.index.writer(50_000_000)?));
 
-        self.content_document_sources.par_iter().for_each(
-            |(
-                _key,
-                ContentDocumentSource {
-                    mdast, reference, ..
+        // drafts are only built for previews, they are not searchable
+        self.content_document_sources
+            .par_iter()
+            .filter(|(_, ContentDocumentSource { reference, .. })| {
+                !reference.front_matter.is_draft_at(now)
+            })
+            .for_each(
+                |(
+                    _key,
+                    ContentDocumentSource {
+                        mdast, reference, ..
+                    },
+                )| {
+                    let basename_string: String = reference.basename().to_string();
+
+                    if let Err(err) = self.index_document(&index_writer, mdast, reference) {
+                        error_collection.errors.insert(basename_string, err);
+                    }
                 },
-            )| {
-                let basename_string: String = reference.basename().to_string();
-                let mut document = mdast_to_tantivy_document(fields.clone(), mdast);
-
-                document.add_field_value(fields.basename, &basename_string);
-                document.add_field_value(fields.title, &reference.front_matter.title);
-                document.add_field_value(fields.description, &reference.front_matter.description);
-
-                if let Err(err) = index_writer
-                    .read()
-                    .expect("Search index read lock is poisoned")
-                    .add_document(document)
-                {
-                    error_collection.errors.insert(basename_string, err.into());
-                }
-            },
-        );
+            );
 
         if !error_collection.errors.is_empty() {
             return Err(anyhow!("{error_collection}"));
@@ -115,6 +166,7 @@ mod tests {
         let public_path: String = "https://example.com".to_string();
         let source_filesystem = Arc::new(Storage {
             base_directory: env!("CARGO_MANIFEST_DIR").into(),
+            directories: Default::default(),
         });
         let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
         let authors = build_authors(source_filesystem.clone()).await?;
@@ -124,16 +176,80 @@ mod tests {
                 base_path: public_path.clone(),
             },
             authors,
+            components: Default::default(),
             esbuild_metafile: Default::default(),
+            feed_full_content: false,
             generated_page_base_path: public_path,
+            generate_feeds: false,
+            generate_llms_txt: false,
             generate_sitemap: false,
+            include_drafts: false,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: Default::default(),
             source_filesystem,
+            taxonomies: Default::default(),
         })
         .await
     }
 
+    async fn index_with(
+        files: &[(&str, &str)],
+        configure: impl FnOnce(&mut BuildProjectParams),
+    ) -> Result<SearchIndexReader> {
+        let directory = tempdir()?;
+        let source_filesystem = Arc::new(Storage {
+            base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
+        });
+
+        source_filesystem
+            .set_file_contents(
+                Path::new("shortcodes/Layout.rhai"),
+                "fn template(context, props, content) { component { <html>{content}</html> } }",
+            )
+            .await?;
+
+        for (relative_path, contents) in files {
+            source_filesystem
+                .set_file_contents(Path::new(relative_path), contents)
+                .await?;
+        }
+
+        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
+        let authors = build_authors(source_filesystem.clone()).await?;
+        let mut params = BuildProjectParams {
+            asset_path_renderer: AssetPathRenderer {
+                base_path: "/".to_string(),
+            },
+            authors,
+            components: Default::default(),
+            esbuild_metafile: Default::default(),
+            feed_full_content: false,
+            generated_page_base_path: "/".to_string(),
+            generate_feeds: false,
+            generate_llms_txt: false,
+            generate_sitemap: false,
+            include_drafts: false,
+            is_watching: false,
+            previous_build_project_result: None,
+            rhai_template_renderer,
+            site: Default::default(),
+            source_filesystem,
+            taxonomies: Default::default(),
+        };
+
+        configure(&mut params);
+
+        let BuildProjectResultStub {
+            content_document_sources,
+            ..
+        } = build_project(params).await?;
+
+        SearchIndex::create_in_memory(content_document_sources).index()
+    }
+
     #[tokio::test]
     async fn test_index_is_searchable() -> Result<()> {
         let BuildProjectResultStub {
@@ -146,6 +262,7 @@ mod tests {
         let results = search_index_reader.query(SearchIndexQueryParams {
             cursor: Default::default(),
             query: "test".to_string(),
+            taxonomies: Default::default(),
         })?;
 
         for result in results {
@@ -157,56 +274,155 @@ mod tests {
 
     #[tokio::test]
     async fn indexes_documents_and_finds_them_by_body_keyword() -> Result<()> {
-        let directory = tempdir()?;
-        let source_filesystem = Arc::new(Storage {
-            base_directory: directory.path().to_path_buf(),
-        });
-
-        source_filesystem
-            .set_file_contents(
-                Path::new("shortcodes/Layout.rhai"),
-                "fn template(context, props, content) { component { <html>{content}</html> } }",
-            )
-            .await?;
-        source_filesystem
-            .set_file_contents(
-                Path::new("content/guide.md"),
+        let search_index_reader = index_with(
+            &[(
+                "content/guide.md",
                 "+++\ndescription = \"Guide description\"\nlayout = \"Layout\"\ntitle = \"Searchable Guide\"\n+++\n\nUnique body keyword zebra.\n",
-            )
-            .await?;
+            )],
+            |_| {},
+        )
+        .await?;
 
-        let rhai_template_renderer = compile_shortcodes(source_filesystem.clone()).await?;
-        let authors = build_authors(source_filesystem.clone()).await?;
+        let results = search_index_reader.query(SearchIndexQueryParams {
+            cursor: Default::default(),
+            query: "zebra".to_string(),
+            taxonomies: Default::default(),
+        })?;
 
-        let BuildProjectResultStub {
-            content_document_sources,
-            ..
-        } = build_project(BuildProjectParams {
-            asset_path_renderer: AssetPathRenderer {
-                base_path: "/".to_string(),
-            },
-            authors,
-            esbuild_metafile: Default::default(),
-            generated_page_base_path: "/".to_string(),
-            generate_sitemap: false,
-            is_watching: false,
-            rhai_template_renderer,
-            source_filesystem,
-        })
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].content_document_reference.front_matter.title,
+            "Searchable Guide"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn leaves_drafts_out_of_the_index() -> Result<()> {
+        let search_index_reader = index_with(
+            &[(
+                "content/guide.md",
+                "+++\ndescription = \"Guide description\"\ndraft = true\nlayout = \"Layout\"\ntitle = \"Searchable Guide\"\n+++\n\nUnique body keyword zebra.\n",
+            )],
+            |params| params.include_drafts = true,
+        )
         .await?;
 
-        let search_index_reader =
-            SearchIndex::create_in_memory(content_document_sources).index()?;
+        let results = search_index_reader.query(SearchIndexQueryParams {
+            cursor: Default::default(),
+            query: "zebra".to_string(),
+            taxonomies: Default::default(),
+        })?;
+
+        assert!(results.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn points_found_documents_at_the_matching_section() -> Result<()> {
+        let search_index_reader = index_with(
+            &[(
+                "content/guide.md",
+                "+++\ndescription = \"Guide description\"\nlayout = \"Layout\"\ntitle = \"Searchable Guide\"\n+++\n\nIntro about horses.\n\n## Stripes\n\nUnique body keyword zebra.\n\n## Spots\n\nLeopards.\n",
+            )],
+            |_| {},
+        )
+        .await?;
 
         let results = search_index_reader.query(SearchIndexQueryParams {
             cursor: Default::default(),
             query: "zebra".to_string(),
+            taxonomies: Default::default(),
+        })?;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].heading_id, Some("stripes".to_string()));
+        assert!(results[0].score > 0.0);
+        assert_eq!(
+            results[0].snippet,
+            Some("Unique body keyword <b>zebra</b>".to_string())
+        );
+
+        let results = search_index_reader.query(SearchIndexQueryParams {
+            cursor: Default::default(),
+            query: "searchable".to_string(),
+            taxonomies: Default::default(),
+        })?;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].heading_id, None);
+        assert_eq!(results[0].snippet, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn finds_documents_by_code_lists_and_tables() -> Result<()> {
+        let search_index_reader = index_with(
+            &[(
+                "content/api.md",
+                "+++\ndescription = \"API\"\nlayout = \"Layout\"\ntitle = \"API\"\n+++\n\n```rust\nlet reader = SearchIndex::create_in_memory(sources);\n```\n\nCall `parseQueryString` first.\n\n- walrus\n\n| Animal |\n| - |\n| hedgehog |\n",
+            )],
+            |_| {},
+        )
+        .await?;
+
+        for query in [
+            "create_in_memory",
+            "SearchIndex",
+            "memory",
+            "query",
+            "walrus",
+            "hedgehog",
+        ] {
+            let results = search_index_reader.query(SearchIndexQueryParams {
+                cursor: Default::default(),
+                query: query.to_string(),
+                taxonomies: Default::default(),
+            })?;
+
+            assert_eq!(results.len(), 1, "nothing found for '{query}'");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filters_found_documents_by_taxonomy_terms() -> Result<()> {
+        let search_index_reader = index_with(
+            &[
+                (
+                    "content/rusty.md",
+                    "+++\ndescription = \"d\"\nlayout = \"Layout\"\ntitle = \"rusty\"\ntags = [\"Rust\"]\n+++\n\nShared keyword otter.\n",
+                ),
+                (
+                    "content/webby.md",
+                    "+++\ndescription = \"d\"\nlayout = \"Layout\"\ntitle = \"webby\"\ntags = [\"Web\"]\n+++\n\nShared keyword otter.\n",
+                ),
+            ],
+            |params| {
+                params
+                    .taxonomies
+                    .insert("tags".to_string(), Default::default());
+            },
+        )
+        .await?;
+        let mut filter = BTreeMap::new();
+
+        filter.insert("tags".to_string(), vec!["rust".to_string()]);
+
+        let results = search_index_reader.query(SearchIndexQueryParams {
+            cursor: Default::default(),
+            query: "otter".to_string(),
+            taxonomies: filter,
         })?;
 
         assert_eq!(results.len(), 1);
         assert_eq!(
             results[0].content_document_reference.front_matter.title,
-            "Searchable Guide"
+            "rusty"
         );
 
         Ok(())
@@ -2,8 +2,14 @@ use tantivy::schema::Field;
 
 pub struct SearchIndexFields {
     pub basename: Field,
+    pub callout: Field,
+    pub code: Field,
     pub description: Field,
     pub header: Field,
+    pub heading_id: Field,
+    pub list: Field,
     pub paragraph: Field,
+    pub table: Field,
+    pub taxonomies: Field,
     pub title: Field,
 }
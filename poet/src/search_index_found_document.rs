@@ -3,4 +3,9 @@ use crate::content_document_reference::ContentDocumentReference;
 #[derive(Clone, Debug)]
 pub struct SearchIndexFoundDocument {
     pub content_document_reference: ContentDocumentReference,
+    /// Nearest heading above the matching section, if there is one
+    pub heading_id: Option<String>,
+    pub score: f32,
+    /// Matching fragment of the section, with matched terms in `<b>` tags
+    pub snippet: Option<String>,
 }
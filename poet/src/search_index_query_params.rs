@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use crate::mcp::list_resources_cursor::ListResourcesCursor;
 
 pub struct SearchIndexQueryParams {
     pub cursor: ListResourcesCursor,
     pub query: String,
+    /// Found documents have to use every one of these terms
+    pub taxonomies: BTreeMap<String, Vec<String>>,
 }
@@ -3,12 +3,21 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use slug::slugify;
 use tantivy::Index;
 use tantivy::IndexReader;
 use tantivy::TantivyDocument;
+use tantivy::Term;
 use tantivy::collector::TopDocs;
+use tantivy::query::BooleanQuery;
+use tantivy::query::Occur;
+use tantivy::query::Query;
 use tantivy::query::QueryParser;
+use tantivy::query::TermQuery;
+use tantivy::schema::Facet;
+use tantivy::schema::IndexRecordOption;
 use tantivy::schema::Value as _;
+use tantivy::snippet::SnippetGenerator;
 
 use crate::content_document_basename::ContentDocumentBasename;
 use crate::content_document_source::ContentDocumentSource;
@@ -30,25 +39,69 @@ impl SearchIndexReader {
         SearchIndexQueryParams {
             cursor: ListResourcesCursor { offset, per_page },
             query,
+            taxonomies,
         }: SearchIndexQueryParams,
     ) -> Result<Vec<SearchIndexFoundDocument>> {
         let mut query_parser = QueryParser::for_index(
             &self.index,
-            vec![self.fields.title, self.fields.header, self.fields.paragraph],
+            vec![
+                self.fields.title,
+                self.fields.header,
+                self.fields.paragraph,
+                self.fields.callout,
+                self.fields.code,
+                self.fields.list,
+                self.fields.table,
+            ],
         );
 
         query_parser.set_field_boost(self.fields.title, 4.0);
         query_parser.set_field_boost(self.fields.description, 3.0);
         query_parser.set_field_boost(self.fields.header, 2.0);
+        query_parser.set_field_boost(self.fields.callout, 1.5);
+        query_parser.set_field_boost(self.fields.code, 1.5);
+        query_parser.set_field_boost(self.fields.list, 1.2);
+        query_parser.set_field_boost(self.fields.table, 1.2);
 
-        let query = query_parser.parse_query(&query)?;
+        let mut subqueries: Vec<(Occur, Box<dyn Query>)> =
+            vec![(Occur::Must, query_parser.parse_query(&query)?)];
+
+        for (taxonomy_name, term_names) in &taxonomies {
+            for term_name in term_names {
+                subqueries.push((
+                    Occur::Must,
+                    Box::new(TermQuery::new(
+                        Term::from_facet(
+                            self.fields.taxonomies,
+                            &Facet::from_path([taxonomy_name.clone(), slugify(term_name)]),
+                        ),
+                        IndexRecordOption::Basic,
+                    )),
+                ));
+            }
+        }
+
+        let query = BooleanQuery::new(subqueries);
 
         let searcher = self.index_reader.searcher();
         let results = searcher.search(&query, &TopDocs::with_limit(per_page).and_offset(offset))?;
 
+        // the first field with a match provides the snippet
+        let snippet_generators: Vec<SnippetGenerator> = [
+            self.fields.paragraph,
+            self.fields.list,
+            self.fields.table,
+            self.fields.callout,
+            self.fields.code,
+            self.fields.header,
+        ]
+        .into_iter()
+        .map(|field| SnippetGenerator::create(&searcher, &query, field))
+        .collect::<tantivy::Result<_>>()?;
+
         let mut ret = Vec::new();
 
-        for (_score, doc_address) in results {
+        for (score, doc_address) in results {
             let tantivy_document: TantivyDocument = searcher.doc::<TantivyDocument>(doc_address)?;
 
             let basename: ContentDocumentBasename = tantivy_document
@@ -64,8 +117,22 @@ impl SearchIndexReader {
                 .get(&basename)
                 .ok_or_else(|| anyhow!("There is no document with basename: '{basename}'"))?;
 
+            let heading_id: Option<String> = tantivy_document
+                .get_first(self.fields.heading_id)
+                .and_then(|value| value.as_str())
+                .map(|heading_id| heading_id.to_string());
+
+            let snippet: Option<String> = snippet_generators
+                .iter()
+                .map(|snippet_generator| snippet_generator.snippet_from_doc(&tantivy_document))
+                .find(|snippet| !snippet.is_empty())
+                .map(|snippet| snippet.to_html());
+
             ret.push(SearchIndexFoundDocument {
                 content_document_reference: reference.clone(),
+                heading_id,
+                score,
+                snippet,
             });
         }
 
@@ -1,7 +1,12 @@
+use tantivy::schema::FacetOptions;
+use tantivy::schema::IndexRecordOption;
 use tantivy::schema::STORED;
 use tantivy::schema::Schema;
 use tantivy::schema::TEXT;
+use tantivy::schema::TextFieldIndexing;
+use tantivy::schema::TextOptions;
 
+use crate::code_tokenizer::CODE_TOKENIZER_NAME;
 use crate::search_index_fields::SearchIndexFields;
 
 pub struct SearchIndexSchema {
@@ -16,17 +21,38 @@ impl Default for SearchIndexSchema {
         let basename = schema_builder.add_text_field("basename", STORED | TEXT);
         let title = schema_builder.add_text_field("title", TEXT);
         let description = schema_builder.add_text_field("description", TEXT);
-        let header = schema_builder.add_text_field("header", TEXT);
-        let paragraph = schema_builder.add_text_field("paragraph", TEXT);
+        let header = schema_builder.add_text_field("header", STORED | TEXT);
+        let paragraph = schema_builder.add_text_field("paragraph", STORED | TEXT);
+        let callout = schema_builder.add_text_field("callout", STORED | TEXT);
+        let list = schema_builder.add_text_field("list", STORED | TEXT);
+        let table = schema_builder.add_text_field("table", STORED | TEXT);
+        let code = schema_builder.add_text_field(
+            "code",
+            TextOptions::default()
+                .set_indexing_options(
+                    TextFieldIndexing::default()
+                        .set_tokenizer(CODE_TOKENIZER_NAME)
+                        .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+                )
+                .set_stored(),
+        );
+        let heading_id = schema_builder.add_text_field("heading_id", STORED);
+        let taxonomies = schema_builder.add_facet_field("taxonomies", FacetOptions::default());
 
         let schema = schema_builder.build();
 
         Self {
             fields: SearchIndexFields {
                 basename,
+                callout,
+                code,
                 description,
                 header,
+                heading_id,
+                list,
                 paragraph,
+                table,
+                taxonomies,
                 title,
             },
             schema,
@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -6,7 +9,6 @@ use serde::Serialize;
 use tokio::task::spawn_blocking;
 
 use crate::content_document_front_matter::ContentDocumentFrontMatter;
-use crate::content_document_reference::ContentDocumentReference;
 use crate::holder::Holder;
 use crate::mcp::content_block::ContentBlock;
 use crate::mcp::content_block::resource_link::ResourceLink;
@@ -20,14 +22,21 @@ use crate::mcp_resource_provider_content_documents::McpResourceProviderContentDo
 use crate::search_index_found_document::SearchIndexFoundDocument;
 use crate::search_index_query_params::SearchIndexQueryParams;
 use crate::search_index_reader_holder::SearchIndexReaderHolder;
+use crate::search_tool_found_document::SearchToolFoundDocument;
 
 #[derive(Deserialize, JsonSchema, Serialize)]
 pub struct SearchToolProviderInput {
     pub query: String,
+    /// Terms by taxonomy name, for example `{"tags": ["rust"]}`
+    #[serde(default)]
+    pub taxonomies: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Deserialize, JsonSchema, Serialize)]
-pub struct SearchToolProviderOutput {}
+pub struct SearchToolProviderOutput {
+    /// Best matching sections first
+    pub results: Vec<SearchToolFoundDocument>,
+}
 
 pub struct SearchTool {
     pub mcp_resource_provider_content_documents: McpResourceProviderContentDocuments,
@@ -47,7 +56,7 @@ impl ToolProvider for SearchTool {
 impl ToolResponder<Self> for SearchTool {
     async fn respond(
         &self,
-        SearchToolProviderInput { query }: SearchToolProviderInput,
+        SearchToolProviderInput { query, taxonomies }: SearchToolProviderInput,
     ) -> Result<ToolCallResult<SearchToolProviderOutput>> {
         match self
             .search_index_reader_holder
@@ -58,33 +67,57 @@ impl ToolResponder<Self> for SearchTool {
                         search_index_reader.query(SearchIndexQueryParams {
                             cursor: Default::default(),
                             query,
+                            taxonomies,
                         })
                     })
                     .await??;
 
+                let mut content: Vec<ContentBlock> = Vec::new();
+                let mut results: Vec<SearchToolFoundDocument> = Vec::new();
+
+                for SearchIndexFoundDocument {
+                    content_document_reference,
+                    heading_id,
+                    score,
+                    snippet,
+                } in search_index_found_documents
+                {
+                    let ContentDocumentFrontMatter {
+                        description,
+                        title,
+                        ..
+                    } = &content_document_reference.front_matter;
+                    let canonical_link = content_document_reference
+                        .canonical_link()
+                        .map_err(|err| anyhow!(err))?;
+                    let uri = self
+                        .mcp_resource_provider_content_documents
+                        .resource_uri(&content_document_reference.basename().to_string());
+
+                    content.push(ContentBlock::ResourceLink(ResourceLink {
+                        description: Some(description.to_string()),
+                        mime_type: Some("text/markdown".to_string()),
+                        name: title.to_string(),
+                        title: Some(title.to_string()),
+                        uri: uri.clone(),
+                    }));
+                    results.push(SearchToolFoundDocument {
+                        description: description.to_string(),
+                        link: match &heading_id {
+                            Some(heading_id) => format!("{canonical_link}#{heading_id}"),
+                            None => canonical_link,
+                        },
+                        heading_id,
+                        score,
+                        snippet,
+                        title: title.to_string(),
+                        uri,
+                    });
+                }
+
                 Ok(ToolCallResult::Success(Success {
-                    content: search_index_found_documents
-                        .iter()
-                        .map(|SearchIndexFoundDocument {
-                            content_document_reference: content_document_reference @ ContentDocumentReference {
-                                front_matter: ContentDocumentFrontMatter {
-                                    description,
-                                    title,
-                                    ..
-                                },
-                                ..
-                            }
-                        }| ContentBlock::ResourceLink(ResourceLink {
-                            description: Some(description.to_string()),
-                            mime_type: Some("text/markdown".to_string()),
-                            name: title.to_string(),
-                            title: Some(title.to_string()),
-                            uri: self.mcp_resource_provider_content_documents.resource_uri(&content_document_reference.basename().to_string()),
-                        }))
-                        .collect()
-                    ,
-                    structured_content: SearchToolProviderOutput {
-                    }
+                    content,
+                    structured_content: SearchToolProviderOutput { results },
                 }))
             },
             None => Ok(
@@ -125,6 +158,7 @@ mod tests {
         let directory = tempdir()?;
         let source_filesystem = Arc::new(Storage {
             base_directory: directory.path().to_path_buf(),
+            directories: Default::default(),
         });
 
         source_filesystem
@@ -136,7 +170,7 @@ mod tests {
         source_filesystem
             .set_file_contents(
                 Path::new("content/guide.md"),
-                "+++\ndescription = \"Guide\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\nkeyword zebra body\n",
+                "+++\ndescription = \"Guide\"\nlayout = \"Layout\"\ntitle = \"Guide\"\n+++\n\nIntro\n\n## Habitat\n\nkeyword zebra body\n",
             )
             .await?;
 
@@ -151,12 +185,20 @@ mod tests {
                 base_path: "/".to_string(),
             },
             authors,
+            components: Default::default(),
             esbuild_metafile: Default::default(),
+            feed_full_content: false,
             generated_page_base_path: "/".to_string(),
+            generate_feeds: false,
+            generate_llms_txt: false,
             generate_sitemap: false,
+            include_drafts: false,
             is_watching: false,
+            previous_build_project_result: None,
             rhai_template_renderer,
+            site: Default::default(),
             source_filesystem,
+            taxonomies: Default::default(),
         })
         .await?;
 
@@ -184,6 +226,7 @@ mod tests {
         let result = empty_search_tool()
             .respond(SearchToolProviderInput {
                 query: "anything".to_string(),
+                taxonomies: Default::default(),
             })
             .await?;
 
@@ -193,16 +236,29 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn responds_with_resource_links_for_matches() -> Result<()> {
+    async fn responds_with_resource_links_and_found_sections() -> Result<()> {
         let result = search_tool_with_index()
             .await?
             .respond(SearchToolProviderInput {
                 query: "zebra".to_string(),
+                taxonomies: Default::default(),
             })
             .await?;
 
         match result {
-            ToolCallResult::Success(success) => assert_eq!(success.content.len(), 1),
+            ToolCallResult::Success(success) => {
+                assert_eq!(success.content.len(), 1);
+                assert_eq!(success.structured_content.results.len(), 1);
+
+                let found_document = &success.structured_content.results[0];
+
+                assert_eq!(found_document.heading_id, Some("habitat".to_string()));
+                assert_eq!(found_document.link, "/guide/#habitat");
+                assert_eq!(
+                    found_document.snippet,
+                    Some("keyword <b>zebra</b> body".to_string())
+                );
+            }
             ToolCallResult::Failure(_) => unreachable!("expected a successful search result"),
         }
 
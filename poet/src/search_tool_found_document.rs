@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+pub struct SearchToolFoundDocument {
+    pub description: String,
+    /// Id of the nearest heading above the match, usable as a URL fragment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading_id: Option<String>,
+    /// Link to the page, pointing at the matching section
+    pub link: String,
+    pub score: f32,
+    /// Matching fragment, with matched terms in `<b>` tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    pub title: String,
+    /// Resource with the Markdown source of the document
+    pub uri: String,
+}
@@ -0,0 +1,243 @@
+use chrono::DateTime;
+use chrono::Utc;
+use rhai::CustomType;
+use rhai::EvalAltResult;
+use rhai::TypeBuilder;
+use rhai_components::escape_html_attribute::escape_html_attribute;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::author::Author;
+use crate::content_document_front_matter::seo_schema_type::SeoSchemaType;
+use crate::content_document_reference::ContentDocumentReference;
+
+fn meta_tag(attribute: &str, name: &str, content: &str) -> String {
+    format!(
+        "<meta {attribute}=\"{name}\" content=\"{}\">",
+        escape_html_attribute(content)
+    )
+}
+
+/// Closing tags inside of the JSON would end the script element early.
+fn json_ld_script(value: &Value) -> String {
+    format!(
+        "<script type=\"application/ld+json\">{}</script>",
+        value.to_string().replace("</", "<\\/")
+    )
+}
+
+/// Metadata of a document, for search engines and link previews
+#[derive(Clone)]
+pub struct Seo {
+    pub authors: Vec<Author>,
+    /// Documents from the root of the primary collection down to this one
+    pub breadcrumbs: Vec<ContentDocumentReference>,
+    pub canonical_link: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub last_updated_at: Option<DateTime<Utc>>,
+    pub noindex: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub schema_type: SeoSchemaType,
+    pub site_title: Option<String>,
+    pub title: String,
+}
+
+impl Seo {
+    pub fn render(&self) -> Result<String, String> {
+        let mut tags: Vec<String> = vec![
+            meta_tag("property", "og:type", "article"),
+            meta_tag("property", "og:title", &self.title),
+            meta_tag("property", "og:description", &self.description),
+            meta_tag("property", "og:url", &self.canonical_link),
+        ];
+
+        if self.noindex {
+            tags.push(meta_tag("name", "robots", "noindex"));
+        }
+
+        if let Some(site_title) = &self.site_title {
+            tags.push(meta_tag("property", "og:site_name", site_title));
+        }
+
+        if let Some(image) = &self.image {
+            tags.push(meta_tag("property", "og:image", image));
+        }
+
+        if let Some(publish_at) = &self.publish_at {
+            tags.push(meta_tag(
+                "property",
+                "article:published_time",
+                &publish_at.to_rfc3339(),
+            ));
+        }
+
+        if let Some(last_updated_at) = &self.last_updated_at {
+            tags.push(meta_tag(
+                "property",
+                "article:modified_time",
+                &last_updated_at.to_rfc3339(),
+            ));
+        }
+
+        tags.push(meta_tag(
+            "name",
+            "twitter:card",
+            match self.image {
+                Some(_) => "summary_large_image",
+                None => "summary",
+            },
+        ));
+        tags.push(meta_tag("name", "twitter:title", &self.title));
+        tags.push(meta_tag("name", "twitter:description", &self.description));
+
+        if let Some(image) = &self.image {
+            tags.push(meta_tag("name", "twitter:image", image));
+        }
+
+        let mut article = json!({
+            "@context": "https://schema.org",
+            "@type": self.schema_type.as_str(),
+            "author": self
+                .authors
+                .iter()
+                .map(|author| json!({ "@type": "Person", "name": author.data.name }))
+                .collect::<Vec<_>>(),
+            "description": self.description,
+            "headline": self.title,
+            "url": self.canonical_link,
+        });
+
+        if let Some(image) = &self.image {
+            article["image"] = json!(image);
+        }
+
+        if let Some(publish_at) = &self.publish_at {
+            article["datePublished"] = json!(publish_at.to_rfc3339());
+        }
+
+        if let Some(last_updated_at) = &self.last_updated_at {
+            article["dateModified"] = json!(last_updated_at.to_rfc3339());
+        }
+
+        tags.push(json_ld_script(&article));
+
+        if !self.breadcrumbs.is_empty() {
+            let mut items: Vec<Value> = Vec::new();
+
+            for (index, reference) in self.breadcrumbs.iter().enumerate() {
+                items.push(json!({
+                    "@type": "ListItem",
+                    "item": reference.canonical_link()?,
+                    "name": reference.front_matter.title,
+                    "position": index + 1,
+                }));
+            }
+
+            tags.push(json_ld_script(&json!({
+                "@context": "https://schema.org",
+                "@type": "BreadcrumbList",
+                "itemListElement": items,
+            })));
+        }
+
+        Ok(tags.join(""))
+    }
+
+    fn rhai_render(&mut self) -> Result<String, Box<EvalAltResult>> {
+        Ok(self.render()?)
+    }
+}
+
+impl CustomType for Seo {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Seo")
+            .with_fn("render", Self::rhai_render);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::author_data::AuthorData;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+
+    fn reference(basename: &str, title: &str) -> ContentDocumentReference {
+        ContentDocumentReference {
+            basename_path: basename.into(),
+            front_matter: ContentDocumentFrontMatter::mock(title),
+            generated_page_base_path: "https://example.com/".to_string(),
+        }
+    }
+
+    fn seo() -> Seo {
+        Seo {
+            authors: vec![Author {
+                basename: "alice".to_string().into(),
+                data: AuthorData::mock("Alice"),
+            }],
+            breadcrumbs: vec![],
+            canonical_link: "https://example.com/guide/".to_string(),
+            description: "All about \"poet\"".to_string(),
+            image: None,
+            last_updated_at: None,
+            noindex: false,
+            publish_at: None,
+            schema_type: SeoSchemaType::Article,
+            site_title: None,
+            title: "Guide".to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_open_graph_and_twitter_tags() -> Result<()> {
+        let rendered = seo().render().map_err(|err| anyhow!(err))?;
+
+        assert!(rendered.contains("<meta property=\"og:title\" content=\"Guide\">"));
+        assert!(
+            rendered.contains(
+                "<meta property=\"og:description\" content=\"All about &quot;poet&quot;\">"
+            )
+        );
+        assert!(
+            rendered.contains("<meta property=\"og:url\" content=\"https://example.com/guide/\">")
+        );
+        assert!(rendered.contains("<meta name=\"twitter:card\" content=\"summary\">"));
+        assert!(!rendered.contains("og:image"));
+        assert!(!rendered.contains("robots"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_article_and_breadcrumbs_as_json_ld() -> Result<()> {
+        let rendered = Seo {
+            breadcrumbs: vec![reference("docs", "Docs"), reference("docs/guide", "Guide")],
+            image: Some("https://example.com/cover.png".to_string()),
+            last_updated_at: Some("2025-03-01T00:00:00Z".parse()?),
+            noindex: true,
+            schema_type: SeoSchemaType::TechArticle,
+            title: "</script>".to_string(),
+            ..seo()
+        }
+        .render()
+        .map_err(|err| anyhow!(err))?;
+
+        assert!(rendered.contains("<meta name=\"robots\" content=\"noindex\">"));
+        assert!(rendered.contains("\"@type\":\"TechArticle\""));
+        assert!(rendered.contains("\"author\":[{\"@type\":\"Person\",\"name\":\"Alice\"}]"));
+        assert!(rendered.contains("\"dateModified\":\"2025-03-01T00:00:00+00:00\""));
+        assert!(rendered.contains("\"headline\":\"<\\/script>\""));
+        assert!(rendered.contains("\"@type\":\"BreadcrumbList\""));
+        assert!(rendered.contains(
+            "{\"@type\":\"ListItem\",\"item\":\"https://example.com/docs/guide/\",\"name\":\"Guide\",\"position\":2}"
+        ));
+        assert!(rendered.contains("<meta name=\"twitter:card\" content=\"summary_large_image\">"));
+
+        Ok(())
+    }
+}
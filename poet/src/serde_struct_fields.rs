@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::de;
+use serde::de::Visitor;
+use serde::de::value::Error;
+use serde::forward_to_deserialize_any;
+
+/// Only asks the deserialized type for the names of its fields, then bails.
+struct StructFieldsDeserializer<'fields> {
+    fields: &'fields mut &'static [&'static str],
+}
+
+impl<'de> Deserializer<'de> for StructFieldsDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<TVisitor>(self, _visitor: TVisitor) -> Result<TVisitor::Value, Error>
+    where
+        TVisitor: Visitor<'de>,
+    {
+        Err(de::Error::custom("expected a struct"))
+    }
+
+    fn deserialize_struct<TVisitor>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: TVisitor,
+    ) -> Result<TVisitor::Value, Error>
+    where
+        TVisitor: Visitor<'de>,
+    {
+        *self.fields = fields;
+
+        Err(de::Error::custom("struct fields collected"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Field names as they appear in serialized data, so renamed fields use their
+/// serialized name.
+pub fn serde_struct_fields<'de, TStruct>() -> &'static [&'static str]
+where
+    TStruct: Deserialize<'de>,
+{
+    let mut fields: &'static [&'static str] = &[];
+
+    // the error is expected, the deserializer never produces a value
+    let _ = TStruct::deserialize(StructFieldsDeserializer {
+        fields: &mut fields,
+    });
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+
+    #[test]
+    fn lists_serialized_field_names_of_a_struct() {
+        let fields = serde_struct_fields::<ContentDocumentFrontMatter>();
+
+        assert!(fields.contains(&"collection"));
+        assert!(fields.contains(&"layout"));
+        assert!(!fields.contains(&"collections"));
+    }
+}
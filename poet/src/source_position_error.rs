@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::fmt;
+
+use markdown::unist::Position;
+use rhai::EvalAltResult;
+
+/// Error raised while evaluating a node at a known position of a source file.
+/// Only the innermost position is kept, since it is the most precise one.
+#[derive(Debug)]
+pub struct SourcePositionError {
+    pub column: usize,
+    pub end_column: usize,
+    pub end_line: usize,
+    pub line: usize,
+    pub source: anyhow::Error,
+}
+
+impl SourcePositionError {
+    pub fn attach(err: anyhow::Error, position: Option<&Position>) -> anyhow::Error {
+        match position {
+            Some(position) if err.downcast_ref::<SourcePositionError>().is_none() => {
+                SourcePositionError {
+                    column: position.start.column,
+                    end_column: position.end.column,
+                    end_line: position.end.line,
+                    line: position.start.line,
+                    source: err,
+                }
+                .into()
+            }
+            _ => err,
+        }
+    }
+
+    /// Points into the `{…}` expression itself when Rhai knows where within
+    /// the expression evaluation failed.
+    pub fn attach_to_expression(err: anyhow::Error, position: Option<&Position>) -> anyhow::Error {
+        let rhai_position = err
+            .downcast_ref::<Box<EvalAltResult>>()
+            .map(|eval_alt_result| eval_alt_result.position());
+
+        match (
+            position,
+            rhai_position.and_then(|rhai_position| rhai_position.line()),
+        ) {
+            (Some(position), Some(expression_line)) => {
+                let expression_column = rhai_position
+                    .and_then(|rhai_position| rhai_position.position())
+                    .unwrap_or(1);
+                // the expression starts right after the opening brace
+                let column = if expression_line == 1 {
+                    position.start.column + expression_column
+                } else {
+                    expression_column
+                };
+                let line = position.start.line + expression_line - 1;
+
+                SourcePositionError {
+                    column,
+                    end_column: column,
+                    end_line: line,
+                    line,
+                    source: err,
+                }
+                .into()
+            }
+            _ => Self::attach(err, position),
+        }
+    }
+
+    pub fn find_in(err: &anyhow::Error) -> Option<&SourcePositionError> {
+        err.downcast_ref::<SourcePositionError>()
+    }
+}
+
+impl Error for SourcePositionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl fmt::Display for SourcePositionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "at line {}, column {}", self.line, self.column)
+    }
+}
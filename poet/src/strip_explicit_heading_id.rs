@@ -0,0 +1,74 @@
+use markdown::mdast::MdxTextExpression;
+use markdown::mdast::Node;
+use markdown::mdast::Text;
+
+/// Splits `## Heading {#custom-id}` into the heading contents and the
+/// explicit id. The `{#id}` suffix is parsed as an expression, so it has to
+/// be removed before the heading contents are evaluated.
+pub fn strip_explicit_heading_id(children: &[Node]) -> (Vec<Node>, Option<String>) {
+    let Some((Node::MdxTextExpression(MdxTextExpression { value, .. }), other_children)) =
+        children.split_last()
+    else {
+        return (children.to_vec(), None);
+    };
+
+    let id = match value.trim().strip_prefix('#') {
+        Some(id) if !id.is_empty() && !id.contains(char::is_whitespace) => id.to_string(),
+        _ => return (children.to_vec(), None),
+    };
+
+    let mut stripped_children = other_children.to_vec();
+
+    if let Some(Node::Text(Text { value, .. })) = stripped_children.last_mut() {
+        value.truncate(value.trim_end().len());
+
+        if value.is_empty() {
+            stripped_children.pop();
+        }
+    }
+
+    (stripped_children, Some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use markdown::mdast::Heading;
+
+    use super::*;
+    use crate::string_to_mdast::string_to_mdast;
+
+    fn heading_children(markdown: &str) -> Result<Vec<Node>> {
+        match string_to_mdast(markdown)?
+            .children()
+            .and_then(|children| children.first())
+        {
+            Some(Node::Heading(Heading { children, .. })) => Ok(children.clone()),
+            _ => Err(anyhow::anyhow!("Expected a heading")),
+        }
+    }
+
+    #[test]
+    fn strips_explicit_id_with_preceding_whitespace() -> Result<()> {
+        let (children, id) = strip_explicit_heading_id(&heading_children("## Setup {#install}")?);
+
+        assert_eq!(id, Some("install".to_string()));
+        assert!(matches!(
+            children.as_slice(),
+            [Node::Text(Text { value, .. })] if value == "Setup"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_expressions_that_are_not_ids() -> Result<()> {
+        let (children, id) =
+            strip_explicit_heading_id(&heading_children("## Setup {context.title}")?);
+
+        assert_eq!(id, None);
+        assert_eq!(children.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::EvalAltResult;
+use rhai::TypeBuilder;
+use slug::slugify;
+
+use crate::content_document_reference::ContentDocumentReference;
+use crate::taxonomy_term::TaxonomyTerm;
+
+#[derive(Clone, Debug)]
+pub struct Taxonomy {
+    pub layout: Option<String>,
+    pub name: String,
+    pub terms: BTreeMap<String, TaxonomyTerm>,
+}
+
+impl Taxonomy {
+    pub fn add_document(&mut self, term_name: &str, reference: &ContentDocumentReference) {
+        let slug = slugify(term_name);
+
+        self.terms
+            .entry(slug.clone())
+            .or_insert_with(|| TaxonomyTerm {
+                documents: Default::default(),
+                generated_page_base_path: reference.generated_page_base_path.clone(),
+                name: term_name.to_string(),
+                slug,
+                taxonomy_name: self.name.clone(),
+            })
+            .documents
+            .insert(reference.clone());
+    }
+
+    fn rhai_name(&mut self) -> String {
+        self.name.clone()
+    }
+
+    fn rhai_term(&mut self, term_name: &str) -> Result<TaxonomyTerm, Box<EvalAltResult>> {
+        match self.terms.get(&slugify(term_name)) {
+            Some(term) => Ok(term.clone()),
+            None => Err(format!(
+                "Term is never used in taxonomy '{}': '{term_name}'",
+                self.name
+            )
+            .into()),
+        }
+    }
+
+    fn rhai_terms(&mut self) -> rhai::Array {
+        self.terms.values().cloned().map(Dynamic::from).collect()
+    }
+}
+
+impl CustomType for Taxonomy {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Taxonomy")
+            .with_get("name", Self::rhai_name)
+            .with_get("terms", Self::rhai_terms)
+            .with_fn("term", Self::rhai_term);
+    }
+}
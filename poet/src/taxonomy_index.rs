@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use crate::content_document_reference::ContentDocumentReference;
+use crate::project_config::taxonomy_config::TaxonomyConfig;
+use crate::taxonomy::Taxonomy;
+
+/// Terms of every configured taxonomy, with the documents that use them.
+#[derive(Clone, Debug, Default)]
+pub struct TaxonomyIndex {
+    pub taxonomies: BTreeMap<String, Taxonomy>,
+}
+
+impl TaxonomyIndex {
+    pub fn new(taxonomies: &BTreeMap<String, TaxonomyConfig>) -> Self {
+        Self {
+            taxonomies: taxonomies
+                .iter()
+                .map(|(name, TaxonomyConfig { layout })| {
+                    (
+                        name.clone(),
+                        Taxonomy {
+                            layout: layout.clone(),
+                            name: name.clone(),
+                            terms: Default::default(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Taxonomies that are not configured are skipped, build reports them
+    /// separately.
+    pub fn add_document(&mut self, reference: &ContentDocumentReference) {
+        for (taxonomy_name, term_names) in &reference.front_matter.taxonomies {
+            if let Some(taxonomy) = self.taxonomies.get_mut(taxonomy_name) {
+                for term_name in term_names {
+                    taxonomy.add_document(term_name, reference);
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, taxonomy_name: &str) -> Result<Taxonomy, String> {
+        self.taxonomies
+            .get(taxonomy_name)
+            .cloned()
+            .ok_or_else(|| format!("Taxonomy is not configured in poet.toml: '{taxonomy_name}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::content_document_front_matter::ContentDocumentFrontMatter;
+
+    fn reference(basename: &str, tags: &[&str]) -> ContentDocumentReference {
+        let mut front_matter = ContentDocumentFrontMatter::mock(basename);
+
+        front_matter.taxonomies.insert(
+            "tags".to_string(),
+            tags.iter().map(|tag| tag.to_string()).collect(),
+        );
+
+        ContentDocumentReference {
+            basename_path: basename.into(),
+            front_matter,
+            generated_page_base_path: "/".to_string(),
+        }
+    }
+
+    #[test]
+    fn groups_documents_by_slugified_term() -> Result<()> {
+        let mut taxonomies = BTreeMap::new();
+
+        taxonomies.insert("tags".to_string(), TaxonomyConfig::default());
+
+        let mut taxonomy_index = TaxonomyIndex::new(&taxonomies);
+
+        taxonomy_index.add_document(&reference("a", &["Rust Lang"]));
+        taxonomy_index.add_document(&reference("b", &["rust lang", "web"]));
+
+        let tags = taxonomy_index.get("tags").map_err(|err| anyhow!(err))?;
+        let rust = &tags.terms["rust-lang"];
+
+        assert_eq!(rust.name, "Rust Lang");
+        assert_eq!(rust.documents.len(), 2);
+        assert_eq!(rust.canonical_link(), "/tags/rust-lang/");
+        assert_eq!(tags.terms["web"].documents.len(), 1);
+        assert!(taxonomy_index.get("categories").is_err());
+
+        Ok(())
+    }
+}
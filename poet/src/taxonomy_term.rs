@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use rhai::CustomType;
+use rhai::Dynamic;
+use rhai::TypeBuilder;
+
+use crate::content_document_reference::ContentDocumentReference;
+
+/// Terms are told apart by their slugs, so `Rust` and `rust` are the same
+/// term, named after the first spelling found.
+#[derive(Clone, Debug)]
+pub struct TaxonomyTerm {
+    pub documents: BTreeSet<ContentDocumentReference>,
+    pub generated_page_base_path: String,
+    pub name: String,
+    pub slug: String,
+    pub taxonomy_name: String,
+}
+
+impl TaxonomyTerm {
+    pub fn basename(&self) -> String {
+        format!("{}/{}", self.taxonomy_name, self.slug)
+    }
+
+    pub fn canonical_link(&self) -> String {
+        format!("{}{}/", self.generated_page_base_path, self.basename())
+    }
+
+    /// Starts without leading slash
+    pub fn target_file_relative_path(&self) -> PathBuf {
+        format!("{}/index.html", self.basename()).into()
+    }
+
+    fn rhai_canonical_link(&mut self) -> String {
+        self.canonical_link()
+    }
+
+    fn rhai_documents(&mut self) -> rhai::Array {
+        self.documents.iter().cloned().map(Dynamic::from).collect()
+    }
+
+    fn rhai_name(&mut self) -> String {
+        self.name.clone()
+    }
+
+    fn rhai_slug(&mut self) -> String {
+        self.slug.clone()
+    }
+
+    fn rhai_taxonomy_name(&mut self) -> String {
+        self.taxonomy_name.clone()
+    }
+}
+
+impl CustomType for TaxonomyTerm {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("TaxonomyTerm")
+            .with_get("canonical_link", Self::rhai_canonical_link)
+            .with_get("documents", Self::rhai_documents)
+            .with_get("name", Self::rhai_name)
+            .with_get("slug", Self::rhai_slug)
+            .with_get("taxonomy_name", Self::rhai_taxonomy_name);
+    }
+}
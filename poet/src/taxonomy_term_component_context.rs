@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use rhai::CustomType;
+use rhai::EvalAltResult;
+use rhai::TypeBuilder;
+
+use crate::asset_manager::AssetManager;
+use crate::content_document_linker::ContentDocumentLinker;
+use crate::project_config::site_config::SiteConfig;
+use crate::taxonomy::Taxonomy;
+use crate::taxonomy_index::TaxonomyIndex;
+use crate::taxonomy_term::TaxonomyTerm;
+
+/// Context of the layout that renders a term listing page.
+#[derive(Clone)]
+pub struct TaxonomyTermComponentContext {
+    pub asset_manager: AssetManager,
+    pub content_document_linker: ContentDocumentLinker,
+    pub is_watching: bool,
+    pub site: Arc<SiteConfig>,
+    pub taxonomy_index: Arc<TaxonomyIndex>,
+    pub term: TaxonomyTerm,
+}
+
+impl TaxonomyTermComponentContext {
+    fn rhai_get_assets(&mut self) -> AssetManager {
+        self.asset_manager.clone()
+    }
+
+    fn rhai_is_watching(&mut self) -> bool {
+        self.is_watching
+    }
+
+    fn rhai_link_to(&mut self, path: &str) -> Result<String, Box<EvalAltResult>> {
+        Ok(self.content_document_linker.link_to(path)?)
+    }
+
+    fn rhai_site(&mut self) -> SiteConfig {
+        (*self.site).clone()
+    }
+
+    fn rhai_taxonomy(&mut self, taxonomy_name: &str) -> Result<Taxonomy, Box<EvalAltResult>> {
+        Ok(self.taxonomy_index.get(taxonomy_name)?)
+    }
+
+    fn rhai_term(&mut self) -> TaxonomyTerm {
+        self.term.clone()
+    }
+}
+
+impl CustomType for TaxonomyTermComponentContext {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("TaxonomyTermComponentContext")
+            .with_get("assets", Self::rhai_get_assets)
+            .with_get("is_watching", Self::rhai_is_watching)
+            .with_get("site", Self::rhai_site)
+            .with_get("term", Self::rhai_term)
+            .with_fn("link_to", Self::rhai_link_to)
+            .with_fn("taxonomy", Self::rhai_taxonomy);
+    }
+}
@@ -7,6 +7,7 @@ use dashmap::DashMap;
 use rhai::CustomType;
 use rhai::Dynamic;
 use rhai::Engine;
+use rhai::EvalAltResult;
 use rhai::Position;
 use rhai::Scope;
 
@@ -54,6 +55,8 @@ impl RhaiTemplateRenderer {
         })
     }
 
+    /// Errors point at the location in the component file where rendering
+    /// failed, when Rhai knows it.
     pub fn render<TComponentContext>(
         &self,
         name: &str,
@@ -70,6 +73,20 @@ impl RhaiTemplateRenderer {
                 &component_reference.name,
                 (context, props, content),
             )
+            .map_err(|err| {
+                let location = err
+                    .downcast_ref::<Box<EvalAltResult>>()
+                    .map(|eval_alt_result| eval_alt_result.position())
+                    .and_then(|position| Some((position.line()?, position.position()?)));
+
+                match location {
+                    Some((line, column)) => err.context(format!(
+                        "Component '{name}' failed at {}:{line}:{column}",
+                        component_reference.path
+                    )),
+                    None => err.context(format!("Component '{name}' failed")),
+                }
+            })
         } else {
             Err(anyhow!("Template '{name}' not found"))
         }
@@ -213,6 +230,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn render_reports_component_path_with_line_and_column() -> Result<()> {
+        assert!(build_renderer(&["Note"]).is_ok_and(|renderer| {
+            renderer
+                .render("Note", DummyContext, Dynamic::UNIT, Dynamic::UNIT)
+                .is_err_and(|error| {
+                    error
+                        .to_string()
+                        .starts_with("Component 'Note' failed at Note:")
+                })
+        }));
+
+        Ok(())
+    }
+
     #[test]
     fn render_expression_evaluates_simple_expression() -> Result<()> {
         assert!(build_renderer(&[]).is_ok_and(|renderer| {